@@ -1,6 +1,6 @@
 use std::hint::black_box;
 
-use criterion::{Criterion, criterion_group, criterion_main};
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
 use shamir_share::ShamirShare;
 
 /// Test data sizes for HSSS benchmarking
@@ -16,6 +16,70 @@ fn create_mock_data(size: usize) -> Vec<u8> {
     (0..size).map(|i| (i % 256) as u8).collect()
 }
 
+/// Data size for benchmarks that vary the scheme parameters instead
+const SCALING_DATA_SIZE: usize = 10 * 1024; // 10KB
+
+/// Thresholds for the threshold scaling sweep
+const THRESHOLDS: &[u8] = &[2, 4, 8, 16, 32, 64, 128];
+
+/// Scheme parameters for one point of a split/reconstruct sweep
+struct SchemeCase {
+    total_shares: u8,
+    threshold: u8,
+    data_size: usize,
+}
+
+/// Benchmarks split and reconstruction of a threshold-sized share set for every case
+///
+/// Results go into `<group_name>_split` and `<group_name>_reconstruct`, each labelled
+/// with `parameter(case)`.
+fn bench_split_reconstruct_cases(
+    c: &mut Criterion,
+    group_name: &str,
+    cases: &[SchemeCase],
+    parameter: impl Fn(&SchemeCase) -> String,
+) {
+    let mut split_group = c.benchmark_group(format!("{group_name}_split"));
+    for case in cases {
+        let data = create_mock_data(case.data_size);
+        let mut shamir = ShamirShare::builder(case.total_shares, case.threshold)
+            .build()
+            .unwrap();
+
+        split_group.bench_with_input(
+            BenchmarkId::from_parameter(parameter(case)),
+            &data,
+            |b, data| {
+                b.iter(|| {
+                    black_box(shamir.split(black_box(data)).unwrap());
+                });
+            },
+        );
+    }
+    split_group.finish();
+
+    let mut reconstruct_group = c.benchmark_group(format!("{group_name}_reconstruct"));
+    for case in cases {
+        let data = create_mock_data(case.data_size);
+        let mut shamir = ShamirShare::builder(case.total_shares, case.threshold)
+            .build()
+            .unwrap();
+        let shares = shamir.split(&data).unwrap();
+        let needed = &shares[..case.threshold as usize];
+
+        reconstruct_group.bench_with_input(
+            BenchmarkId::from_parameter(parameter(case)),
+            needed,
+            |b, needed| {
+                b.iter(|| {
+                    black_box(ShamirShare::reconstruct(black_box(needed)).unwrap());
+                });
+            },
+        );
+    }
+    reconstruct_group.finish();
+}
+
 fn bench_split(c: &mut Criterion) {
     let mut group = c.benchmark_group("split");
 
@@ -53,6 +117,33 @@ fn bench_reconstruct(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_reconstruct_two_of_n(c: &mut Criterion) {
+    let mut group = c.benchmark_group("reconstruct_two_of_n");
+    let mut shamir = ShamirShare::builder(5, 2).build().unwrap();
+
+    // Both reconstruct the same two shares with the default parallel threshold:
+    // `reconstruct` takes the closed-form two-point path, while `reconstruct_ct` always
+    // uses general Lagrange interpolation. Its constant-time coefficient computation only
+    // examines one index pair here, so the difference is the per-byte interpolation.
+    for size in DATA_SIZES.iter() {
+        let data = create_mock_data(*size);
+        let shares = shamir.split(&data).unwrap();
+
+        group.bench_function(format!("two_point_{}_bytes", size), |b| {
+            b.iter(|| {
+                black_box(ShamirShare::reconstruct(black_box(&shares[0..2])).unwrap());
+            });
+        });
+        group.bench_function(format!("lagrange_{}_bytes", size), |b| {
+            b.iter(|| {
+                black_box(ShamirShare::reconstruct_ct(black_box(&shares[0..2])).unwrap());
+            });
+        });
+    }
+
+    group.finish();
+}
+
 fn bench_full_workflow(c: &mut Criterion) {
     let mut group = c.benchmark_group("full_workflow");
 
@@ -72,5 +163,30 @@ fn bench_full_workflow(c: &mut Criterion) {
     group.finish();
 }
 
-criterion_group!(benches, bench_split, bench_reconstruct, bench_full_workflow);
+fn bench_threshold_scaling(c: &mut Criterion) {
+    // Share count and data size stay fixed so only the threshold varies; computing the
+    // Lagrange coefficients is quadratic in the threshold
+    let total_shares = *THRESHOLDS.last().unwrap();
+    let cases: Vec<SchemeCase> = THRESHOLDS
+        .iter()
+        .map(|&threshold| SchemeCase {
+            total_shares,
+            threshold,
+            data_size: SCALING_DATA_SIZE,
+        })
+        .collect();
+
+    bench_split_reconstruct_cases(c, "threshold_scaling", &cases, |case| {
+        format!("threshold_{}", case.threshold)
+    });
+}
+
+criterion_group!(
+    benches,
+    bench_split,
+    bench_reconstruct,
+    bench_reconstruct_two_of_n,
+    bench_threshold_scaling,
+    bench_full_workflow
+);
 criterion_main!(benches);
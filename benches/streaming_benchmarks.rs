@@ -307,12 +307,51 @@ fn bench_streaming_integrity_check(c: &mut Criterion) {
     group.finish();
 }
 
+/// Benchmark small-secret splitting and reconstruction on either side of the parallel
+/// threshold
+fn bench_parallel_threshold(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel_threshold");
+    let data = create_mock_data(32);
+
+    let configs = [
+        ("serial", Config::new().with_parallel_threshold(usize::MAX)),
+        ("parallel", Config::new().with_parallel_threshold(0)),
+    ];
+
+    for (name, config) in configs {
+        let mut scheme = ShamirShare::builder(5, 3)
+            .with_config(config.clone())
+            .build()
+            .unwrap();
+        let shares = scheme.split(&data).unwrap();
+
+        group.bench_function(BenchmarkId::new("split_stream_32_bytes", name), |b| {
+            b.iter(|| {
+                let mut source = Cursor::new(black_box(&data));
+                let mut destinations = vec![Cursor::new(Vec::new()); 5];
+                scheme.split_stream(&mut source, &mut destinations).unwrap();
+                black_box(destinations);
+            });
+        });
+        group.bench_function(BenchmarkId::new("reconstruct_32_bytes", name), |b| {
+            b.iter(|| {
+                black_box(
+                    ShamirShare::reconstruct_with_config(black_box(&shares[..3]), &config).unwrap(),
+                );
+            });
+        });
+    }
+
+    group.finish();
+}
+
 criterion_group!(
     streaming_benches,
     bench_split_stream,
     bench_reconstruct_stream,
     bench_streaming_vs_memory_comparison,
     bench_split_stream_chunk_sizes,
-    bench_streaming_integrity_check
+    bench_streaming_integrity_check,
+    bench_parallel_threshold
 );
 criterion_main!(streaming_benches);
@@ -0,0 +1,86 @@
+//! Compares split timings of low- and high-entropy secrets
+//!
+//! Run with `cargo run --release --example timing_harness --features timing-harness`.
+//! Welch's t-statistic between the two distributions stays small (|t| < 4.5 is the usual
+//! threshold) when the split time does not depend on the secret.
+
+use rand::RngCore;
+use shamir_share::timing::{self, Operation};
+use shamir_share::{Config, ShamirShare};
+
+const SECRET_LEN: usize = 1024;
+const ROUNDS: usize = 2000;
+
+struct Stats {
+    mean: f64,
+    median: f64,
+    std_dev: f64,
+    count: usize,
+}
+
+impl Stats {
+    fn new(mut samples: Vec<f64>) -> Self {
+        samples.sort_by(f64::total_cmp);
+        let count = samples.len();
+        let mean = samples.iter().sum::<f64>() / count as f64;
+        let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (count - 1) as f64;
+        Self {
+            mean,
+            median: samples[count / 2],
+            std_dev: variance.sqrt(),
+            count,
+        }
+    }
+
+    fn welch_t(&self, other: &Self) -> f64 {
+        let error = (self.std_dev.powi(2) / self.count as f64
+            + other.std_dev.powi(2) / other.count as f64)
+            .sqrt();
+        (self.mean - other.mean) / error
+    }
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    // Compression would make low-entropy secrets faster by design, so measure the
+    // polynomial evaluation alone
+    let mut scheme = ShamirShare::builder(5, 3)
+        .with_config(Config::new().with_compression(false))
+        .build()?;
+    let mut rng = rand::rng();
+
+    let low_entropy = [0u8; SECRET_LEN];
+    let mut high_entropy = [0u8; SECRET_LEN];
+    let mut low_ns = Vec::with_capacity(ROUNDS);
+    let mut high_ns = Vec::with_capacity(ROUNDS);
+
+    // Interleave the two classes so clock drift and frequency scaling affect both alike
+    for _ in 0..ROUNDS {
+        rng.fill_bytes(&mut high_entropy);
+        for (secret, timings) in [(&low_entropy, &mut low_ns), (&high_entropy, &mut high_ns)] {
+            let (shares, samples) = timing::record(|| scheme.split(secret));
+            shares?;
+            let split = samples
+                .iter()
+                .find(|sample| sample.operation == Operation::Split)
+                .expect("split is instrumented");
+            timings.push(split.elapsed.as_nanos() as f64);
+        }
+    }
+
+    let low = Stats::new(low_ns);
+    let high = Stats::new(high_ns);
+    println!("Split of a {SECRET_LEN}-byte secret, {ROUNDS} rounds each (ns)");
+    println!(
+        "{:<14} {:>12} {:>12} {:>12}",
+        "secret", "mean", "median", "std dev"
+    );
+    for (name, stats) in [("low entropy", &low), ("high entropy", &high)] {
+        println!(
+            "{name:<14} {:>12.0} {:>12.0} {:>12.0}",
+            stats.mean, stats.median, stats.std_dev
+        );
+    }
+    println!("Welch's t-statistic: {:.2}", low.welch_t(&high));
+
+    Ok(())
+}
@@ -0,0 +1,165 @@
+//! Asynchronous share collection for network-distributed shares
+//!
+//! This module is only available with the `tokio` feature.
+
+use std::pin::Pin;
+use std::task::{Context, Poll};
+
+use futures_sink::Sink;
+use tokio::sync::oneshot;
+
+use crate::error::{Result, ShamirError};
+use crate::shamir::{ShamirShare, Share};
+
+/// A [`Sink`] that reconstructs a secret as shares arrive
+///
+/// Designed for gossip or DHT-based distribution, where shares trickle in from peers in
+/// arbitrary order and possibly more than once. Each incoming share is validated against
+/// the first one received; once `threshold` consistent shares are collected the secret is
+/// reconstructed and delivered through the [`oneshot::Receiver`] returned by
+/// [`new`](Self::new).
+///
+/// The receiver gets exactly one result:
+/// - `Ok(secret)` once enough shares arrived and reconstruction succeeded
+/// - The first inconsistency, if a share's metadata, length, or data conflicts with the
+///   shares collected so far (`InconsistentShareMetadata`, `MixedGenerations`, or
+///   `InvalidShareIndex` for two different shares with the same index). The inconsistent
+///   share is also rejected by `start_send`
+/// - The reconstruction error (e.g. `IntegrityCheckFailed`) if interpolation fails
+/// - `InsufficientShares` if the sink is closed before `threshold` shares arrived
+///
+/// Exact duplicates are ignored, and shares sent after the result was delivered are
+/// accepted and dropped, so producers do not need to know when collection finished.
+///
+/// # Example
+/// ```
+/// use futures_util::SinkExt;
+/// use shamir_share::{AsyncCollector, ShamirShare};
+///
+/// # tokio_test::block_on(async {
+/// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+/// let shares = scheme.split(b"gossiped secret").unwrap();
+///
+/// let (mut collector, secret) = AsyncCollector::new();
+/// for share in shares.into_iter().rev() {
+///     collector.send(share).await.unwrap();
+/// }
+///
+/// assert_eq!(secret.await.unwrap().unwrap(), b"gossiped secret");
+/// # });
+/// ```
+#[derive(Debug)]
+pub struct AsyncCollector {
+    /// Consistent shares received so far, at most `threshold` of them
+    shares: Vec<Share>,
+    /// Completes the receiver; `None` once the result was delivered
+    result: Option<oneshot::Sender<Result<Vec<u8>>>>,
+}
+
+impl AsyncCollector {
+    /// Creates a collector and the receiver that resolves to the reconstructed secret
+    pub fn new() -> (Self, oneshot::Receiver<Result<Vec<u8>>>) {
+        let (sender, receiver) = oneshot::channel();
+        let collector = Self {
+            shares: Vec::new(),
+            result: Some(sender),
+        };
+        (collector, receiver)
+    }
+
+    /// Returns the number of distinct consistent shares collected so far
+    pub fn collected(&self) -> usize {
+        self.shares.len()
+    }
+
+    /// Returns whether the result has been delivered to the receiver
+    pub fn is_complete(&self) -> bool {
+        self.result.is_none()
+    }
+
+    /// Checks `share` against the shares collected so far
+    ///
+    /// Returns `Ok(true)` for a new share, `Ok(false)` for an exact duplicate.
+    fn check(&self, share: &Share) -> Result<bool> {
+        if share.index == 0 {
+            return Err(ShamirError::InvalidShareIndex(0));
+        }
+
+        let Some(first) = self.shares.first() else {
+            return Ok(true);
+        };
+        ShamirShare::ensure_same_scheme(&[first, share])?;
+
+        match self.shares.iter().find(|s| s.index == share.index) {
+            Some(existing) if existing.data == share.data => Ok(false),
+            Some(_) => Err(ShamirError::InvalidShareIndex(share.index)),
+            None => Ok(true),
+        }
+    }
+
+    /// Delivers `result` to the receiver and releases the collected shares
+    fn complete(&mut self, result: Result<Vec<u8>>) {
+        if let Some(sender) = self.result.take() {
+            // The receiver may have been dropped; the result is then discarded
+            let _ = sender.send(result);
+        }
+        self.shares.clear();
+    }
+}
+
+/// Copies an error returned by [`AsyncCollector::check`], as `ShamirError` is not `Clone`
+fn copy_conflict(error: &ShamirError) -> ShamirError {
+    match error {
+        ShamirError::InvalidShareIndex(index) => ShamirError::InvalidShareIndex(*index),
+        ShamirError::MixedGenerations => ShamirError::MixedGenerations,
+        ShamirError::InconsistentShareMetadata { field } => {
+            ShamirError::InconsistentShareMetadata { field }
+        }
+        _ => unreachable!("check only reports conflicts between shares"),
+    }
+}
+
+impl Sink<Share> for AsyncCollector {
+    type Error = ShamirError;
+
+    fn poll_ready(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn start_send(self: Pin<&mut Self>, share: Share) -> Result<()> {
+        let this = self.get_mut();
+        if this.is_complete() {
+            return Ok(());
+        }
+
+        match this.check(&share) {
+            Ok(true) => this.shares.push(share),
+            Ok(false) => return Ok(()),
+            Err(e) => {
+                // Report the inconsistency to both the receiver and the producer
+                this.complete(Err(copy_conflict(&e)));
+                return Err(e);
+            }
+        }
+
+        if this.shares.len() >= this.shares[0].threshold as usize {
+            let result = ShamirShare::reconstruct(&this.shares);
+            this.complete(result);
+        }
+        Ok(())
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        Poll::Ready(Ok(()))
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Result<()>> {
+        let this = self.get_mut();
+        if !this.is_complete() {
+            let needed = this.shares.first().map_or(1, |s| s.threshold);
+            let got = u8::try_from(this.shares.len()).unwrap_or(u8::MAX);
+            this.complete(Err(ShamirError::InsufficientShares { needed, got }));
+        }
+        Poll::Ready(Ok(()))
+    }
+}
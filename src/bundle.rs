@@ -0,0 +1,158 @@
+//! Grouping of the shares held by a single participant
+
+use crate::error::{Result, ShamirError};
+use crate::shamir::{ShamirShare, Share};
+
+/// The shares of one scheme held by a single participant
+///
+/// Participants sometimes hold several shares, for example to give them more weight in a
+/// scheme. A bundle lets such a holder pass subsets of that holding on to a sub-team
+/// without involving the dealer.
+///
+/// # Limitations
+/// Subdividing does not create new cryptographic shares: that would need the polynomial,
+/// which only the dealer has. Every sub-delegate receives some of the holder's original
+/// points, so:
+/// - Sub-delegates together hold exactly the holder's power, never more
+/// - A sub-delegate with `threshold` shares can reconstruct alone, just as the holder could
+/// - Delegated shares cannot be revoked; use
+///   [`ShamirShare::refresh_shares`](crate::ShamirShare::refresh_shares) with all
+///   participants to invalidate them
+///
+/// # Example
+/// ```
+/// use shamir_share::{ShamirShare, ShareBundle};
+///
+/// let mut scheme = ShamirShare::builder(7, 5).build().unwrap();
+/// let shares = scheme.split(b"team secret").unwrap();
+///
+/// let bundle = ShareBundle::new(shares[..5].to_vec()).unwrap();
+/// let groups = bundle.subdivide(&[3, 2]).unwrap();
+/// assert_eq!(groups[0].len(), 3);
+/// assert_eq!(groups[1].len(), 2);
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct ShareBundle {
+    shares: Vec<Share>,
+}
+
+impl ShareBundle {
+    /// Creates a bundle from shares of the same scheme
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Shares differ in share counts, data length, or flags (`InconsistentShareMetadata`)
+    /// - Shares are from different refresh generations (`MixedGenerations`)
+    /// - An index appears more than once (`InvalidShareIndex`)
+    pub fn new(shares: Vec<Share>) -> Result<Self> {
+        ShamirShare::ensure_same_scheme(&shares)?;
+
+        let mut seen = [false; 256];
+        for share in &shares {
+            if seen[share.index as usize] {
+                return Err(ShamirError::InvalidShareIndex(share.index));
+            }
+            seen[share.index as usize] = true;
+        }
+
+        Ok(Self { shares })
+    }
+
+    /// Returns the shares in the bundle, in the order they were given
+    pub fn shares(&self) -> &[Share] {
+        &self.shares
+    }
+
+    /// Partitions the holding among sub-delegates
+    ///
+    /// Sub-delegate `i` receives the next `counts[i]` shares in bundle order. Shares left
+    /// over when `counts` sums to less than the bundle size stay with the holder and are not
+    /// returned.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if `counts` sums to more than the number of
+    /// shares in the bundle.
+    pub fn subdivide(&self, counts: &[u8]) -> Result<Vec<Vec<Share>>> {
+        let requested: usize = counts.iter().map(|&count| count as usize).sum();
+        if requested > self.shares.len() {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Cannot hand out {requested} shares from a bundle of {}",
+                self.shares.len()
+            )));
+        }
+
+        let mut remaining = self.shares.as_slice();
+        Ok(counts
+            .iter()
+            .map(|&count| {
+                let (group, rest) = remaining.split_at(count as usize);
+                remaining = rest;
+                group.to_vec()
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShamirShare;
+
+    #[test]
+    fn test_subdivided_groups_jointly_reconstruct() {
+        let mut scheme = ShamirShare::builder(7, 5).build().unwrap();
+        let secret = b"delegated holding";
+        let shares = scheme.split(secret).unwrap();
+
+        let bundle = ShareBundle::new(shares[..5].to_vec()).unwrap();
+        let groups = bundle.subdivide(&[2, 2, 1]).unwrap();
+        assert_eq!(
+            groups.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![2, 2, 1]
+        );
+
+        // No group reconstructs alone, together they do
+        for group in &groups {
+            assert!(ShamirShare::reconstruct(group).is_err());
+        }
+        let joined: Vec<Share> = groups.concat();
+        assert_eq!(ShamirShare::reconstruct(&joined).unwrap(), secret);
+
+        // Two groups plus outside shares also reach the threshold
+        let with_others = [groups[0].clone(), groups[2].clone(), shares[5..].to_vec()].concat();
+        assert_eq!(ShamirShare::reconstruct(&with_others).unwrap(), secret);
+
+        // Leftover shares stay with the holder
+        assert_eq!(bundle.subdivide(&[1, 1]).unwrap().concat(), shares[..2]);
+    }
+
+    #[test]
+    fn test_bundle_rejects_mixed_holdings() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme.split(b"holding").unwrap();
+
+        assert!(matches!(
+            ShareBundle::new(vec![shares[0].clone(), shares[0].clone()]),
+            Err(ShamirError::InvalidShareIndex(1))
+        ));
+
+        let longer = scheme.split(b"a longer holding").unwrap();
+        assert!(matches!(
+            ShareBundle::new(vec![shares[0].clone(), longer[1].clone()]),
+            Err(ShamirError::InconsistentShareMetadata {
+                field: "data length"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_subdivide_more_than_held() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme.split(b"holding").unwrap();
+        let bundle = ShareBundle::new(shares[..3].to_vec()).unwrap();
+        assert!(matches!(
+            bundle.subdivide(&[2, 2]),
+            Err(ShamirError::InvalidConfig(msg)) if msg == "Cannot hand out 4 shares from a bundle of 3"
+        ));
+    }
+}
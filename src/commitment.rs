@@ -0,0 +1,122 @@
+//! Salted hash commitments to a secret
+//!
+//! A dealer can publish a [`Commitment`] when splitting a secret and later show that a
+//! reconstructed value is the secret that was split. Unlike Feldman commitments this
+//! needs no group arithmetic, but it only checks the final secret, not individual shares.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::integrity::ct_eq;
+
+/// Size in bytes of the random salt
+pub(crate) const COMMITMENT_SALT_SIZE: usize = 32;
+
+/// A public commitment to a secret: `SHA-256(salt || secret)` together with its salt
+///
+/// Create one with [`ShamirShare::split_with_commitment`](crate::ShamirShare::split_with_commitment).
+/// Both fields are public and the commitment can be serialized for publication.
+///
+/// # Security
+/// - Binding: finding a second secret that verifies requires a SHA-256 collision
+/// - Hiding only for unguessable secrets: anyone holding the commitment can test candidate
+///   secrets against it. The salt prevents precomputed tables, not guessing, so do not
+///   commit to passwords or other low-entropy secrets
+///
+/// # Example
+/// ```
+/// use shamir_share::ShamirShare;
+///
+/// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+/// let (shares, commitment) = scheme.split_with_commitment(b"launch code").unwrap();
+///
+/// let secret = ShamirShare::reconstruct(&shares[..3]).unwrap();
+/// assert!(commitment.verify(&secret));
+/// assert!(!commitment.verify(b"other code"));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Commitment {
+    salt: [u8; COMMITMENT_SALT_SIZE],
+    digest: [u8; 32],
+}
+
+impl Commitment {
+    /// Commits to `secret` under `salt`
+    pub(crate) fn new(secret: &[u8], salt: [u8; COMMITMENT_SALT_SIZE]) -> Self {
+        Self {
+            salt,
+            digest: salted_digest(&salt, secret),
+        }
+    }
+
+    /// Returns true if `secret` is the committed secret
+    ///
+    /// The digests are compared in constant time.
+    pub fn verify(&self, secret: &[u8]) -> bool {
+        ct_eq(&salted_digest(&self.salt, secret), &self.digest)
+    }
+
+    /// Returns the random salt
+    pub fn salt(&self) -> &[u8; COMMITMENT_SALT_SIZE] {
+        &self.salt
+    }
+
+    /// Returns `SHA-256(salt || secret)`
+    pub fn digest(&self) -> &[u8; 32] {
+        &self.digest
+    }
+}
+
+/// Computes `SHA-256(salt || secret)`
+fn salted_digest(salt: &[u8], secret: &[u8]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update(salt)
+        .chain_update(secret)
+        .finalize()
+        .into()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, ShamirShare};
+
+    #[test]
+    fn test_commitment_verifies_reconstructed_secret() {
+        let secret = b"committed secret";
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let (shares, commitment) = scheme.split_with_commitment(secret).unwrap();
+
+        let reconstructed = ShamirShare::reconstruct(&shares[2..]).unwrap();
+        assert!(commitment.verify(&reconstructed));
+        assert!(!commitment.verify(b"committed secreT"));
+        assert!(!commitment.verify(b""));
+
+        // Salts are fresh, so committing to the same secret twice gives different digests
+        let (_, again) = scheme.split_with_commitment(secret).unwrap();
+        assert_ne!(again.salt(), commitment.salt());
+        assert_ne!(again.digest(), commitment.digest());
+        assert!(again.verify(secret));
+    }
+
+    #[test]
+    fn test_commitment_is_independent_of_share_encoding() {
+        // The commitment covers the secret, not the compressed or length-prefixed payload
+        let config = Config {
+            compression: cfg!(feature = "compress"),
+            ..Config::new().with_length_check(true)
+        };
+        let mut scheme = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let secret = [b'z'; 256];
+        let (shares, commitment) = scheme.split_with_commitment(&secret).unwrap();
+
+        assert_eq!(
+            commitment.digest(),
+            &salted_digest(commitment.salt(), &secret)
+        );
+        assert!(commitment.verify(&ShamirShare::reconstruct(&shares[..2]).unwrap()));
+    }
+}
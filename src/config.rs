@@ -1,9 +1,27 @@
+use std::sync::Arc;
+
 use crate::error::{Result, ShamirError};
+use crate::integrity::{IntegrityVerifier, Sha256Verifier};
+use crate::shamir::{LENGTH_PREFIX_SIZE, STREAM_HEADER_LEN};
+
+/// Size of the SHA-256 hash stored with every stream chunk under integrity checking
+const CHUNK_HASH_SIZE: usize = 32;
+/// Size of the length prefix of every stream record
+const STREAM_LENGTH_PREFIX_SIZE: usize = 4;
+/// Smallest chunk size accepted with integrity checking, capping the hash overhead at 100%
+const MIN_INTEGRITY_CHUNK_SIZE: usize = CHUNK_HASH_SIZE;
+/// Data size in bytes from which chunk splitting and reconstruction use rayon
+///
+/// Below this, scheduling parallel work costs more than the GF(256) arithmetic it spreads.
+pub(crate) const DEFAULT_PARALLEL_THRESHOLD: usize = 4096;
+/// Parallel threshold of the `Performance` preset, spreading mid-sized chunks across cores
+const PERFORMANCE_PARALLEL_THRESHOLD: usize = 1024;
 
 /// Processing mode for share operations
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
 pub enum SplitMode {
     /// Process data sequentially
+    #[default]
     Sequential,
     /// Process data in parallel
     Parallel,
@@ -11,10 +29,55 @@ pub enum SplitMode {
     Streaming,
 }
 
-impl Default for SplitMode {
-    fn default() -> Self {
-        Self::Sequential
-    }
+/// Security profile selecting a [`Config::preset`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Profile {
+    /// Every check enabled, nothing that trades safety for speed or size
+    MaximumSecurity,
+    /// The defaults plus compression
+    Balanced,
+    /// Cheapest checks, compression, and parallel processing from smaller chunk sizes
+    Performance,
+}
+
+/// Share sizes a [`Config`] produces for a secret, from [`Config::overhead_bytes`]
+///
+/// Figures are exact without compression. With compression they are upper bounds:
+/// compression is only kept where it makes data smaller, so it can only save space,
+/// except for the one marker byte per stream chunk counted in `stream_framing_bytes`.
+/// How much it saves depends on the data and cannot be predicted from its length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OverheadReport {
+    /// Length of the secret the report is for
+    pub secret_len: usize,
+    /// Bytes of integrity tag in every share from `split`
+    pub integrity_bytes: usize,
+    /// Bytes of secret length prefix in every share from `split`
+    pub length_check_bytes: usize,
+    /// Length of `Share::data` for every share from `split`
+    pub share_data_len: usize,
+    /// Number of chunks `split_stream` writes
+    pub stream_chunks: usize,
+    /// Bytes every `split_stream` output adds to the secret: header, length prefixes,
+    /// chunk hashes, compression markers, and the digest trailer
+    pub stream_framing_bytes: usize,
+    /// Total length of every `split_stream` output
+    pub stream_share_len: usize,
+    /// Whether compression may make the actual sizes smaller than reported
+    pub compression: bool,
+}
+
+/// Advice on padding a secret to hide its length, from
+/// [`ShamirShare::padding_recommendation`](crate::ShamirShare::padding_recommendation)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PaddingAdvice {
+    /// Whether padding changes the length, i.e. the length is not already on a bucket
+    /// boundary
+    pub recommended: bool,
+    /// Multiple to pad the secret length up to
+    pub pad_to_multiple: usize,
+    /// Length of the secret after padding
+    pub padded_len: usize,
 }
 
 /// Configuration options for splitting and reconstruction
@@ -28,6 +91,22 @@ pub struct Config {
     pub compression: bool,
     /// Whether to perform integrity checks
     pub integrity_check: bool,
+    /// Scheme used to compute and verify integrity tags when `integrity_check` is enabled
+    pub verifier: Arc<dyn IntegrityVerifier>,
+    /// Whether schemes with `threshold == total_shares` (no fault tolerance) may be built
+    pub allow_no_fault_tolerance: bool,
+    /// Whether polynomial coefficients are derived from the secret instead of the CSPRNG
+    pub deterministic_coefficients: bool,
+    /// Data size in bytes below which chunks are processed serially instead of with rayon
+    pub parallel_threshold: usize,
+    /// Whether stream chunk length prefixes are written big-endian instead of little-endian
+    pub big_endian_lengths: bool,
+    /// Whether the secret length is stored with the secret and checked on reconstruction
+    pub length_check: bool,
+    /// Whether split streams end with a digest of the whole stream
+    pub stream_digest: bool,
+    /// Size of the fixed records shares are stored in, checked when splitting
+    pub fixed_record_size: Option<usize>,
 }
 
 impl Default for Config {
@@ -37,6 +116,14 @@ impl Default for Config {
             mode: SplitMode::default(),
             compression: false,
             integrity_check: true,
+            verifier: Arc::new(Sha256Verifier),
+            allow_no_fault_tolerance: true,
+            deterministic_coefficients: false,
+            parallel_threshold: DEFAULT_PARALLEL_THRESHOLD,
+            big_endian_lengths: false,
+            length_check: false,
+            stream_digest: false,
+            fixed_record_size: None,
         }
     }
 }
@@ -47,13 +134,76 @@ impl Config {
         Self::default()
     }
 
+    /// Creates a configuration pre-filled for a security profile
+    ///
+    /// Fields not listed keep their [`Config::default`] values. GF(256) arithmetic is
+    /// constant-time under every profile; there is no faster variable-time mode to select.
+    /// Refine a preset with the `with_*` methods as usual.
+    ///
+    /// | Field                      | `MaximumSecurity` | `Balanced`   | `Performance` |
+    /// |----------------------------|-------------------|--------------|---------------|
+    /// | `integrity_check`          | `true` (SHA-256)  | `true`       | `false`       |
+    /// | `length_check`             | `true`            | `false`      | `true`        |
+    /// | `compression`              | `false`           | `true`\*     | `true`\*      |
+    /// | `parallel_threshold`       | 4096              | 4096         | 1024          |
+    /// | `allow_no_fault_tolerance` | `false`           | `true`       | `true`        |
+    ///
+    /// \* Only with the `compress` feature; without it `compression` stays `false`.
+    ///
+    /// - `MaximumSecurity` leaves compression off because compressed sizes reveal
+    ///   information about the secret, adds the length check on top of the integrity hash,
+    ///   and makes `build` reject schemes where losing one share loses the secret
+    /// - `Performance` drops the SHA-256 hash and keeps only the 4-byte length check, which
+    ///   catches truncation but not corrupted content. It also spreads chunks from 1 KiB
+    ///   across the rayon thread pool; pass it to
+    ///   [`ShamirShare::reconstruct_with_config`](crate::ShamirShare::reconstruct_with_config)
+    ///   to reconstruct the same way
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, Profile, ShamirShare};
+    ///
+    /// let config = Config::preset(Profile::MaximumSecurity);
+    /// assert!(config.integrity_check);
+    /// assert!(ShamirShare::builder(3, 3).with_config(config).build().is_err());
+    /// ```
+    pub fn preset(profile: Profile) -> Self {
+        let defaults = Self::default();
+        match profile {
+            Profile::MaximumSecurity => Self {
+                integrity_check: true,
+                length_check: true,
+                compression: false,
+                allow_no_fault_tolerance: false,
+                ..defaults
+            },
+            Profile::Balanced => Self {
+                compression: cfg!(feature = "compress"),
+                ..defaults
+            },
+            Profile::Performance => Self {
+                integrity_check: false,
+                length_check: true,
+                compression: cfg!(feature = "compress"),
+                parallel_threshold: PERFORMANCE_PARALLEL_THRESHOLD,
+                ..defaults
+            },
+        }
+    }
+
     /// Sets the chunk size
+    ///
+    /// With integrity checking, stream splitting stores a 32-byte SHA-256 hash with every
+    /// chunk, so the per-chunk overhead is `32 / chunk_size`: about 3% at 1 KiB, 100% at
+    /// 32 bytes, and 640% at 5 bytes. Chunk sizes below 32 bytes are therefore rejected
+    /// while integrity checking is enabled; call `with_integrity_check(false)` first to use
+    /// them.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if `size` is zero, or below 32 bytes with
+    /// integrity checking enabled.
     pub fn with_chunk_size(mut self, size: usize) -> Result<Self> {
-        if size == 0 {
-            return Err(ShamirError::InvalidConfig(
-                "Chunk size cannot be zero".into(),
-            ));
-        }
+        check_chunk_size(size, self.integrity_check)?;
         self.chunk_size = size;
         Ok(self)
     }
@@ -65,6 +215,14 @@ impl Config {
     }
 
     /// Enables or disables compression
+    ///
+    /// Compression is applied per secret (or per stream chunk) and skipped when zstd does
+    /// not make the data smaller, as for tiny or random inputs; shares record which was used.
+    ///
+    /// # Security
+    /// The compression flag every share carries, like the share length, tells anyone
+    /// holding a single share whether the secret compresses, and so something about its
+    /// redundancy. Leave compression off when that must not leak.
     #[cfg(feature = "compress")]
     pub fn with_compression(mut self, enabled: bool) -> Self {
         self.compression = enabled;
@@ -77,11 +235,271 @@ impl Config {
         self
     }
 
+    /// Sets the integrity verifier used when integrity checking is enabled
+    ///
+    /// Replaces the default [`Sha256Verifier`]. Shares record the verifier's algorithm id;
+    /// shares made with a custom verifier are reconstructed with
+    /// [`ShamirShare::reconstruct_with_verifier`](crate::ShamirShare::reconstruct_with_verifier).
+    /// Stream splitting only supports the built-in SHA-256 verifier.
+    pub fn with_verifier(mut self, verifier: Box<dyn IntegrityVerifier>) -> Self {
+        self.verifier = Arc::from(verifier);
+        self
+    }
+
+    /// Allows or rejects schemes where `threshold == total_shares`
+    ///
+    /// Such schemes have zero fault tolerance: losing any single share makes the secret
+    /// unrecoverable. They are allowed by default; pass `false` to make
+    /// `ShamirShareBuilder::build` return `ShamirError::NoFaultTolerance` for them.
+    pub fn with_allow_no_fault_tolerance(mut self, allowed: bool) -> Self {
+        self.allow_no_fault_tolerance = allowed;
+        self
+    }
+
+    /// Enables or disables deterministic, secret-derived polynomial coefficients
+    ///
+    /// When enabled, `split`, `split_bounded`, and `dealer` derive the coefficients with
+    /// HKDF-SHA256 from the secret and a fixed domain label instead of the CSPRNG, so
+    /// splitting the same secret with the same threshold always yields identical shares.
+    /// This makes re-running a backup job idempotent. Stream splitting and share
+    /// refreshing always use the CSPRNG.
+    ///
+    /// # Security
+    /// This weakens the scheme and is disabled by default:
+    /// - Anyone comparing share sets learns whether two splits protect the same secret
+    /// - A single share becomes a test for guesses: an attacker holding fewer than
+    ///   `threshold` shares can split candidate secrets and compare. Only use this for
+    ///   high-entropy secrets such as randomly generated keys, never for passwords
+    pub fn with_deterministic_coefficients(mut self, enabled: bool) -> Self {
+        self.deterministic_coefficients = enabled;
+        self
+    }
+
+    /// Sets the data size below which chunks are processed serially
+    ///
+    /// Stream chunks shorter than `threshold` bytes are split on the calling thread;
+    /// larger ones spread share evaluation across the rayon thread pool. For small secrets
+    /// processed at high frequency, the serial path avoids rayon's scheduling overhead.
+    /// Use `0` to always parallelize and `usize::MAX` to never do so.
+    /// [`ShamirShare::reconstruct`](crate::ShamirShare::reconstruct), which has no
+    /// configuration, always uses the default of 4096 bytes; pass the config to
+    /// [`ShamirShare::reconstruct_with_config`](crate::ShamirShare::reconstruct_with_config)
+    /// to apply the same threshold to reconstruction.
+    pub fn with_parallel_threshold(mut self, threshold: usize) -> Self {
+        self.parallel_threshold = threshold;
+        self
+    }
+
+    /// Returns whether chunks of `len` bytes are processed with rayon
+    pub(crate) fn parallelizes(&self, len: usize) -> bool {
+        len >= self.parallel_threshold
+    }
+
+    /// Writes stream chunk length prefixes in big-endian (network) byte order
+    ///
+    /// Affects `split_stream` only; the stream header records the byte order, so
+    /// `reconstruct_stream` detects it without configuration. Little-endian remains the
+    /// default. Use this to match external tools that document big-endian lengths.
+    pub fn with_big_endian_lengths(mut self, enabled: bool) -> Self {
+        self.big_endian_lengths = enabled;
+        self
+    }
+
+    /// Enables or disables the secret length check
+    ///
+    /// When enabled, `split`, `split_bounded`, and `dealer` prepend the 4-byte length of
+    /// the secret (before compression) to the data being split, and reconstruction fails
+    /// with `ShamirError::SecretLengthMismatch` unless the recovered secret has exactly
+    /// that length. This catches truncated shares for the cost of four bytes per share and
+    /// no hashing, but unlike integrity checking it does not detect corrupted content. It
+    /// is independent of integrity checking; shares record whether it was used. Stream
+    /// splitting ignores it, since stream chunks carry their own lengths.
+    pub fn with_length_check(mut self, enabled: bool) -> Self {
+        self.length_check = enabled;
+        self
+    }
+
+    /// Enables or disables the whole-stream digest
+    ///
+    /// Per-chunk integrity hashes cannot tell whether chunks were dropped from the end of a
+    /// stream or arrived in a different order. When enabled, `split_stream` feeds every
+    /// chunk's plaintext (before compression) into a running SHA-256 and ends each share
+    /// stream with a trailer holding a share of the digest. `reconstruct_stream` hashes
+    /// the plaintext it writes and fails with `ShamirError::IntegrityCheckFailed` if the
+    /// digests differ. Neither side buffers the whole stream, so reconstructed data has
+    /// already been written when the mismatch is reported. The stream header records the
+    /// setting.
+    pub fn with_stream_digest(mut self, enabled: bool) -> Self {
+        self.stream_digest = enabled;
+        self
+    }
+
+    /// Declares that shares are stored in fixed-size records of `size` bytes
+    ///
+    /// Every split method then fails with `ShamirError::InvalidConfig` when the shares do
+    /// not fit, rather than leaving the failure to
+    /// [`Share::to_fixed_record`](crate::Share::to_fixed_record) at storage time. Write the records with `to_fixed_record(size)` and read them back
+    /// with [`Share::from_fixed_record`](crate::Share::from_fixed_record): the length field
+    /// of the share format trims the zero padding deterministically, so reconstruction
+    /// always sees the exact share data.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare, Share};
+    ///
+    /// let config = Config::new().with_fixed_record_size(128);
+    /// let mut scheme = ShamirShare::builder(3, 2).with_config(config).build().unwrap();
+    /// let records: Vec<Vec<u8>> = scheme
+    ///     .split(b"secret")
+    ///     .unwrap()
+    ///     .iter()
+    ///     .map(|share| share.to_fixed_record(128).unwrap())
+    ///     .collect();
+    ///
+    /// let shares: Vec<Share> = records
+    ///     .iter()
+    ///     .map(|record| Share::from_fixed_record(record).unwrap())
+    ///     .collect();
+    /// assert_eq!(ShamirShare::reconstruct(&shares[..2]).unwrap(), b"secret");
+    ///
+    /// // A secret too large for the records fails at split time
+    /// assert!(scheme.split(&[0u8; 200]).is_err());
+    /// ```
+    pub fn with_fixed_record_size(mut self, size: usize) -> Self {
+        self.fixed_record_size = Some(size);
+        self
+    }
+
+    /// Computes the size of the shares this configuration produces for a secret
+    ///
+    /// Consolidates the size arithmetic of `split` and `split_stream` into one
+    /// [`OverheadReport`], to compare options before splitting. Pure: nothing is split.
+    /// Share sizes do not depend on the scheme's share counts.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::Config;
+    ///
+    /// let report = Config::new().with_length_check(true).overhead_bytes(100);
+    /// assert_eq!(report.integrity_bytes, 32);
+    /// assert_eq!(report.share_data_len, 4 + 32 + 100);
+    /// assert_eq!(report.stream_chunks, 1);
+    /// ```
+    pub fn overhead_bytes(&self, secret_len: usize) -> OverheadReport {
+        let integrity_bytes = if self.integrity_check {
+            self.verifier.prefix_len()
+        } else {
+            0
+        };
+        let length_check_bytes = if self.length_check {
+            LENGTH_PREFIX_SIZE
+        } else {
+            0
+        };
+
+        // Streams always hash with SHA-256 and carry their own chunk lengths
+        let stream_chunks = secret_len.div_ceil(self.chunk_size);
+        let chunk_hash = if self.integrity_check {
+            CHUNK_HASH_SIZE
+        } else {
+            0
+        };
+        let compression_marker = usize::from(self.compression);
+        let digest_trailer = if self.stream_digest {
+            2 * STREAM_LENGTH_PREFIX_SIZE + CHUNK_HASH_SIZE
+        } else {
+            0
+        };
+        let stream_framing_bytes = STREAM_HEADER_LEN
+            + stream_chunks * (STREAM_LENGTH_PREFIX_SIZE + chunk_hash + compression_marker)
+            + digest_trailer;
+
+        OverheadReport {
+            secret_len,
+            integrity_bytes,
+            length_check_bytes,
+            share_data_len: length_check_bytes + integrity_bytes + secret_len,
+            stream_chunks,
+            stream_framing_bytes,
+            stream_share_len: stream_framing_bytes + secret_len,
+            compression: self.compression,
+        }
+    }
+
     /// Validates the configuration
     pub fn validate(&self) -> Result<()> {
-        if self.chunk_size == 0 {
+        check_chunk_size(self.chunk_size, self.integrity_check)?;
+        if self.integrity_check {
+            let prefix_len = self.verifier.prefix_len();
+            if prefix_len == 0 {
+                return Err(ShamirError::InvalidConfig(
+                    "Integrity verifier must produce a non-empty tag".into(),
+                ));
+            }
+            let tag_len = self.verifier.compute(&[]).len();
+            if tag_len != prefix_len {
+                return Err(ShamirError::InvalidConfig(format!(
+                    "Integrity verifier produces {tag_len}-byte tags but declares {prefix_len}"
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validates the configuration for a scheme with the given share counts
+    ///
+    /// Runs [`validate`](Self::validate), then checks the rules that involve the scheme
+    /// or the enabled features. `ShamirShareBuilder::build` calls this, so a config that
+    /// passes here builds for that scheme size.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `total_shares` is 0 (`InvalidShareCount`) or `threshold` is 0 (`InvalidThreshold`)
+    /// - `threshold > total_shares` (`ThresholdTooLarge`)
+    /// - `threshold == total_shares` while `allow_no_fault_tolerance` is false
+    ///   (`NoFaultTolerance`)
+    /// - `chunk_size` is zero, or smaller than the 32-byte hash with integrity checking
+    ///   (`InvalidConfig`)
+    /// - With integrity checking, the verifier produces empty tags, or tags whose length
+    ///   differs from its `prefix_len` (`InvalidConfig`)
+    /// - `compression` is set but the crate was built without the `compress` feature
+    ///   (`InvalidConfig`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirError};
+    ///
+    /// let config = Config::new().with_allow_no_fault_tolerance(false);
+    /// assert!(config.validate_for(5, 3).is_ok());
+    /// assert!(matches!(
+    ///     config.validate_for(3, 3),
+    ///     Err(ShamirError::NoFaultTolerance { threshold: 3 })
+    /// ));
+    /// ```
+    pub fn validate_for(&self, total_shares: u8, threshold: u8) -> Result<()> {
+        if total_shares == 0 {
+            return Err(ShamirError::InvalidShareCount(total_shares));
+        }
+        if threshold == 0 {
+            return Err(ShamirError::InvalidThreshold(threshold));
+        }
+        if threshold > total_shares {
+            return Err(ShamirError::ThresholdTooLarge {
+                threshold,
+                total_shares,
+            });
+        }
+        if threshold == total_shares && !self.allow_no_fault_tolerance {
+            return Err(ShamirError::NoFaultTolerance { threshold });
+        }
+
+        self.validate()?;
+
+        // The field is public, so it can be set even where `with_compression` is absent
+        if self.compression && !cfg!(feature = "compress") {
             return Err(ShamirError::InvalidConfig(
-                "Chunk size cannot be zero".into(),
+                "Compression requires the `compress` feature".into(),
             ));
         }
 
@@ -89,6 +507,24 @@ impl Config {
     }
 }
 
+/// Rejects chunk sizes that are zero or dwarfed by the per-chunk integrity hash
+fn check_chunk_size(size: usize, integrity_check: bool) -> Result<()> {
+    if size == 0 {
+        return Err(ShamirError::InvalidConfig(
+            "Chunk size cannot be zero".into(),
+        ));
+    }
+    if integrity_check && size < MIN_INTEGRITY_CHUNK_SIZE {
+        return Err(ShamirError::InvalidConfig(format!(
+            "Chunk size {size} is too small with integrity checking: each chunk carries a \
+             {CHUNK_HASH_SIZE}-byte hash ({}% overhead). Use a chunk size of at least \
+             {MIN_INTEGRITY_CHUNK_SIZE} bytes, or disable integrity checking first",
+            CHUNK_HASH_SIZE * 100 / size
+        )));
+    }
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -100,6 +536,13 @@ mod tests {
         assert_eq!(config.chunk_size, 1024 * 1024);
         assert!(!config.compression);
         assert!(config.integrity_check);
+        assert_eq!(config.verifier.algorithm_id(), 0);
+        assert!(config.allow_no_fault_tolerance);
+        assert!(!config.deterministic_coefficients);
+        assert_eq!(config.parallel_threshold, DEFAULT_PARALLEL_THRESHOLD);
+        assert!(!config.big_endian_lengths);
+        assert!(!config.length_check);
+        assert!(!config.stream_digest);
     }
 
     #[test]
@@ -121,8 +564,207 @@ mod tests {
         assert!(!config.integrity_check);
     }
 
+    #[test]
+    fn test_presets() {
+        let compress = cfg!(feature = "compress");
+
+        let config = Config::preset(Profile::MaximumSecurity);
+        assert!(config.integrity_check);
+        assert_eq!(config.verifier.algorithm_id(), 0);
+        assert!(config.length_check);
+        assert!(!config.compression);
+        assert_eq!(config.parallel_threshold, DEFAULT_PARALLEL_THRESHOLD);
+        assert!(!config.allow_no_fault_tolerance);
+        assert!(!config.deterministic_coefficients);
+        assert!(config.validate().is_ok());
+
+        let config = Config::preset(Profile::Balanced);
+        assert!(config.integrity_check);
+        assert!(!config.length_check);
+        assert_eq!(config.compression, compress);
+        assert_eq!(config.parallel_threshold, DEFAULT_PARALLEL_THRESHOLD);
+        assert!(config.allow_no_fault_tolerance);
+        assert!(!config.deterministic_coefficients);
+        assert!(config.validate().is_ok());
+
+        let config = Config::preset(Profile::Performance);
+        assert!(!config.integrity_check);
+        assert!(config.length_check);
+        assert_eq!(config.compression, compress);
+        assert_eq!(config.parallel_threshold, PERFORMANCE_PARALLEL_THRESHOLD);
+        assert!(config.allow_no_fault_tolerance);
+        assert!(!config.deterministic_coefficients);
+        assert!(config.validate().is_ok());
+
+        for config in [
+            Config::preset(Profile::MaximumSecurity),
+            Config::preset(Profile::Balanced),
+            Config::preset(Profile::Performance),
+        ] {
+            assert_eq!(config.chunk_size, 1024 * 1024);
+            assert_eq!(config.mode, SplitMode::Sequential);
+            assert!(!config.big_endian_lengths);
+        }
+
+        // Performance parallelizes chunks the other presets process serially
+        assert!(Config::preset(Profile::Performance).parallelizes(2048));
+        assert!(!Config::preset(Profile::Balanced).parallelizes(2048));
+        assert!(!Config::preset(Profile::MaximumSecurity).parallelizes(2048));
+    }
+
     #[test]
     fn test_invalid_config() {
         assert!(Config::new().with_chunk_size(0).is_err());
     }
+
+    #[test]
+    fn test_small_chunk_size_with_integrity() {
+        let err = Config::new().with_chunk_size(5).unwrap_err();
+        match err {
+            ShamirError::InvalidConfig(message) => {
+                assert!(message.contains("640% overhead"), "{message}");
+                assert!(message.contains("at least 32 bytes"), "{message}");
+            }
+            other => panic!("unexpected error: {other:?}"),
+        }
+        assert!(Config::new().with_chunk_size(32).is_ok());
+
+        // Tiny chunks are fine without hashes
+        let config = Config::new()
+            .with_integrity_check(false)
+            .with_chunk_size(5)
+            .unwrap();
+        assert!(config.validate().is_ok());
+
+        // Re-enabling integrity afterwards is caught by validation
+        assert!(config.with_integrity_check(true).validate().is_err());
+    }
+
+    #[test]
+    fn test_validate_for() {
+        let config = Config::new();
+        assert!(config.validate_for(5, 3).is_ok());
+        assert!(config.validate_for(1, 1).is_ok());
+
+        // Scheme size
+        assert!(matches!(
+            config.validate_for(0, 1),
+            Err(ShamirError::InvalidShareCount(0))
+        ));
+        assert!(matches!(
+            config.validate_for(3, 0),
+            Err(ShamirError::InvalidThreshold(0))
+        ));
+        assert!(matches!(
+            config.validate_for(3, 4),
+            Err(ShamirError::ThresholdTooLarge {
+                threshold: 4,
+                total_shares: 3
+            })
+        ));
+        let strict = Config::new().with_allow_no_fault_tolerance(false);
+        assert!(strict.validate_for(4, 3).is_ok());
+        assert!(matches!(
+            strict.validate_for(3, 3),
+            Err(ShamirError::NoFaultTolerance { threshold: 3 })
+        ));
+
+        // Tiny chunk under integrity, set directly to bypass `with_chunk_size`
+        let tiny = Config {
+            chunk_size: 8,
+            ..Config::new()
+        };
+        assert!(matches!(
+            tiny.validate_for(5, 3),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        let tiny = Config {
+            integrity_check: false,
+            ..tiny
+        };
+        assert!(tiny.validate_for(5, 3).is_ok());
+
+        // Compression needs the feature
+        let compressed = Config {
+            compression: true,
+            ..Config::new()
+        };
+        assert_eq!(
+            compressed.validate_for(5, 3).is_ok(),
+            cfg!(feature = "compress")
+        );
+    }
+
+    #[test]
+    fn test_overhead_bytes_matches_split_output() {
+        use crate::ShamirShare;
+        use std::io::Cursor;
+
+        let configs = [
+            Config::new(),
+            Config::new().with_integrity_check(false),
+            Config::new().with_length_check(true),
+            Config::new().with_chunk_size(100).unwrap(),
+            Config::new()
+                .with_chunk_size(64)
+                .unwrap()
+                .with_stream_digest(true)
+                .with_length_check(true),
+            Config::new()
+                .with_integrity_check(false)
+                .with_chunk_size(7)
+                .unwrap(),
+        ];
+        // Random-looking data, which compression would not shrink
+        let secret: Vec<u8> = (0..250u32).map(|i| (i * 167 + 13) as u8).collect();
+
+        for config in configs {
+            for len in [0, 1, 99, 100, 250] {
+                let report = config.overhead_bytes(len);
+                let mut scheme = ShamirShare::builder(3, 2)
+                    .with_config(config.clone())
+                    .build()
+                    .unwrap();
+
+                if len > 0 {
+                    let shares = scheme.split(&secret[..len]).unwrap();
+                    assert_eq!(shares[0].data.len(), report.share_data_len);
+                }
+
+                let mut outputs = vec![Cursor::new(Vec::new()); 3];
+                scheme
+                    .split_stream(&mut Cursor::new(&secret[..len]), &mut outputs)
+                    .unwrap();
+                assert_eq!(outputs[0].get_ref().len(), report.stream_share_len);
+                assert_eq!(
+                    report.stream_share_len,
+                    report.stream_framing_bytes + report.secret_len
+                );
+            }
+        }
+
+        // With compression the figures bound the actual sizes
+        let config = Config {
+            compression: true,
+            ..Config::new().with_chunk_size(64).unwrap()
+        };
+        let report = config.overhead_bytes(250);
+        assert!(report.compression);
+        assert_eq!(report.stream_framing_bytes, 7 + 4 * (4 + 32 + 1));
+        #[cfg(feature = "compress")]
+        {
+            let mut scheme = ShamirShare::builder(3, 2)
+                .with_config(config)
+                .build()
+                .unwrap();
+            let repetitive = [b'r'; 250];
+            let shares = scheme.split(&repetitive).unwrap();
+            assert!(shares[0].data.len() < report.share_data_len);
+            let mut outputs = vec![Cursor::new(Vec::new()); 3];
+            scheme
+                .split_stream(&mut Cursor::new(&repetitive), &mut outputs)
+                .unwrap();
+            assert!(outputs[0].get_ref().len() < report.stream_share_len);
+        }
+    }
 }
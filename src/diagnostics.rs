@@ -0,0 +1,274 @@
+//! Diagnostic helpers for debugging share generation
+//!
+//! This module is only available with the `diagnostics` feature. Nothing here is a
+//! security guarantee; these helpers exist to catch gross failures such as a broken RNG
+//! during development and testing.
+
+use crate::error::{Result, ShamirError};
+use crate::finite_field::FiniteField;
+use crate::shamir::{ShamirShare, Share};
+
+impl Share {
+    /// Estimates the Shannon entropy of the share data, in bits per byte (0.0 to 8.0)
+    ///
+    /// Share data produced with a working CSPRNG looks uniformly random, so large shares
+    /// score close to 8.0. A score near zero means the data is nearly constant, which
+    /// points at a broken RNG producing constant coefficients. Short shares cannot reach
+    /// 8.0 (at most `log2(len)`), so compare against the data length. Empty data scores 0.0.
+    ///
+    /// This is a diagnostic aid only: high entropy does not prove the shares are secure.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(&[0u8; 4096]).unwrap();
+    /// assert!(shares[0].entropy_estimate() > 7.5);
+    /// ```
+    pub fn entropy_estimate(&self) -> f64 {
+        if self.data.is_empty() {
+            return 0.0;
+        }
+
+        let mut counts = [0usize; 256];
+        for &byte in &self.data {
+            counts[byte as usize] += 1;
+        }
+
+        let len = self.data.len() as f64;
+        counts
+            .iter()
+            .filter(|&&count| count > 0)
+            .map(|&count| {
+                let p = count as f64 / len;
+                -p * p.log2()
+            })
+            .sum()
+    }
+}
+
+impl ShamirShare {
+    /// Infers the lowest polynomial degree consistent with the shares, to check their
+    /// threshold
+    ///
+    /// The shares of one split lie on a polynomial of degree `threshold - 1` at every byte
+    /// position. For each position this finds the degree of the lowest-degree polynomial
+    /// through all shares, from Newton divided differences, and returns the highest. A
+    /// degree above `threshold - 1` means the shares do not belong together: one is
+    /// corrupted, or they come from different splits, refresh generations or thresholds.
+    ///
+    /// Any `m` shares fit a polynomial of degree `m - 1`, so a mismatch can only show with
+    /// at least `threshold + 1` shares. For very short secrets the result can be below
+    /// `threshold - 1`, when the leading coefficient happens to be zero at every position.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `shares` is empty (`InsufficientShares`)
+    /// - Share indices are duplicated or 0 (`InvalidShareFormat`)
+    /// - Share data lengths differ (`InconsistentShareLength`)
+    /// - The inferred degree exceeds `threshold - 1`, taking the threshold of the first
+    ///   share (`PolynomialDegreeMismatch`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let mut shares = scheme.split(b"debug me").unwrap();
+    /// assert_eq!(ShamirShare::infer_polynomial_degree(&shares).unwrap(), 2);
+    ///
+    /// shares[4].data[0] ^= 1;
+    /// assert!(matches!(
+    ///     ShamirShare::infer_polynomial_degree(&shares),
+    ///     Err(ShamirError::PolynomialDegreeMismatch { declared: 2, inferred: 4 })
+    /// ));
+    /// ```
+    pub fn infer_polynomial_degree(shares: &[Share]) -> Result<usize> {
+        let Some(first) = shares.first() else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+        if shares
+            .iter()
+            .any(|share| share.data.len() != first.data.len())
+        {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+        let xs: Vec<FiniteField> = shares
+            .iter()
+            .map(|share| FiniteField::new(share.index))
+            .collect();
+        if xs.contains(&FiniteField::new(0)) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        // inverses[level][i] = 1 / (x_i - x_(i - level)), the same for every byte position
+        let mut inverses = vec![Vec::new(); xs.len()];
+        for (level, row) in inverses.iter_mut().enumerate().skip(1) {
+            *row = (level..xs.len())
+                .map(|i| (xs[i] - xs[i - level]).inverse())
+                .collect::<Option<Vec<_>>>()
+                .ok_or(ShamirError::InvalidShareFormat)?;
+        }
+
+        let mut coefficients = vec![FiniteField::new(0); xs.len()];
+        let mut degree = 0;
+        for byte_idx in 0..first.data.len() {
+            for (coefficient, share) in coefficients.iter_mut().zip(shares) {
+                *coefficient = FiniteField::new(share.data[byte_idx]);
+            }
+            // Turn the values into Newton coefficients in place
+            for level in 1..xs.len() {
+                for i in (level..xs.len()).rev() {
+                    coefficients[i] =
+                        (coefficients[i] - coefficients[i - 1]) * inverses[level][i - level];
+                }
+            }
+            // The Newton basis polynomial of coefficient k has degree k
+            let byte_degree = coefficients
+                .iter()
+                .rposition(|&c| c != FiniteField::new(0))
+                .unwrap_or(0);
+            degree = degree.max(byte_degree);
+        }
+
+        let declared = first.threshold.saturating_sub(1) as usize;
+        if degree > declared {
+            return Err(ShamirError::PolynomialDegreeMismatch {
+                declared,
+                inferred: degree,
+            });
+        }
+        Ok(degree)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, ShamirShare};
+
+    #[test]
+    fn test_normal_shares_have_high_entropy() {
+        // A constant secret, so any entropy comes from the random coefficients
+        let secret = vec![0x42u8; 16 * 1024];
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        for share in shamir.split(&secret).unwrap() {
+            let entropy = share.entropy_estimate();
+            assert!(entropy > 7.9, "share {} entropy {entropy}", share.index);
+        }
+    }
+
+    #[test]
+    fn test_constant_share_has_low_entropy() {
+        let share = Share {
+            index: 1,
+            data: vec![0u8; 4096],
+            threshold: 3,
+            total_shares: 5,
+            integrity_check: false,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+        assert_eq!(share.entropy_estimate(), 0.0);
+
+        // Two alternating values carry exactly one bit per byte
+        let mut share = share;
+        share.data = [0xAA, 0x55].repeat(2048);
+        assert!((share.entropy_estimate() - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_empty_share_entropy() {
+        let share = Share {
+            index: 1,
+            data: Vec::new(),
+            threshold: 1,
+            total_shares: 1,
+            integrity_check: false,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+        assert_eq!(share.entropy_estimate(), 0.0);
+    }
+
+    #[test]
+    fn test_infer_degree_of_consistent_shares() {
+        for threshold in [1, 2, 4, 7] {
+            let mut scheme = ShamirShare::builder(9, threshold).build().unwrap();
+            let shares = scheme.split(b"consistent share set").unwrap();
+            let declared = threshold as usize - 1;
+            assert_eq!(
+                ShamirShare::infer_polynomial_degree(&shares).unwrap(),
+                declared
+            );
+            // Any subset of at least threshold shares shows the same degree
+            let subset: Vec<Share> = shares.iter().rev().step_by(2).cloned().collect();
+            if subset.len() >= threshold as usize {
+                assert_eq!(
+                    ShamirShare::infer_polynomial_degree(&subset).unwrap(),
+                    declared
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_infer_degree_flags_inconsistent_shares() {
+        let mut scheme = ShamirShare::builder(6, 3).build().unwrap();
+        let shares = scheme.split(b"malformed set").unwrap();
+        let mismatch = |shares: &[Share]| match ShamirShare::infer_polynomial_degree(shares) {
+            Err(ShamirError::PolynomialDegreeMismatch { declared, inferred }) => {
+                assert_eq!(declared, 2);
+                inferred
+            }
+            other => panic!("expected a degree mismatch, got {other:?}"),
+        };
+
+        // One corrupted byte in one share raises the degree to fit every share
+        let mut corrupted = shares.clone();
+        corrupted[2].data[5] ^= 0x80;
+        assert_eq!(mismatch(&corrupted), 5);
+
+        // Shares of two splits of the same secret
+        let other = scheme.split(b"malformed set").unwrap();
+        let mixed = [&shares[..3], &other[3..4]].concat();
+        assert_eq!(mismatch(&mixed), 3);
+
+        // Shares of a threshold 4 split declaring threshold 3
+        let mut scheme = ShamirShare::builder(5, 4).build().unwrap();
+        let mut relabeled = scheme.split(b"malformed set").unwrap();
+        for share in &mut relabeled {
+            share.threshold = 3;
+        }
+        assert_eq!(mismatch(&relabeled), 3);
+        // With only threshold shares the mismatch cannot show
+        assert_eq!(
+            ShamirShare::infer_polynomial_degree(&relabeled[..3]).unwrap(),
+            2
+        );
+
+        let duplicated = [shares[0].clone(), shares[0].clone()];
+        assert!(matches!(
+            ShamirShare::infer_polynomial_degree(&duplicated),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(matches!(
+            ShamirShare::infer_polynomial_degree(&[]),
+            Err(ShamirError::InsufficientShares { .. })
+        ));
+    }
+}
@@ -0,0 +1,1298 @@
+//! Text encodings for transporting shares outside of `FileShareStore`
+//!
+//! Shares are encoded using the same binary layout as `FileShareStore` share files, so a
+//! decoded share carries its full metadata (index, threshold, flags) and not just its data.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use sha2::{Digest, Sha256};
+
+use crate::error::{Result, ShamirError};
+use crate::integrity::{SHA256_ALGORITHM_ID, crc32};
+use crate::shamir::{ShamirShare, Share};
+use crate::storage::{read_share, write_share};
+
+const PAPER_BEGIN: &str = "-----BEGIN SHAMIR SHARE-----";
+const PAPER_END: &str = "-----END SHAMIR SHARE-----";
+const PAPER_CHECKSUM_LABEL: &str = "CRC32:";
+/// RFC 4648 base32 alphabet, free of the easily confused digits 0, 1 and 8
+const BASE32_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+const FINGERPRINT_LEN: usize = 6;
+const FINGERPRINT_DOMAIN: &[u8] = b"shamir_share/v1/share-fingerprint";
+const TRANSPOSED_MAGIC: &[u8] = b"SHST";
+const TRANSPOSED_VERSION: u8 = 2; // Version 2 adds the refresh generation
+/// Header length of version 1 sets, which have no refresh generation
+const TRANSPOSED_V1_HEADER_LEN: usize = 14;
+const TRANSPOSED_HEADER_LEN: usize = 18;
+
+/// Formats a checksum as two dash-separated groups of hex digits, e.g. `CBF4-3926`
+fn format_checksum(crc: u32) -> String {
+    format!("{:04X}-{:04X}", crc >> 16, crc & 0xFFFF)
+}
+
+/// Returns the value of one hex digit of either case
+fn hex_digit(digit: u8) -> Result<u8> {
+    (digit as char)
+        .to_digit(16)
+        .map(|value| value as u8)
+        .ok_or(ShamirError::InvalidShareFormat)
+}
+
+/// Returns the flags byte of a share, with bits as in share files
+fn share_flags(share: &Share) -> u8 {
+    u8::from(share.integrity_check)
+        | (u8::from(share.compression) << 1)
+        | (u8::from(share.length_check) << 2)
+}
+
+/// Feeds the fields that identify `share` into `hasher`, in a fixed layout
+///
+/// Layout: `[index][threshold][total_shares][flags][integrity algorithm]
+/// [4-byte BE refresh generation][8-byte BE data length][data]`. Unlike the binary share
+/// format it never changes with the format version and leaves out the unauthenticated
+/// `metadata` and `not_before` hints, so fingerprints survive format upgrades and label
+/// edits. Callers prefix their own domain.
+pub(crate) fn hash_share_identity(hasher: &mut Sha256, share: &Share) {
+    hasher.update([
+        share.index,
+        share.threshold,
+        share.total_shares,
+        share_flags(share),
+        share.integrity_algorithm,
+    ]);
+    hasher.update(share.refresh_generation.to_be_bytes());
+    hasher.update((share.data.len() as u64).to_be_bytes());
+    hasher.update(&share.data);
+}
+
+/// Appends the data of `shares`, which must be equally long, byte-position-major
+fn write_columns(shares: &[Share], out: &mut Vec<u8>) {
+    let len = shares.first().map_or(0, |share| share.data.len());
+    for position in 0..len {
+        out.extend(shares.iter().map(|share| share.data[position]));
+    }
+}
+
+/// Collects column `column` of `count` interleaved columns
+fn read_column(columns: &[u8], column: usize, count: usize) -> Vec<u8> {
+    columns
+        .iter()
+        .skip(column)
+        .step_by(count)
+        .copied()
+        .collect()
+}
+
+/// Serializes a share into the binary share format
+///
+/// # Panics
+/// Panics if the share's metadata or data exceeds `u32::MAX` bytes, which the format's
+/// length fields cannot record.
+pub(crate) fn share_to_bytes(share: &Share) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(34 + share.metadata.len() + share.data.len());
+    // Writing into a Vec cannot fail; only oversized fields are rejected
+    write_share(&mut bytes, share).expect("share fields must fit a 4-byte length");
+    bytes
+}
+
+/// Reads one share from the front of `reader`, reporting truncation as a format error
+fn read_share_from_slice(reader: &mut &[u8]) -> Result<Share> {
+    read_share(reader).map_err(|e| match e {
+        ShamirError::IoError(_) => ShamirError::InvalidShareFormat,
+        other => other,
+    })
+}
+
+/// Parses a share from the binary share format, rejecting truncated or trailing data
+pub(crate) fn share_from_bytes(bytes: &[u8]) -> Result<Share> {
+    let mut reader = bytes;
+    let share = read_share_from_slice(&mut reader)?;
+    if !reader.is_empty() {
+        return Err(ShamirError::InvalidShareFormat);
+    }
+    Ok(share)
+}
+
+impl Share {
+    /// Serializes the share (data and metadata) into the binary share format
+    ///
+    /// This is the layout of `FileShareStore` share files and the payload of the text
+    /// encodings.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let bytes = shares[0].to_bytes();
+    /// assert_eq!(Share::from_bytes(&bytes).unwrap(), shares[0]);
+    /// ```
+    pub fn to_bytes(&self) -> Vec<u8> {
+        share_to_bytes(self)
+    }
+
+    /// Parses a share from the binary share format produced by [`Share::to_bytes`]
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The share was written by a newer library version (`UnsupportedVersion`, carrying
+    ///   the version found and the newest version this library reads)
+    /// - The bytes are truncated, have trailing data, or are otherwise malformed
+    ///   (`InvalidShareFormat`)
+    /// - The bytes do not match their checksum (`ShareFileCorrupt`)
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self> {
+        share_from_bytes(bytes)
+    }
+
+    /// Encodes the share (data and metadata) as a single-line standard base64 string
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let encoded = shares[0].to_base64();
+    /// assert_eq!(Share::from_base64(&encoded).unwrap(), shares[0]);
+    /// ```
+    pub fn to_base64(&self) -> String {
+        STANDARD.encode(share_to_bytes(self))
+    }
+
+    /// Decodes a share produced by [`Share::to_base64`]
+    ///
+    /// This is the strict variant: the input must be a single unbroken base64 string.
+    /// Any whitespace, including line breaks, is rejected. Use
+    /// [`Share::from_base64_lenient`] for text copied from emails or wrapped files.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareFormat` if the input is not valid base64 or does
+    /// not contain a well-formed share.
+    pub fn from_base64(encoded: &str) -> Result<Self> {
+        let bytes = STANDARD
+            .decode(encoded)
+            .map_err(|_| ShamirError::InvalidShareFormat)?;
+        share_from_bytes(&bytes)
+    }
+
+    /// Decodes a base64 share, tolerating line wrapping and stray whitespace
+    ///
+    /// Spaces, tabs, and line breaks (`\n` and `\r\n`) anywhere in the input, including a
+    /// trailing newline, are stripped before decoding.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let encoded = shares[0].to_base64();
+    /// let (head, tail) = encoded.split_at(8);
+    /// let wrapped = format!("{head}\r\n  {tail}\n");
+    ///
+    /// assert_eq!(Share::from_base64_lenient(&wrapped).unwrap(), shares[0]);
+    /// ```
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareFormat` if the remaining input is not valid base64
+    /// or does not contain a well-formed share.
+    pub fn from_base64_lenient(encoded: &str) -> Result<Self> {
+        let compact: String = encoded
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace())
+            .collect();
+        Self::from_base64(&compact)
+    }
+
+    /// Encodes the share (data and metadata) as a lowercase hex string
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let encoded = shares[0].to_hex();
+    /// assert_eq!(Share::from_hex(&encoded).unwrap(), shares[0]);
+    /// ```
+    pub fn to_hex(&self) -> String {
+        share_to_bytes(self)
+            .iter()
+            .map(|byte| format!("{byte:02x}"))
+            .collect()
+    }
+
+    /// Decodes a share produced by [`Share::to_hex`]
+    ///
+    /// Upper- and lowercase digits are accepted, and whitespace anywhere in the input is
+    /// ignored.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareFormat` if the input has an odd number of digits,
+    /// contains a non-hex character, or does not contain a well-formed share.
+    pub fn from_hex(encoded: &str) -> Result<Self> {
+        let digits: Vec<u8> = encoded
+            .bytes()
+            .filter(|b| !b.is_ascii_whitespace())
+            .collect();
+        if !digits.len().is_multiple_of(2) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        let bytes = digits
+            .chunks(2)
+            .map(|pair| Ok((hex_digit(pair[0])? << 4) | hex_digit(pair[1])?))
+            .collect::<Result<Vec<u8>>>()?;
+        share_from_bytes(&bytes)
+    }
+
+    /// Serializes the share into a zero-padded record of exactly `record_size` bytes
+    ///
+    /// For storage that requires fixed-size records. The record uses the binary share
+    /// format, whose length field lets [`Share::from_fixed_record`] strip the padding
+    /// deterministically. A share without metadata or a not-before time takes 22 bytes
+    /// plus its data. Metadata adds 4 bytes plus its own length, and a not-before time
+    /// adds 8 bytes plus those 4, even without metadata.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if the encoded share does not fit in
+    /// `record_size` bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let record = shares[0].to_fixed_record(128).unwrap();
+    /// assert_eq!(record.len(), 128);
+    /// assert_eq!(Share::from_fixed_record(&record).unwrap(), shares[0]);
+    /// ```
+    pub fn to_fixed_record(&self, record_size: usize) -> Result<Vec<u8>> {
+        let mut record = share_to_bytes(self);
+        if record.len() > record_size {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Share needs {} bytes but the record size is {record_size}",
+                record.len()
+            )));
+        }
+        record.resize(record_size, 0);
+        Ok(record)
+    }
+
+    /// Parses a share from a fixed-size record, trimming trailing zero padding
+    ///
+    /// The share's own length field decides where the data ends, so padding is never
+    /// mistaken for share data.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareFormat` if the record does not start with a
+    /// well-formed share or the bytes after it are not all zero.
+    pub fn from_fixed_record(record: &[u8]) -> Result<Self> {
+        let mut reader = record;
+        let share = read_share_from_slice(&mut reader)?;
+        if reader.iter().any(|&byte| byte != 0) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        Ok(share)
+    }
+
+    /// Formats the share as a printable block for paper backups
+    ///
+    /// The block contains a human-readable header with the share index, the share encoded
+    /// as base64 (including all metadata) wrapped to `columns` characters per line, and a
+    /// CRC-32 checksum of the encoded share that can be compared by eye after re-typing:
+    ///
+    /// ```text
+    /// Shamir share 2 of 5 (threshold 3)
+    /// -----BEGIN SHAMIR SHARE-----
+    /// U0hTMQMBAAIDBSwAAAA...
+    /// -----END SHAMIR SHARE-----
+    /// CRC32: 1A2B-3C4D
+    /// ```
+    ///
+    /// A `columns` value of 0 puts the base64 data on a single line.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"cold storage").unwrap();
+    ///
+    /// let printed = shares[1].to_paper_backup(32);
+    /// assert!(printed.starts_with("Shamir share 2 of 5 (threshold 3)"));
+    /// assert_eq!(Share::from_paper_backup(&printed).unwrap(), shares[1]);
+    /// ```
+    pub fn to_paper_backup(&self, columns: usize) -> String {
+        let bytes = share_to_bytes(self);
+        let encoded = STANDARD.encode(&bytes);
+        let width = if columns == 0 {
+            encoded.len().max(1)
+        } else {
+            columns
+        };
+
+        let mut block = format!(
+            "Shamir share {} of {} (threshold {})\n{PAPER_BEGIN}\n",
+            self.index, self.total_shares, self.threshold
+        );
+        for line in encoded.as_bytes().chunks(width) {
+            // base64 output is ASCII, so any byte boundary is a char boundary
+            block.push_str(std::str::from_utf8(line).expect("base64 is ASCII"));
+            block.push('\n');
+        }
+        block.push_str(PAPER_END);
+        block.push('\n');
+        block.push_str(&format!(
+            "{PAPER_CHECKSUM_LABEL} {}\n",
+            format_checksum(crc32(&bytes))
+        ));
+        block
+    }
+
+    /// Returns a short code identifying this exact share, for comparing shares by voice
+    ///
+    /// The code is the first 30 bits of a SHA-256 hash over the share's index, scheme
+    /// parameters, flags, refresh generation and data, written as 6 base32 characters,
+    /// e.g. `K7QX2M`. The public [`metadata`](Share::metadata) and
+    /// [`not_before`](Share::not_before) hints are not covered, and the code does not
+    /// depend on the share format version. Two holders reading their codes to each other
+    /// can catch a corrupted share or a share from the wrong set without revealing the
+    /// share itself.
+    ///
+    /// A differing share yields the same code with probability 2^-30 (about one in a
+    /// billion). That is ample for catching accidents, but the code is too short to resist
+    /// an attacker deliberately crafting a colliding share.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let code = shares[0].short_fingerprint();
+    /// assert_eq!(code.len(), 6);
+    /// assert_eq!(shares[0].clone().short_fingerprint(), code);
+    /// ```
+    pub fn short_fingerprint(&self) -> String {
+        let mut hasher = Sha256::new_with_prefix(FINGERPRINT_DOMAIN);
+        hash_share_identity(&mut hasher, self);
+        let digest = hasher.finalize();
+        let bits = u32::from_be_bytes([digest[0], digest[1], digest[2], digest[3]]);
+        (0..FINGERPRINT_LEN)
+            .map(|i| {
+                let group = (bits >> (32 - 5 * (i + 1))) & 0x1F;
+                BASE32_ALPHABET[group as usize] as char
+            })
+            .collect()
+    }
+
+    /// Parses a block produced by [`Share::to_paper_backup`]
+    ///
+    /// The parser tolerates the usual damage from printing and re-typing or scanning:
+    /// blank lines, indentation, trailing spaces, CRLF line endings, lowercase hex in the
+    /// checksum, and a different column width. The header line is informational only; the
+    /// share metadata comes from the encoded data itself.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The begin/end markers or the checksum line are missing (`InvalidShareFormat`)
+    /// - The base64 data does not decode to a well-formed share (`InvalidShareFormat`)
+    /// - The checksum does not match the decoded data (`IntegrityCheckFailed`)
+    pub fn from_paper_backup(text: &str) -> Result<Self> {
+        let mut lines = text.lines().map(str::trim).filter(|line| !line.is_empty());
+
+        if !lines.by_ref().any(|line| line == PAPER_BEGIN) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        let mut encoded = String::new();
+        let mut terminated = false;
+        for line in lines.by_ref() {
+            if line == PAPER_END {
+                terminated = true;
+                break;
+            }
+            encoded.push_str(line);
+        }
+        if !terminated {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        let checksum = lines
+            .find_map(|line| line.strip_prefix(PAPER_CHECKSUM_LABEL))
+            .ok_or(ShamirError::InvalidShareFormat)?;
+        let checksum_digits: String = checksum
+            .chars()
+            .filter(|c| !c.is_ascii_whitespace() && *c != '-')
+            .collect();
+        let expected_crc = u32::from_str_radix(&checksum_digits, 16)
+            .map_err(|_| ShamirError::InvalidShareFormat)?;
+
+        let bytes = STANDARD
+            .decode(encoded.split_ascii_whitespace().collect::<String>())
+            .map_err(|_| ShamirError::InvalidShareFormat)?;
+        if crc32(&bytes) != expected_crc {
+            return Err(ShamirError::IntegrityCheckFailed);
+        }
+
+        share_from_bytes(&bytes)
+    }
+}
+
+/// A share in one of the supported encodings, for reconstructing from mixed sources
+///
+/// Holders often return their shares in different forms: a raw file, a base64 string
+/// from a password manager, a hex dump, a re-typed paper backup. Pass them together to
+/// [`ShamirShare::reconstruct_mixed`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ShareInput {
+    /// The binary share format, as produced by [`Share::to_bytes`]
+    Bytes(Vec<u8>),
+    /// Base64 as produced by [`Share::to_base64`]; whitespace and line wrapping are
+    /// tolerated
+    Base64(String),
+    /// Hex as produced by [`Share::to_hex`]
+    Hex(String),
+    /// A block produced by [`Share::to_paper_backup`]
+    PaperBackup(String),
+}
+
+impl ShareInput {
+    /// Decodes the input into a share
+    ///
+    /// # Errors
+    /// Returns any error from the matching decoder, e.g. [`Share::from_hex`].
+    pub fn decode(&self) -> Result<Share> {
+        match self {
+            Self::Bytes(bytes) => Share::from_bytes(bytes),
+            Self::Base64(encoded) => Share::from_base64_lenient(encoded),
+            Self::Hex(encoded) => Share::from_hex(encoded),
+            Self::PaperBackup(text) => Share::from_paper_backup(text),
+        }
+    }
+}
+
+impl ShamirShare {
+    /// Reconstructs the secret from shares supplied in different encodings
+    ///
+    /// Every input is decoded with [`ShareInput::decode`] before reconstruction.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Any input fails to decode (the error of the first such input)
+    /// - Any error from [`ShamirShare::reconstruct`] for the decoded shares
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, ShareInput};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let inputs = [
+    ///     ShareInput::Hex(shares[0].to_hex()),
+    ///     ShareInput::Base64(shares[2].to_base64()),
+    /// ];
+    /// assert_eq!(ShamirShare::reconstruct_mixed(&inputs).unwrap(), b"secret");
+    /// ```
+    pub fn reconstruct_mixed(inputs: &[ShareInput]) -> Result<Vec<u8>> {
+        let shares = inputs
+            .iter()
+            .map(ShareInput::decode)
+            .collect::<Result<Vec<Share>>>()?;
+        Self::reconstruct(&shares)
+    }
+
+    /// Splits `secret` and encodes the shares in the transposed layout
+    ///
+    /// Equivalent to [`encode_transposed`](Self::encode_transposed) of
+    /// [`split`](Self::split).
+    ///
+    /// # Errors
+    /// Returns any error from [`split`](Self::split).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let columnar = scheme.split_transposed(b"columnar export").unwrap();
+    ///
+    /// let secret = ShamirShare::reconstruct_transposed(&columnar).unwrap();
+    /// assert_eq!(secret, b"columnar export");
+    /// ```
+    pub fn split_transposed(&mut self, secret: &[u8]) -> Result<Vec<u8>> {
+        let shares = self.split(secret)?;
+        Self::encode_transposed(&shares)
+    }
+
+    /// Encodes a set of shares in the transposed (byte-position-major) layout
+    ///
+    /// Some columnar tools store a share set with the first byte of every share
+    /// together, then every second byte, and so on. This layout holds all shares in one
+    /// buffer:
+    ///
+    /// `[magic "SHST"][version][flags][integrity algorithm][threshold][total_shares]
+    /// [count][4-byte LE refresh generation, from version 2][4-byte LE data length]
+    /// [indices][data]`
+    ///
+    /// where `data` holds byte `j` of each share, in the order of `indices`, before
+    /// byte `j + 1`. Flags are as in share files. Sets of never-refreshed shares are
+    /// written as version 1, without the refresh generation, so older readers still
+    /// decode them. The distinct magic keeps the layout from being mistaken for the
+    /// per-share format: [`Share::from_bytes`] rejects it, and
+    /// [`decode_transposed`](Self::decode_transposed) rejects share files. Tools that
+    /// expect bare columns without this header take
+    /// [`encode_columns`](Self::encode_columns) instead.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `shares` is empty (`InsufficientShares`) or holds more than 255 shares
+    ///   (`InvalidConfig`)
+    /// - The shares disagree on threshold, total share count, flags or integrity
+    ///   algorithm (`MixedShareSets`), or on refresh generation (`MixedGenerations`)
+    /// - Share data lengths differ (`InconsistentShareLength`)
+    /// - Any share carries [`metadata`](Share::metadata) or a
+    ///   [`not_before`](Share::not_before) time, which the layout has no room for
+    ///   (`InvalidConfig`)
+    pub fn encode_transposed(shares: &[Share]) -> Result<Vec<u8>> {
+        let Some(first) = shares.first() else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+        let count = u8::try_from(shares.len()).map_err(|_| {
+            ShamirError::InvalidConfig(format!(
+                "A transposed set holds at most 255 shares, got {}",
+                shares.len()
+            ))
+        })?;
+        for share in shares {
+            if share_flags(share) != share_flags(first)
+                || share.integrity_algorithm != first.integrity_algorithm
+                || share.threshold != first.threshold
+                || share.total_shares != first.total_shares
+            {
+                return Err(ShamirError::MixedShareSets);
+            }
+            if share.refresh_generation != first.refresh_generation {
+                return Err(ShamirError::MixedGenerations);
+            }
+            if share.data.len() != first.data.len() {
+                return Err(ShamirError::InconsistentShareLength);
+            }
+            if !share.metadata.is_empty() || share.not_before.is_some() {
+                return Err(ShamirError::InvalidConfig(format!(
+                    "Share {} carries metadata or a not-before time, which a transposed set cannot hold",
+                    share.index
+                )));
+            }
+        }
+
+        let len = first.data.len();
+        let version = if first.refresh_generation == 0 {
+            1
+        } else {
+            TRANSPOSED_VERSION
+        };
+        let mut bytes = Vec::with_capacity(TRANSPOSED_HEADER_LEN + shares.len() * (len + 1));
+        bytes.extend_from_slice(TRANSPOSED_MAGIC);
+        bytes.extend_from_slice(&[
+            version,
+            share_flags(first),
+            first.integrity_algorithm,
+            first.threshold,
+            first.total_shares,
+            count,
+        ]);
+        if version >= 2 {
+            bytes.extend_from_slice(&first.refresh_generation.to_le_bytes());
+        }
+        bytes.extend_from_slice(&(len as u32).to_le_bytes());
+        bytes.extend(shares.iter().map(|share| share.index));
+        write_columns(shares, &mut bytes);
+        Ok(bytes)
+    }
+
+    /// Decodes a share set from the layout written by
+    /// [`encode_transposed`](Self::encode_transposed)
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The buffer does not start with the transposed layout's magic, is truncated, or
+    ///   has trailing data (`InvalidShareFormat`)
+    /// - The buffer was written by a newer library version (`UnsupportedVersion`)
+    pub fn decode_transposed(bytes: &[u8]) -> Result<Vec<Share>> {
+        let Some((&[magic @ .., version], _)) = bytes.split_first_chunk::<5>() else {
+            return Err(ShamirError::InvalidShareFormat);
+        };
+        if magic != TRANSPOSED_MAGIC {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        if version > TRANSPOSED_VERSION {
+            return Err(ShamirError::UnsupportedVersion {
+                found: version,
+                max_supported: TRANSPOSED_VERSION,
+            });
+        }
+        // Version 1 sets predate refresh generations
+        let header_len = if version >= 2 {
+            TRANSPOSED_HEADER_LEN
+        } else {
+            TRANSPOSED_V1_HEADER_LEN
+        };
+        let Some((header, rest)) = bytes.split_at_checked(header_len) else {
+            return Err(ShamirError::InvalidShareFormat);
+        };
+        let [flags, integrity_algorithm, threshold, total_shares, count] =
+            [header[5], header[6], header[7], header[8], header[9]];
+        let count = count as usize;
+        let mut refresh_generation = [0u8; 4];
+        if version >= 2 {
+            refresh_generation.copy_from_slice(&header[10..14]);
+        }
+        let refresh_generation = u32::from_le_bytes(refresh_generation);
+        let mut len = [0u8; 4];
+        len.copy_from_slice(&header[header_len - 4..]);
+        let len = u32::from_le_bytes(len) as usize;
+        let (indices, data) = rest.split_at_checked(count).unwrap_or((rest, &[]));
+        if indices.len() != count || Some(data.len()) != len.checked_mul(count) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        Ok(indices
+            .iter()
+            .enumerate()
+            .map(|(column, &index)| Share {
+                index,
+                data: read_column(data, column, count),
+                threshold,
+                total_shares,
+                integrity_check: flags & 1 != 0,
+                integrity_algorithm,
+                compression: flags & 2 != 0,
+                length_check: flags & 4 != 0,
+                refresh_generation,
+                metadata: Vec::new(),
+                not_before: None,
+            })
+            .collect())
+    }
+
+    /// Reconstructs the secret from a share set in the transposed layout
+    ///
+    /// # Errors
+    /// Returns any error from [`decode_transposed`](Self::decode_transposed) or
+    /// [`reconstruct`](Self::reconstruct).
+    pub fn reconstruct_transposed(bytes: &[u8]) -> Result<Vec<u8>> {
+        Self::reconstruct(&Self::decode_transposed(bytes)?)
+    }
+
+    /// Writes the data of `shares` in raw byte-position-major order, with no header
+    ///
+    /// The output holds byte `j` of each share, in the order of `shares`, before byte
+    /// `j + 1`: the column layout external columnar tools expect. Unlike
+    /// [`encode_transposed`](Self::encode_transposed) it records nothing else, so the
+    /// reader must know the threshold and the share indices, in order, to
+    /// [`decode_columns`](Self::decode_columns) it.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `shares` is empty (`InsufficientShares`)
+    /// - Share data lengths differ (`InconsistentShareLength`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    ///
+    /// // Raw columns carry no integrity tag or compression flag, so split without them
+    /// let config = Config::new().with_integrity_check(false).with_compression(false);
+    /// let mut scheme = ShamirShare::builder(5, 3).with_config(config).build().unwrap();
+    /// let shares = scheme.split(b"columnar").unwrap();
+    ///
+    /// let columns = ShamirShare::encode_columns(&shares[1..4]).unwrap();
+    /// let decoded = ShamirShare::decode_columns(&columns, 3, &[2, 3, 4]).unwrap();
+    /// assert_eq!(ShamirShare::reconstruct(&decoded).unwrap(), b"columnar");
+    /// ```
+    pub fn encode_columns(shares: &[Share]) -> Result<Vec<u8>> {
+        let Some(first) = shares.first() else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+        if shares
+            .iter()
+            .any(|share| share.data.len() != first.data.len())
+        {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+        let mut columns = Vec::with_capacity(shares.len() * first.data.len());
+        write_columns(shares, &mut columns);
+        Ok(columns)
+    }
+
+    /// Reads shares from raw byte-position-major data, as written by
+    /// [`encode_columns`](Self::encode_columns) or an external columnar tool
+    ///
+    /// `indices` lists the share index of each column, in column order. The layout holds
+    /// only share data, so the shares carry `threshold`, no integrity check, compression
+    /// or length check, refresh generation 0, and the largest index as their total share
+    /// count.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `threshold` is 0 (`InvalidThreshold`)
+    /// - `indices` is empty (`InsufficientShares`), or holds 0 or a repeated index
+    ///   (`InvalidShareIndex`)
+    /// - The length of `columns` is not a multiple of the number of indices
+    ///   (`InvalidShareFormat`)
+    pub fn decode_columns(columns: &[u8], threshold: u8, indices: &[u8]) -> Result<Vec<Share>> {
+        if threshold == 0 {
+            return Err(ShamirError::InvalidThreshold(threshold));
+        }
+        let Some(&total_shares) = indices.iter().max() else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+        for (i, &index) in indices.iter().enumerate() {
+            if index == 0 || indices[..i].contains(&index) {
+                return Err(ShamirError::InvalidShareIndex(index));
+            }
+        }
+        let count = indices.len();
+        if !columns.len().is_multiple_of(count) {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        Ok(indices
+            .iter()
+            .enumerate()
+            .map(|(column, &index)| Share {
+                index,
+                data: read_column(columns, column, count),
+                threshold,
+                total_shares,
+                integrity_check: false,
+                integrity_algorithm: SHA256_ALGORITHM_ID,
+                compression: false,
+                length_check: false,
+                refresh_generation: 0,
+                metadata: Vec::new(),
+                not_before: None,
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Wraps a string at `width` characters using CRLF line endings
+    fn wrap_crlf(encoded: &str, width: usize) -> String {
+        let mut wrapped = String::new();
+        for line in encoded.as_bytes().chunks(width) {
+            wrapped.push_str(std::str::from_utf8(line).unwrap());
+            wrapped.push_str("\r\n");
+        }
+        wrapped
+    }
+
+    #[test]
+    fn test_base64_roundtrip() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(b"base64 roundtrip").unwrap();
+
+        for share in &shares {
+            let decoded = Share::from_base64(&share.to_base64()).unwrap();
+            assert_eq!(&decoded, share);
+        }
+    }
+
+    #[test]
+    fn test_lenient_accepts_crlf_wrapped() {
+        let secret = b"A secret long enough to need several wrapped lines of base64";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        let decoded: Vec<Share> = shares[..3]
+            .iter()
+            .map(|share| {
+                let wrapped = wrap_crlf(&share.to_base64(), 16);
+                assert!(wrapped.contains("\r\n"));
+                Share::from_base64_lenient(&wrapped).unwrap()
+            })
+            .collect();
+
+        assert_eq!(&decoded[..], &shares[..3]);
+        assert_eq!(ShamirShare::reconstruct(&decoded).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_lenient_accepts_spaces_and_trailing_newline() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"whitespace").unwrap();
+
+        let encoded = shares[1].to_base64();
+        let spaced: String = encoded
+            .chars()
+            .enumerate()
+            .flat_map(|(i, c)| {
+                if i % 5 == 4 {
+                    vec![c, ' ', '\t']
+                } else {
+                    vec![c]
+                }
+            })
+            .collect();
+        let input = format!("  {spaced}\n");
+
+        assert_eq!(Share::from_base64_lenient(&input).unwrap(), shares[1]);
+    }
+
+    #[test]
+    fn test_strict_rejects_wrapped() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"strict decoding").unwrap();
+
+        let wrapped = wrap_crlf(&shares[0].to_base64(), 16);
+        assert!(matches!(
+            Share::from_base64(&wrapped),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        let trailing_newline = format!("{}\n", shares[0].to_base64());
+        assert!(matches!(
+            Share::from_base64(&trailing_newline),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_from_fixed_size_records() {
+        const RECORD_SIZE: usize = 96;
+        let secret = b"stored in fixed records";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        // Lay the shares out back to back as a table of fixed-size records
+        let table: Vec<u8> = shares
+            .iter()
+            .flat_map(|s| s.to_fixed_record(RECORD_SIZE).unwrap())
+            .collect();
+        assert_eq!(table.len(), RECORD_SIZE * shares.len());
+
+        // The raw records carry padding and cannot be used as share data directly
+        let mut padded = shares[0].clone();
+        padded.data = table[..RECORD_SIZE].to_vec();
+        assert_ne!(padded.data.len(), shares[0].data.len());
+
+        let loaded: Vec<Share> = table
+            .chunks(RECORD_SIZE)
+            .skip(2)
+            .map(|record| Share::from_fixed_record(record).unwrap())
+            .collect();
+        assert_eq!(&loaded[..], &shares[2..]);
+        assert_eq!(ShamirShare::reconstruct(&loaded).unwrap(), secret);
+    }
+
+    #[test]
+    fn test_fixed_record_errors() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"too big for the record").unwrap();
+
+        assert!(matches!(
+            shares[0].to_fixed_record(16),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        let mut record = shares[0].to_fixed_record(128).unwrap();
+        record[127] = 1;
+        assert!(matches!(
+            Share::from_fixed_record(&record),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_from_bytes_reports_unsupported_version() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"secret").unwrap();
+
+        let mut bytes = shares[0].to_bytes();
+        assert_eq!(Share::from_bytes(&bytes).unwrap(), shares[0]);
+
+        bytes[4] = 0xFF;
+        assert!(matches!(
+            Share::from_bytes(&bytes),
+            Err(ShamirError::UnsupportedVersion {
+                found: 0xFF,
+                max_supported: 8
+            })
+        ));
+    }
+
+    #[test]
+    fn test_short_fingerprint() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"compare me").unwrap();
+
+        let code = shares[0].short_fingerprint();
+        assert_eq!(code.len(), 6);
+        assert!(code.bytes().all(|b| BASE32_ALPHABET.contains(&b)));
+
+        // A share decoded from a transcript matches the original
+        let decoded = Share::from_base64(&shares[0].to_base64()).unwrap();
+        assert_eq!(decoded.short_fingerprint(), code);
+
+        // Any single-byte change in data or scheme parameters alters the code
+        let mut corrupted = shares[0].clone();
+        corrupted.data[3] ^= 0x01;
+        assert_ne!(corrupted.short_fingerprint(), code);
+        let mut relabeled = shares[0].clone();
+        relabeled.index = 2;
+        assert_ne!(relabeled.short_fingerprint(), code);
+        let mut refreshed = shares[0].clone();
+        refreshed.refresh_generation = 1;
+        assert_ne!(refreshed.short_fingerprint(), code);
+        assert_ne!(shares[1].short_fingerprint(), code);
+
+        // The public hints are not part of the share's identity
+        let mut hinted = shares[0].clone();
+        hinted.metadata = b"new label".to_vec();
+        hinted.not_before = Some(1);
+        assert_eq!(hinted.short_fingerprint(), code);
+    }
+
+    #[test]
+    fn test_crc32_known_value() {
+        assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+        assert_eq!(format_checksum(0xCBF4_3926), "CBF4-3926");
+    }
+
+    #[test]
+    fn test_paper_backup_roundtrip() {
+        let secret = b"A secret destined for a safe deposit box";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        for columns in [0, 16, 40, 64] {
+            let printed: Vec<String> = shares.iter().map(|s| s.to_paper_backup(columns)).collect();
+            for block in &printed {
+                let data_lines: Vec<&str> = block
+                    .lines()
+                    .skip_while(|line| *line != PAPER_BEGIN)
+                    .skip(1)
+                    .take_while(|line| *line != PAPER_END)
+                    .collect();
+                if columns == 0 {
+                    assert_eq!(data_lines.len(), 1);
+                } else {
+                    assert!(data_lines.iter().all(|line| line.len() <= columns));
+                }
+            }
+
+            let restored: Vec<Share> = printed[1..4]
+                .iter()
+                .map(|block| Share::from_paper_backup(block).unwrap())
+                .collect();
+            assert_eq!(&restored[..], &shares[1..4]);
+            assert_eq!(ShamirShare::reconstruct(&restored).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn test_paper_backup_tolerates_retyped_layout() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"printed and scanned").unwrap();
+
+        let printed = shares[2].to_paper_backup(20);
+        assert!(printed.contains(PAPER_CHECKSUM_LABEL));
+
+        // Indent, add blank lines, use CRLF, and lowercase the checksum
+        let retyped: String = printed
+            .lines()
+            .map(|line| {
+                if line.starts_with(PAPER_CHECKSUM_LABEL) {
+                    format!(
+                        "   {}  \r\n\r\n",
+                        line.to_lowercase().replace("crc32", "CRC32")
+                    )
+                } else {
+                    format!("   {line}  \r\n\r\n")
+                }
+            })
+            .collect();
+
+        assert_eq!(Share::from_paper_backup(&retyped).unwrap(), shares[2]);
+    }
+
+    #[test]
+    fn test_paper_backup_detects_transcription_errors() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"checksum protected").unwrap();
+        let printed = shares[0].to_paper_backup(24);
+
+        // Swap one base64 character in the data for another valid one
+        let data_line = printed.lines().nth(2).unwrap();
+        let mut chars: Vec<char> = data_line.chars().collect();
+        chars[10] = if chars[10] == 'A' { 'B' } else { 'A' };
+        let typo: String = chars.into_iter().collect();
+        let damaged = printed.replacen(data_line, &typo, 1);
+        assert!(matches!(
+            Share::from_paper_backup(&damaged),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+
+        // Missing checksum line
+        let without_checksum: String = printed
+            .lines()
+            .filter(|line| !line.starts_with(PAPER_CHECKSUM_LABEL))
+            .map(|line| format!("{line}\n"))
+            .collect();
+        assert!(matches!(
+            Share::from_paper_backup(&without_checksum),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_rejects_malformed_payload() {
+        // Valid base64, but not a share
+        assert!(matches!(
+            Share::from_base64("aGVsbG8gd29ybGQ="),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        // Truncated share: its checksum is missing, as if the length had been corrupted
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"truncated").unwrap();
+        let mut bytes = share_to_bytes(&shares[0]);
+        bytes.pop();
+        assert!(matches!(
+            Share::from_base64(&STANDARD.encode(&bytes)),
+            Err(ShamirError::ShareFileCorrupt { index: 1 })
+        ));
+
+        // Truncated header
+        bytes.truncate(10);
+        assert!(matches!(
+            Share::from_base64(&STANDARD.encode(&bytes)),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_hex_roundtrip_and_errors() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"hex").unwrap();
+
+        let encoded = shares[0].to_hex();
+        assert_eq!(encoded.len(), 2 * shares[0].to_bytes().len());
+        assert_eq!(Share::from_hex(&encoded).unwrap(), shares[0]);
+        let spaced_upper = format!(" {}\n", encoded.to_uppercase());
+        assert_eq!(Share::from_hex(&spaced_upper).unwrap(), shares[0]);
+
+        for bad in [&encoded[1..], &format!("+f{}", &encoded[2..]), "zz"] {
+            assert!(matches!(
+                Share::from_hex(bad),
+                Err(ShamirError::InvalidShareFormat)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_metadata_roundtrip() {
+        let secret = b"hinted secret";
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let hints = [b"quarter-1 backup".to_vec(), Vec::new(), vec![0; 3]];
+        let shares = shamir.split_with_metadata(secret, &hints).unwrap();
+        assert_eq!(shares[0].metadata, b"quarter-1 backup");
+
+        for share in &shares {
+            assert_eq!(Share::from_bytes(&share.to_bytes()).unwrap(), *share);
+            assert_eq!(Share::from_base64(&share.to_base64()).unwrap(), *share);
+            let paper = share.to_paper_backup(32);
+            assert_eq!(Share::from_paper_backup(&paper).unwrap(), *share);
+            let record = share.to_fixed_record(128).unwrap();
+            assert_eq!(Share::from_fixed_record(&record).unwrap(), *share);
+            let serialized = bincode::serialize(share).unwrap();
+            assert_eq!(bincode::deserialize::<Share>(&serialized).unwrap(), *share);
+        }
+
+        // The metadata is public and takes no part in reconstruction
+        let mut relabeled = shares[..2].to_vec();
+        relabeled[0].metadata = b"forged label".to_vec();
+        assert_eq!(ShamirShare::reconstruct(&relabeled).unwrap(), secret);
+        let refreshed = shamir.refresh_shares(&shares).unwrap();
+        assert_eq!(refreshed[0].metadata, shares[0].metadata);
+
+        assert!(matches!(
+            shamir.split_with_metadata(secret, &hints[..2]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            ShamirShare::encode_transposed(&shares),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        // A metadata length claiming 4 GiB is rejected without allocating it
+        let mut bytes = shares[0].to_bytes();
+        bytes[14..18].copy_from_slice(&u32::MAX.to_le_bytes());
+        assert!(matches!(
+            Share::from_bytes(&bytes),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_mixed_encodings() {
+        let secret = b"collected from four holders";
+        let mut shamir = ShamirShare::builder(5, 4).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        let inputs = [
+            ShareInput::Bytes(shares[0].to_bytes()),
+            ShareInput::Base64(wrap_crlf(&shares[1].to_base64(), 20)),
+            ShareInput::Hex(shares[2].to_hex()),
+            ShareInput::PaperBackup(shares[4].to_paper_backup(32)),
+        ];
+        assert_eq!(ShamirShare::reconstruct_mixed(&inputs).unwrap(), secret);
+
+        // One undecodable input fails the whole reconstruction
+        let mut damaged = inputs.clone();
+        damaged[2] = ShareInput::Hex("not hex".to_string());
+        assert!(matches!(
+            ShamirShare::reconstruct_mixed(&damaged),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_transposed_roundtrip() {
+        let secret = b"stored byte-position-major";
+        let config = crate::Config::new().with_length_check(true);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        let subset = [shares[4].clone(), shares[1].clone(), shares[2].clone()];
+        let columnar = ShamirShare::encode_transposed(&subset).unwrap();
+        assert_eq!(columnar[4], 1);
+        let data = &columnar[TRANSPOSED_V1_HEADER_LEN + subset.len()..];
+        assert_eq!(data.len(), 3 * shares[0].data.len());
+        assert_eq!(
+            &data[..3],
+            &[shares[4].data[0], shares[1].data[0], shares[2].data[0]]
+        );
+        assert_eq!(data[3], shares[4].data[1]);
+
+        assert_eq!(ShamirShare::decode_transposed(&columnar).unwrap(), subset);
+        assert_eq!(
+            ShamirShare::reconstruct_transposed(&columnar).unwrap(),
+            secret
+        );
+        let split = shamir.split_transposed(secret).unwrap();
+        assert_eq!(ShamirShare::reconstruct_transposed(&split).unwrap(), secret);
+
+        // Only refreshed sets need version 2, which records the generation
+        let refreshed = shamir.refresh_shares(&shares).unwrap();
+        let columnar_v2 = ShamirShare::encode_transposed(&refreshed).unwrap();
+        assert_eq!(columnar_v2[4], TRANSPOSED_VERSION);
+        assert_eq!(
+            ShamirShare::decode_transposed(&columnar_v2).unwrap(),
+            refreshed
+        );
+
+        for damaged in [
+            &columnar[..columnar.len() - 1],
+            &[&columnar[..], &[0]].concat(),
+        ] {
+            assert!(matches!(
+                ShamirShare::decode_transposed(damaged),
+                Err(ShamirError::InvalidShareFormat)
+            ));
+        }
+    }
+
+    #[test]
+    fn test_raw_columns_roundtrip() {
+        let secret = b"exchanged with a columnar tool";
+        // Too short to compress, so the shares carry no flags the raw layout drops
+        let config = crate::Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = shamir.split(secret).unwrap();
+        assert!(!shares[0].compression);
+        let subset = [shares[4].clone(), shares[0].clone(), shares[2].clone()];
+
+        // The raw layout is the data section of the transposed layout, without a header
+        let columns = ShamirShare::encode_columns(&subset).unwrap();
+        let transposed = ShamirShare::encode_transposed(&subset).unwrap();
+        assert!(transposed.ends_with(&columns));
+        assert_eq!(columns.len(), 3 * shares[0].data.len());
+
+        let decoded = ShamirShare::decode_columns(&columns, 3, &[5, 1, 3]).unwrap();
+        for (decoded, original) in decoded.iter().zip(&subset) {
+            assert_eq!((decoded.index, decoded.threshold), (original.index, 3));
+            assert_eq!(decoded.data, original.data);
+        }
+        assert_eq!(decoded[0].total_shares, 5);
+        assert_eq!(ShamirShare::reconstruct(&decoded).unwrap(), secret);
+
+        assert!(matches!(
+            ShamirShare::decode_columns(&columns, 0, &[5, 1, 3]),
+            Err(ShamirError::InvalidThreshold(0))
+        ));
+        assert!(matches!(
+            ShamirShare::decode_columns(&columns, 3, &[5, 1, 5]),
+            Err(ShamirError::InvalidShareIndex(5))
+        ));
+        assert!(matches!(
+            ShamirShare::decode_columns(&columns[1..], 3, &[5, 1, 3]),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(matches!(
+            ShamirShare::encode_columns(&[
+                shares[0].clone(),
+                shamir.split(b"x").unwrap()[1].clone()
+            ]),
+            Err(ShamirError::InconsistentShareLength)
+        ));
+    }
+
+    #[test]
+    fn test_transposed_layout_is_distinct_from_share_format() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"not interchangeable").unwrap();
+
+        let columnar = ShamirShare::encode_transposed(&shares[..1]).unwrap();
+        assert!(matches!(
+            Share::from_bytes(&columnar),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(matches!(
+            ShamirShare::decode_transposed(&shares[0].to_bytes()),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        let other = shamir.split(b"another split, another length").unwrap();
+        assert!(matches!(
+            ShamirShare::encode_transposed(&[shares[0].clone(), other[1].clone()]),
+            Err(ShamirError::InconsistentShareLength)
+        ));
+        let mut relabeled = shares[1].clone();
+        relabeled.threshold = 3;
+        assert!(matches!(
+            ShamirShare::encode_transposed(&[shares[0].clone(), relabeled]),
+            Err(ShamirError::MixedShareSets)
+        ));
+    }
+}
@@ -0,0 +1,147 @@
+//! Authenticated envelopes for sending a share to a single participant
+//!
+//! An envelope binds a share to a recipient identifier with an HMAC-SHA256 tag, so a
+//! share that was tampered with or delivered to the wrong participant is detected when
+//! opened. The envelope does not encrypt the share; send it over a confidential channel.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+use crate::encoding::{share_from_bytes, share_to_bytes};
+use crate::error::{Result, ShamirError};
+use crate::integrity::ct_eq;
+use crate::shamir::Share;
+
+type HmacSha256 = Hmac<Sha256>;
+
+const TAG_SIZE: usize = 32;
+const RECIPIENT_LEN_SIZE: usize = 2;
+
+/// Computes the envelope tag over the recipient-framed share bytes
+fn envelope_mac(key: &[u8], body: &[u8]) -> HmacSha256 {
+    let mut mac = HmacSha256::new_from_slice(key).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    mac
+}
+
+impl Share {
+    /// Seals the share in an envelope addressed to `recipient` and authenticated with `key`
+    ///
+    /// Layout: `[2-byte LE recipient length][recipient][share bytes][32-byte HMAC-SHA256]`,
+    /// where the share bytes use the binary share format and the HMAC covers everything
+    /// before it. The length prefix keeps recipient and share unambiguous.
+    ///
+    /// # Panics
+    /// Panics if `recipient` is longer than 65535 bytes.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let envelope = shares[0].seal(b"alice@example.com", b"shared transport key");
+    /// let opened = Share::open(&envelope, b"alice@example.com", b"shared transport key").unwrap();
+    /// assert_eq!(opened, shares[0]);
+    /// ```
+    pub fn seal(&self, recipient: &[u8], key: &[u8]) -> Vec<u8> {
+        let recipient_len =
+            u16::try_from(recipient.len()).expect("recipient identifier exceeds 65535 bytes");
+        let share_bytes = share_to_bytes(self);
+
+        let mut envelope =
+            Vec::with_capacity(RECIPIENT_LEN_SIZE + recipient.len() + share_bytes.len() + TAG_SIZE);
+        envelope.extend_from_slice(&recipient_len.to_le_bytes());
+        envelope.extend_from_slice(recipient);
+        envelope.extend_from_slice(&share_bytes);
+        let tag = envelope_mac(key, &envelope).finalize().into_bytes();
+        envelope.extend_from_slice(&tag);
+        envelope
+    }
+
+    /// Opens an envelope produced by [`Share::seal`]
+    ///
+    /// The tag and the recipient are both checked in constant time before the share is
+    /// parsed.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The envelope is truncated, its tag does not verify under `key`, or it is addressed
+    ///   to someone other than `expected_recipient` (`EnvelopeAuthFailed`)
+    /// - The authenticated contents are not a well-formed share (`InvalidShareFormat` or
+    ///   `UnsupportedVersion`)
+    pub fn open(envelope: &[u8], expected_recipient: &[u8], key: &[u8]) -> Result<Self> {
+        if envelope.len() < RECIPIENT_LEN_SIZE + TAG_SIZE {
+            return Err(ShamirError::EnvelopeAuthFailed);
+        }
+        let (body, tag) = envelope.split_at(envelope.len() - TAG_SIZE);
+        let tag_valid = envelope_mac(key, body).verify_slice(tag).is_ok();
+
+        let recipient_len = u16::from_le_bytes([body[0], body[1]]) as usize;
+        let Some(rest) = body.get(RECIPIENT_LEN_SIZE..) else {
+            return Err(ShamirError::EnvelopeAuthFailed);
+        };
+        if rest.len() < recipient_len {
+            return Err(ShamirError::EnvelopeAuthFailed);
+        }
+        let (recipient, share_bytes) = rest.split_at(recipient_len);
+        let recipient_valid = ct_eq(recipient, expected_recipient);
+
+        if !(tag_valid & recipient_valid) {
+            return Err(ShamirError::EnvelopeAuthFailed);
+        }
+        share_from_bytes(share_bytes)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShamirShare;
+
+    const KEY: &[u8] = b"transport key";
+
+    #[test]
+    fn test_seal_open_roundtrip() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme.split(b"sealed secret").unwrap();
+
+        for share in &shares {
+            let envelope = share.seal(b"bob", KEY);
+            assert_eq!(&Share::open(&envelope, b"bob", KEY).unwrap(), share);
+        }
+
+        // An empty recipient is allowed
+        let envelope = shares[0].seal(b"", KEY);
+        assert_eq!(Share::open(&envelope, b"", KEY).unwrap(), shares[0]);
+    }
+
+    #[test]
+    fn test_open_detects_tampering_and_misrouting() {
+        let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = scheme.split(b"sealed secret").unwrap();
+        let envelope = shares[1].seal(b"carol", KEY);
+
+        let auth_failed =
+            |result: Result<Share>| matches!(result, Err(ShamirError::EnvelopeAuthFailed));
+
+        // Every flipped byte, in recipient, share, or tag, is detected
+        for position in 0..envelope.len() {
+            let mut tampered = envelope.clone();
+            tampered[position] ^= 0x01;
+            assert!(auth_failed(Share::open(&tampered, b"carol", KEY)));
+        }
+
+        assert!(auth_failed(Share::open(&envelope, b"mallory", KEY)));
+        assert!(auth_failed(Share::open(&envelope, b"caro", KEY)));
+        assert!(auth_failed(Share::open(&envelope, b"carol", b"wrong key")));
+        assert!(auth_failed(Share::open(
+            &envelope[..envelope.len() - 1],
+            b"carol",
+            KEY
+        )));
+        assert!(auth_failed(Share::open(&envelope[..10], b"carol", KEY)));
+        assert!(auth_failed(Share::open(&[], b"carol", KEY)));
+    }
+}
@@ -1,4 +1,5 @@
 use std::io;
+use std::path::PathBuf;
 use thiserror::Error;
 
 /// Error type for Shamir's Secret Sharing operations
@@ -16,6 +17,10 @@ pub enum ShamirError {
     #[error("Threshold {threshold} exceeds total shares {total_shares}")]
     ThresholdTooLarge { threshold: u8, total_shares: u8 },
 
+    /// Threshold equals total shares, so losing any share makes recovery impossible
+    #[error("Threshold {threshold} equals total shares; scheme has no fault tolerance")]
+    NoFaultTolerance { threshold: u8 },
+
     /// Insufficient shares for reconstruction
     #[error("Need at least {needed} shares, got {got}")]
     InsufficientShares { needed: u8, got: u8 },
@@ -24,29 +29,145 @@ pub enum ShamirError {
     #[error("Invalid share index {0}")]
     InvalidShareIndex(u8),
 
+    /// The share required by a mandatory-share policy is not among the given shares
+    #[error("Mandatory share {0} is missing")]
+    MandatoryShareMissing(u8),
+
     /// General I/O error
     #[error("I/O error: {0}")]
     IoError(#[from] io::Error),
 
+    /// Writing a share file failed; `path` is the file that could not be written
+    #[error("Failed to write share file {}: {source}", path.display())]
+    ShareWriteFailed {
+        path: PathBuf,
+        #[source]
+        source: io::Error,
+    },
+
     /// Data integrity check failed
     #[error("Data integrity check failed")]
     IntegrityCheckFailed,
 
+    /// Shares record an integrity algorithm for which no verifier is available
+    #[error("Unsupported integrity algorithm {0:#04x}")]
+    UnsupportedIntegrityAlgorithm(u8),
+
+    /// Shares come from different splits (e.g. mixing original and refreshed shares)
+    #[error("Shares belong to different share sets")]
+    MixedShareSets,
+
+    /// Independently reconstructed share sets yield different secrets
+    #[error("Share set {set_index} reconstructs to a different secret than set 0")]
+    SecretMismatch { set_index: usize },
+
+    /// Share data is corrupted at a few byte positions
+    #[error("Share data corrupted at {corrupted_bytes} byte positions")]
+    DataCorruption { corrupted_bytes: usize },
+
+    /// A stream chunk failed verification during lenient reconstruction
+    #[error(
+        "Integrity check failed at chunk {chunk_index} after recovering {recovered_chunks} chunks"
+    )]
+    ChunkIntegrityFailed {
+        chunk_index: usize,
+        recovered_chunks: usize,
+    },
+
+    /// Reconstructed secret differs in length from the length recorded at split time
+    #[error("Reconstructed secret is {actual} bytes, expected {expected}")]
+    SecretLengthMismatch { expected: usize, actual: usize },
+
+    /// Reconstructed secret does not have the fixed size the caller asked for, e.g. shares
+    /// of a byte string passed to [`reconstruct_u64`](crate::ShamirShare::reconstruct_u64)
+    #[error("Reconstructed secret is {actual} bytes, but a {expected}-byte value was requested")]
+    InvalidReconstructedLength { expected: usize, actual: usize },
+
     /// Invalid share format or content
     #[error("Invalid share format")]
     InvalidShareFormat,
 
+    /// Integrity-checked share data is too short to hold the integrity tag and length
+    /// prefix, so the share was truncated
+    #[error("Share data is {length} bytes, but its integrity tag needs at least {minimum}")]
+    ShareTooShort { length: usize, minimum: usize },
+
+    /// A sealed share envelope failed authentication or is addressed to another recipient
+    #[error("Share envelope authentication failed")]
+    EnvelopeAuthFailed,
+
+    /// Share was written by a newer library version using a format this version cannot read
+    #[error("Unsupported share format version {found}; this version reads up to {max_supported}")]
+    UnsupportedVersion { found: u8, max_supported: u8 },
+
+    /// Reconstructed data is not valid UTF-8
+    #[error("Reconstructed data is not valid UTF-8")]
+    InvalidUtf8,
+
     /// Inconsistent share lengths
     #[error("Inconsistent share lengths")]
     InconsistentShareLength,
 
+    /// Shares disagree on a parameter that determines how they are reconstructed
+    ///
+    /// `field` names the first differing parameter: `"threshold"`, `"total_shares"`,
+    /// `"integrity_check"`, `"integrity_algorithm"`, `"compression"`, `"length_check"`, or
+    /// `"data length"`. Reconstruction compares only the parameters after the share counts.
+    #[error("Shares disagree on {field}")]
+    InconsistentShareMetadata { field: &'static str },
+
+    /// The shares' set fingerprint differs from the expected one
+    #[error("Share set fingerprint does not match the expected fingerprint")]
+    ShareSetMismatch,
+
+    /// Shares are from different [`refresh_shares`](crate::ShamirShare::refresh_shares)
+    /// generations, e.g. mixing shares from before and after a refresh
+    #[error("Shares are from different refresh generations")]
+    MixedGenerations,
+
+    /// A share's refresh generation is older than the minimum accepted
+    #[error("Share {index} is stale: refresh generation {generation} is below the minimum")]
+    StaleShare { index: u8, generation: u32 },
+
+    /// A stored share does not match the checksum written with it, or lost it, e.g. after
+    /// bit rot
+    #[error("Share {index} is corrupt: its checksum does not match")]
+    ShareFileCorrupt { index: u8 },
+
+    /// Reconstruction was attempted before the shares' advisory not-before time
+    #[error("Shares are not valid before {not_before} (now {now})")]
+    SharesNotYetValid { not_before: u64, now: u64 },
+
+    /// Shares do not lie on a polynomial of the degree their threshold implies
+    #[error(
+        "Shares lie on a polynomial of degree {inferred}, but their threshold implies degree {declared}"
+    )]
+    PolynomialDegreeMismatch { declared: usize, inferred: usize },
+
+    /// The reconstructed secret is empty or all zero bytes, which the caller ruled out
+    #[error("Reconstructed secret is all zeros")]
+    TrivialSecret,
+
+    /// zstd failed to compress; the source is the error reported by zstd
     #[cfg(feature = "compress")]
     #[error("Compression error: {0}")]
-    CompressionError(String),
+    CompressionError(#[source] io::Error),
 
+    /// Compressed data could not be decompressed; the source is the error reported by zstd,
+    /// or an `InvalidData` error for a malformed stream chunk marker
     #[cfg(feature = "compress")]
     #[error("Decompression error: {0}")]
-    DecompressionError(String),
+    DecompressionError(#[source] io::Error),
+
+    /// The output stream could not be encrypted, e.g. because it has more chunks than the
+    /// AEAD nonce counter allows
+    #[cfg(feature = "encrypt")]
+    #[error("Encryption of the reconstructed stream failed")]
+    EncryptionFailed,
+
+    /// The operating system RNG could not provide a seed
+    #[error("Secure random number generator unavailable: {0}")]
+    RngUnavailable(String),
 
     #[error("Invalid configuration: {0}")]
     InvalidConfig(String),
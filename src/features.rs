@@ -0,0 +1,53 @@
+//! Runtime reporting of the cargo features this library was built with
+
+/// Cargo features enabled in the linked build of this library
+///
+/// Tools that read shares produced elsewhere can use this to explain failures, e.g. that
+/// compressed shares cannot be reconstructed by a build without the `compress` feature.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Features {
+    /// zstd compression of secrets (`compress`)
+    pub compress: bool,
+    /// Zeroization of secret-bearing buffers on drop (`zeroize`)
+    pub zeroize: bool,
+    /// JSON import of shares from other implementations (`interop`)
+    pub interop: bool,
+    /// Share inspection helpers (`diagnostics`)
+    pub diagnostics: bool,
+    /// Length-delimited protobuf encoding of shares (`protobuf`)
+    pub protobuf: bool,
+    /// Asynchronous share collection (`tokio`)
+    pub tokio: bool,
+    /// Reconstruction of streams into memory-mapped files (`memmap`)
+    pub memmap: bool,
+    /// Encryption of reconstructed streams (`encrypt`)
+    pub encrypt: bool,
+    /// Timing hooks for side-channel testing (`timing-harness`)
+    pub timing_harness: bool,
+    /// Internal tables exposed for testing (`test-helpers`)
+    pub test_helpers: bool,
+}
+
+/// Returns the cargo features this library was compiled with
+///
+/// # Example
+/// ```
+/// let features = shamir_share::features();
+/// if !features.compress {
+///     eprintln!("this build lacks compression support; compressed shares cannot be read");
+/// }
+/// ```
+pub fn features() -> Features {
+    Features {
+        compress: cfg!(feature = "compress"),
+        zeroize: cfg!(feature = "zeroize"),
+        interop: cfg!(feature = "interop"),
+        diagnostics: cfg!(feature = "diagnostics"),
+        protobuf: cfg!(feature = "protobuf"),
+        tokio: cfg!(feature = "tokio"),
+        memmap: cfg!(feature = "memmap"),
+        encrypt: cfg!(feature = "encrypt"),
+        timing_harness: cfg!(feature = "timing-harness"),
+        test_helpers: cfg!(feature = "test-helpers"),
+    }
+}
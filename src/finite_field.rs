@@ -146,6 +146,49 @@ impl FiniteField {
             Some(Self(gf256_inverse_const_time(self.0)))
         }
     }
+
+    /// Computes `self^254`, the inverse for nonzero elements and zero for zero
+    ///
+    /// Unlike [`inverse`](Self::inverse) this never branches on the value, so callers can
+    /// invert without revealing whether the element was zero and check that separately.
+    #[inline]
+    pub(crate) fn inverse_or_zero(self) -> Self {
+        self.exp(254)
+    }
+
+    /// Generates the log and antilog (exponent) tables for the generator `0x03`
+    ///
+    /// Intended only for checking cross-compatibility against reference implementations;
+    /// the arithmetic in this crate never uses lookup tables, since table lookups indexed
+    /// by secret data leak through cache timing.
+    ///
+    /// - `log[x]` is the `i` with `0x03^i == x` (`log[0]` is 0, as zero has no logarithm)
+    /// - `antilog[i]` is `0x03^i`; `antilog[255]` wraps around to `antilog[0] == 1`
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::FiniteField;
+    ///
+    /// let (log, antilog) = FiniteField::generate_log_tables();
+    /// let (a, b) = (0x53u8, 0xCAu8);
+    /// let sum = (log[a as usize] as usize + log[b as usize] as usize) % 255;
+    /// assert_eq!(FiniteField::new(a) * FiniteField::new(b), FiniteField::new(antilog[sum]));
+    /// ```
+    #[cfg(feature = "test-helpers")]
+    pub fn generate_log_tables() -> ([u8; 256], [u8; 256]) {
+        const GENERATOR: u8 = 0x03;
+
+        let mut log = [0u8; 256];
+        let mut antilog = [0u8; 256];
+        let mut value = 1u8;
+        for (i, entry) in antilog.iter_mut().take(255).enumerate() {
+            *entry = value;
+            log[value as usize] = i as u8;
+            value = gf256_multiply_const_time(value, GENERATOR);
+        }
+        antilog[255] = antilog[0];
+        (log, antilog)
+    }
 }
 
 /// Implements addition as XOR in GF(2⁸)
@@ -263,4 +306,32 @@ mod tests {
         let value = FiniteField::new(0xAB);
         assert_eq!(value * one, value);
     }
+
+    #[cfg(feature = "test-helpers")]
+    #[test]
+    fn test_log_tables_match_reference() {
+        let (log, antilog) = FiniteField::generate_log_tables();
+
+        // Reference values for the AES field (0x11B) with generator 0x03
+        assert_eq!(
+            &antilog[..8],
+            &[0x01, 0x03, 0x05, 0x0F, 0x11, 0x33, 0x55, 0xFF]
+        );
+        assert_eq!(antilog[25], 0x02);
+        assert_eq!(antilog[254], 0xF6);
+        assert_eq!(antilog[255], 0x01);
+        assert_eq!(log[0x01], 0);
+        assert_eq!(log[0x02], 25);
+        assert_eq!(log[0x03], 1);
+        assert_eq!(log[0xFF], 7);
+        assert_eq!(log[0x53], 0x30);
+
+        // The generator reaches every non-zero element exactly once
+        let mut seen = [false; 256];
+        for &value in &antilog[..255] {
+            assert!(!seen[value as usize]);
+            seen[value as usize] = true;
+        }
+        assert!(!seen[0]);
+    }
 }
@@ -31,10 +31,15 @@
 
 use crate::error::{Result, ShamirError};
 use crate::shamir::{ShamirShare, Share};
+use bincode::Options;
+use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "zeroize")]
 use zeroize::{Zeroize, ZeroizeOnDrop};
 
+/// Upper bound on packet sizes, so a corrupted length prefix cannot trigger huge allocations
+const MAX_PACKET_SIZE: u64 = 64 * 1024 * 1024;
+
 /// Represents an access level in the hierarchical secret sharing scheme
 ///
 /// An access level defines a role or position in the hierarchy and specifies
@@ -82,7 +87,7 @@ pub struct AccessLevel {
 ///     shares: vec![], // Would contain actual Share objects
 /// };
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 pub struct HierarchicalShare {
     /// Name of the access level this share set belongs to
@@ -390,6 +395,47 @@ impl Hsss {
         self.master_scheme.total_shares()
     }
 
+    /// Counts the sets of levels whose shares together meet the master threshold
+    ///
+    /// Each level is treated as one holder. The count is a coverage metric for access
+    /// reviews: how many distinct groups of holders can reconstruct the secret. It is
+    /// computed in `O(levels × master_threshold)` time without enumerating the groups.
+    ///
+    /// # Returns
+    /// The number of qualifying groups, saturating at `u64::MAX`; only hierarchies with
+    /// more than 63 levels can reach it.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::hsss::Hsss;
+    ///
+    /// let hsss = Hsss::builder(5)
+    ///     .add_level("President", 5)
+    ///     .add_level("VP", 3)
+    ///     .add_level("Executive", 2)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// // Any group with the President, plus the VP with the Executive
+    /// assert_eq!(hsss.coverage_count(), 5);
+    /// ```
+    pub fn coverage_count(&self) -> u64 {
+        let threshold = self.master_threshold() as usize;
+        // ways[s] counts the groups seen so far holding s shares, or at least the
+        // threshold for s == threshold. A count that saturates only flows into groups
+        // extending it, so a saturated result is never an undercount.
+        let mut ways = vec![0u64; threshold + 1];
+        ways[0] = 1;
+        for level in &self.levels {
+            // Descending, so every group is extended by this level at most once
+            for held in (0..=threshold).rev() {
+                let with_level = (held + level.shares_count as usize).min(threshold);
+                ways[with_level] = ways[with_level].saturating_add(ways[held]);
+            }
+        }
+        ways[threshold]
+    }
+
     /// Splits a secret into hierarchical shares according to the defined access levels
     ///
     /// This method uses an optimized approach that generates all master shares efficiently
@@ -485,6 +531,79 @@ impl Hsss {
         Ok(hierarchical_shares)
     }
 
+    /// Partitions the shares of an existing flat split into hierarchical levels
+    ///
+    /// Shares are handed out in order: the first level receives the first `shares_count`
+    /// shares, the next level the following ones, and so on. This turns a plain
+    /// [`ShamirShare::split`] into an HSSS distribution without re-splitting the secret.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `levels` is empty (`InvalidConfig`)
+    /// - Any level has a `shares_count` of 0 (`InvalidShareCount`)
+    /// - The levels' share counts do not add up to `shares.len()` (`InvalidConfig`)
+    /// - The shares disagree on `threshold` or `total_shares` (`InvalidShareFormat`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::hsss::{AccessLevel, Hsss};
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let levels = [
+    ///     AccessLevel { name: "Owner".to_string(), shares_count: 3 },
+    ///     AccessLevel { name: "Staff".to_string(), shares_count: 2 },
+    /// ];
+    /// let hierarchical_shares = Hsss::from_shares(shares, &levels).unwrap();
+    /// assert_eq!(hierarchical_shares[1].shares.len(), 2);
+    /// ```
+    pub fn from_shares(
+        shares: Vec<Share>,
+        levels: &[AccessLevel],
+    ) -> Result<Vec<HierarchicalShare>> {
+        if levels.is_empty() {
+            return Err(ShamirError::InvalidConfig(
+                "At least one access level must be defined".to_string(),
+            ));
+        }
+        if let Some(level) = levels.iter().find(|level| level.shares_count == 0) {
+            return Err(ShamirError::InvalidShareCount(level.shares_count));
+        }
+
+        let assigned: usize = levels.iter().map(|level| level.shares_count as usize).sum();
+        if assigned != shares.len() {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Levels assign {} shares, but {} shares were given",
+                assigned,
+                shares.len()
+            )));
+        }
+
+        if let Some(first) = shares.first()
+            && !shares
+                .iter()
+                .all(|s| s.threshold == first.threshold && s.total_shares == first.total_shares)
+        {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        let mut share_iter = shares.into_iter();
+        let hierarchical_shares = levels
+            .iter()
+            .map(|level| HierarchicalShare {
+                level_name: level.name.clone(),
+                shares: share_iter
+                    .by_ref()
+                    .take(level.shares_count as usize)
+                    .collect(),
+            })
+            .collect();
+
+        Ok(hierarchical_shares)
+    }
+
     /// Reconstructs the original secret from hierarchical shares
     ///
     /// This method provides a convenient way to reconstruct the secret from one or more
@@ -556,6 +675,205 @@ impl Hsss {
         // Use the standard Shamir reconstruction method
         ShamirShare::reconstruct(&all_shares)
     }
+
+    /// Selects exactly `master_threshold` shares from collected hierarchical shares
+    ///
+    /// [`reconstruct`](Self::reconstruct) uses every share it is given, but any
+    /// `master_threshold` of them suffice. Passing only the selected subset to
+    /// [`ShamirShare::reconstruct`] keeps the Lagrange interpolation as small as possible.
+    ///
+    /// Selection prefers whole levels: share sets are considered from largest to smallest
+    /// and taken in full while they fit, and only the last set used contributes a partial
+    /// subset. Shares whose index was already selected are skipped.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InsufficientShares` if the collected shares contain fewer than
+    /// `master_threshold` distinct indices.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use shamir_share::hsss::Hsss;
+    ///
+    /// let mut hsss = Hsss::builder(4)
+    ///     .add_level("President", 5)
+    ///     .add_level("VP", 3)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let hierarchical_shares = hsss.split_secret(b"secret").unwrap();
+    /// let minimal = hsss.minimal_shares_for(&hierarchical_shares).unwrap();
+    ///
+    /// assert_eq!(minimal.len(), 4);
+    /// assert_eq!(ShamirShare::reconstruct(&minimal).unwrap(), b"secret");
+    /// ```
+    pub fn minimal_shares_for(&self, collected: &[HierarchicalShare]) -> Result<Vec<Share>> {
+        let threshold = self.master_threshold() as usize;
+
+        let mut by_size: Vec<&HierarchicalShare> = collected.iter().collect();
+        by_size
+            .sort_by_key(|hierarchical_share| std::cmp::Reverse(hierarchical_share.shares.len()));
+
+        let mut seen = [false; 256];
+        let mut selected = Vec::with_capacity(threshold);
+        for hierarchical_share in by_size {
+            for share in &hierarchical_share.shares {
+                if selected.len() == threshold {
+                    return Ok(selected);
+                }
+                if !seen[share.index as usize] {
+                    seen[share.index as usize] = true;
+                    selected.push(share.clone());
+                }
+            }
+        }
+
+        if selected.len() < threshold {
+            return Err(ShamirError::InsufficientShares {
+                needed: self.master_threshold(),
+                got: u8::try_from(selected.len()).unwrap_or(u8::MAX),
+            });
+        }
+        Ok(selected)
+    }
+
+    /// Verifies a level's share set before it is handed to a participant
+    ///
+    /// Catches distribution mistakes such as handing out too few shares, shares from a
+    /// different scheme, or shares that were assigned to another level.
+    ///
+    /// # Arguments
+    /// * `level_name` - Name of the access level the shares are meant for
+    /// * `shares` - The shares about to be distributed for that level
+    ///
+    /// # Checks
+    /// - The number of shares equals the level's `shares_count`
+    /// - Every share carries the master scheme's threshold and total share count
+    /// - Every index is unique and falls within the index range `split_secret` assigns
+    ///   to this level
+    /// - All shares have the same data length and integrity/compression settings
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - No level is named `level_name`, or the share count is wrong (`InvalidConfig`)
+    /// - A share's threshold or total share count does not match (`InvalidShareFormat`)
+    /// - An index is duplicated or belongs to another level (`InvalidShareIndex`)
+    /// - Share data lengths or flags differ (`InconsistentShareMetadata`), or shares are
+    ///   from different refresh generations (`MixedGenerations`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::hsss::Hsss;
+    ///
+    /// let mut hsss = Hsss::builder(5)
+    ///     .add_level("President", 5)
+    ///     .add_level("VP", 3)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let hierarchical_shares = hsss.split_secret(b"secret").unwrap();
+    /// assert!(hsss.verify_level_shares("VP", &hierarchical_shares[1].shares).is_ok());
+    /// assert!(hsss.verify_level_shares("President", &hierarchical_shares[1].shares).is_err());
+    /// ```
+    pub fn verify_level_shares(&self, level_name: &str, shares: &[Share]) -> Result<()> {
+        let position = self
+            .levels
+            .iter()
+            .position(|level| level.name == level_name)
+            .ok_or_else(|| {
+                ShamirError::InvalidConfig(format!("Unknown access level '{level_name}'"))
+            })?;
+        let level = &self.levels[position];
+
+        if shares.len() != level.shares_count as usize {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Level '{}' expects {} shares, got {}",
+                level.name,
+                level.shares_count,
+                shares.len()
+            )));
+        }
+
+        // split_secret hands out master share indices to levels sequentially
+        let first_index: u32 = self.levels[..position]
+            .iter()
+            .map(|level| level.shares_count as u32)
+            .sum::<u32>()
+            + 1;
+        let last_index = first_index + level.shares_count as u32 - 1;
+
+        let mut seen = [false; 256];
+        for share in shares {
+            if share.threshold != self.master_threshold()
+                || share.total_shares != self.total_shares()
+            {
+                return Err(ShamirError::InvalidShareFormat);
+            }
+            let index = share.index as u32;
+            if index < first_index || index > last_index || seen[share.index as usize] {
+                return Err(ShamirError::InvalidShareIndex(share.index));
+            }
+            seen[share.index as usize] = true;
+        }
+
+        ShamirShare::ensure_consistent_metadata(shares)
+    }
+
+    /// Serializes a participant's share packet into a compact binary form
+    ///
+    /// Level names and all share metadata are preserved, so the packet can be handed to a
+    /// participant in one piece and read back with
+    /// [`deserialize_hierarchical`](Self::deserialize_hierarchical).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::hsss::Hsss;
+    ///
+    /// let mut hsss = Hsss::builder(5)
+    ///     .add_level("President", 5)
+    ///     .add_level("VP", 3)
+    ///     .build()
+    ///     .unwrap();
+    ///
+    /// let hierarchical_shares = hsss.split_secret(b"secret").unwrap();
+    /// let packet = Hsss::serialize_hierarchical(&hierarchical_shares[1..]);
+    /// let restored = Hsss::deserialize_hierarchical(&packet).unwrap();
+    /// assert_eq!(restored, &hierarchical_shares[1..]);
+    /// ```
+    pub fn serialize_hierarchical(shares: &[HierarchicalShare]) -> Vec<u8> {
+        bincode_options()
+            .serialize(shares)
+            .expect("serializing strings, integers and byte vectors cannot fail")
+    }
+
+    /// Deserializes a share packet produced by
+    /// [`serialize_hierarchical`](Self::serialize_hierarchical)
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareFormat` if `bytes` is not a valid packet, has
+    /// trailing bytes, or contains a share with index 0.
+    pub fn deserialize_hierarchical(bytes: &[u8]) -> Result<Vec<HierarchicalShare>> {
+        let shares: Vec<HierarchicalShare> = bincode_options()
+            .deserialize(bytes)
+            .map_err(|_| ShamirError::InvalidShareFormat)?;
+
+        if shares
+            .iter()
+            .flat_map(|h| &h.shares)
+            .any(|share| share.index == 0)
+        {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        Ok(shares)
+    }
+}
+
+/// Encoding used for share packets: varint integers, bounded reads, no trailing bytes
+fn bincode_options() -> impl Options {
+    bincode::DefaultOptions::new()
+        .with_limit(MAX_PACKET_SIZE)
+        .reject_trailing_bytes()
 }
 
 #[cfg(test)]
@@ -1088,4 +1406,160 @@ mod tests {
         let reconstructed = hsss.reconstruct(&hierarchical_shares).unwrap();
         assert_eq!(reconstructed, secret);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_verify_level_shares_correct() {
+        let mut hsss = Hsss::builder(5)
+            .add_level("President", 5)
+            .add_level("VP", 3)
+            .add_level("Executive", 2)
+            .build()
+            .unwrap();
+        let hierarchical_shares = hsss.split_secret(b"verify before distribution").unwrap();
+
+        for hierarchical_share in &hierarchical_shares {
+            hsss.verify_level_shares(&hierarchical_share.level_name, &hierarchical_share.shares)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_verify_level_shares_tampered() {
+        let mut hsss = Hsss::builder(5)
+            .add_level("President", 5)
+            .add_level("VP", 3)
+            .add_level("Executive", 2)
+            .build()
+            .unwrap();
+        let hierarchical_shares = hsss.split_secret(b"verify before distribution").unwrap();
+        let vp_shares = &hierarchical_shares[1].shares;
+
+        // Unknown level
+        assert!(matches!(
+            hsss.verify_level_shares("Intern", vp_shares),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        // Missing a share
+        assert!(matches!(
+            hsss.verify_level_shares("VP", &vp_shares[..2]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        // Shares handed to the wrong level
+        assert!(matches!(
+            hsss.verify_level_shares("Executive", &vp_shares[..2]),
+            Err(ShamirError::InvalidShareIndex(6))
+        ));
+
+        // A duplicated share in place of another
+        let duplicated = vec![
+            vp_shares[0].clone(),
+            vp_shares[1].clone(),
+            vp_shares[0].clone(),
+        ];
+        assert!(matches!(
+            hsss.verify_level_shares("VP", &duplicated),
+            Err(ShamirError::InvalidShareIndex(6))
+        ));
+
+        // A share from a different scheme
+        let mut foreign = vp_shares.clone();
+        foreign[2].threshold = 3;
+        assert!(matches!(
+            hsss.verify_level_shares("VP", &foreign),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        // Truncated share data
+        let mut truncated = vp_shares.clone();
+        truncated[1].data.pop();
+        assert!(matches!(
+            hsss.verify_level_shares("VP", &truncated),
+            Err(ShamirError::InconsistentShareMetadata {
+                field: "data length"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_minimal_shares_for() {
+        let mut hsss = Hsss::builder(5)
+            .add_level("President", 5)
+            .add_level("VP", 3)
+            .add_level("Executive", 2)
+            .build()
+            .unwrap();
+        let secret = b"minimal subset";
+        let hierarchical_shares = hsss.split_secret(secret).unwrap();
+
+        // Everyone present: exactly the threshold, taken from the largest set
+        let minimal = hsss.minimal_shares_for(&hierarchical_shares).unwrap();
+        assert_eq!(minimal.len(), 5);
+        assert!(minimal.iter().all(|s| (1..=5).contains(&s.index)));
+        assert_eq!(ShamirShare::reconstruct(&minimal).unwrap(), secret);
+
+        // Executive + VP in any order: the VP's larger set is used whole first
+        let collected = vec![
+            hierarchical_shares[2].clone(),
+            hierarchical_shares[1].clone(),
+        ];
+        let minimal = hsss.minimal_shares_for(&collected).unwrap();
+        assert_eq!(minimal.len(), 5);
+        let indices: Vec<u8> = minimal.iter().map(|s| s.index).collect();
+        assert_eq!(indices, vec![6, 7, 8, 9, 10]);
+        assert_eq!(ShamirShare::reconstruct(&minimal).unwrap(), secret);
+
+        // Duplicate share sets do not count twice
+        let duplicated = vec![
+            hierarchical_shares[1].clone(),
+            hierarchical_shares[1].clone(),
+        ];
+        assert!(matches!(
+            hsss.minimal_shares_for(&duplicated),
+            Err(ShamirError::InsufficientShares { needed: 5, got: 3 })
+        ));
+        assert!(matches!(
+            hsss.minimal_shares_for(&[]),
+            Err(ShamirError::InsufficientShares { needed: 5, got: 0 })
+        ));
+    }
+
+    #[test]
+    fn test_coverage_count() {
+        let coverage = |threshold: u8, counts: &[u8]| {
+            counts
+                .iter()
+                .fold(Hsss::builder(threshold), |builder, &count| {
+                    builder.add_level("holder", count)
+                })
+                .build()
+                .unwrap()
+                .coverage_count()
+        };
+
+        // Only everyone together, any two of three, any nonempty group
+        assert_eq!(coverage(3, &[1, 1, 1]), 1);
+        assert_eq!(coverage(3, &[2, 2, 2]), 4);
+        assert_eq!(coverage(1, &[1, 1, 1, 1]), 15);
+        // Threshold 4 of holders with 3, 2, 1, 1 shares: all groups with the first
+        // holder except it alone (7), plus {2, 1, 1}
+        assert_eq!(coverage(4, &[3, 2, 1, 1]), 8);
+        // Matches brute force over every group
+        let counts = [4, 1, 3, 2, 2, 5, 1];
+        let brute = (0u32..1 << counts.len())
+            .filter(|group| {
+                let held: u32 = (0..counts.len())
+                    .filter(|i| group >> i & 1 == 1)
+                    .map(|i| u32::from(counts[i]))
+                    .sum();
+                held >= 7
+            })
+            .count() as u64;
+        assert_eq!(coverage(7, &counts), brute);
+
+        // 2^70 - 1 groups saturate
+        assert_eq!(coverage(1, &[1; 70]), u64::MAX);
+        assert_eq!(coverage(70, &[1; 70]), 1);
+    }
+}
@@ -0,0 +1,193 @@
+//! Pluggable integrity verification for split secrets
+//!
+//! When integrity checking is enabled, an integrity tag computed over the secret is
+//! prepended to it before splitting, and verified after reconstruction. The tag is split
+//! together with the secret, so it is only visible to someone holding `threshold` shares.
+//!
+//! The built-in [`Sha256Verifier`] is used by default. Custom schemes (e.g. a truncated
+//! keyed MAC) implement [`IntegrityVerifier`] and are plugged in with
+//! [`Config::with_verifier`](crate::Config::with_verifier). Each share records the
+//! verifier's [`algorithm_id`](IntegrityVerifier::algorithm_id), so reconstruction can
+//! dispatch to the right scheme:
+//!
+//! - [`ShamirShare::reconstruct`](crate::ShamirShare::reconstruct) handles the built-in
+//!   algorithms
+//! - [`ShamirShare::reconstruct_with_verifier`](crate::ShamirShare::reconstruct_with_verifier)
+//!   additionally accepts a custom verifier for shares carrying its algorithm id
+//!
+//! Algorithm ids `0x00..=0x7F` are reserved for built-in algorithms; custom verifiers
+//! should use ids in `0x80..=0xFF`.
+
+use std::fmt;
+
+use sha2::{Digest, Sha256};
+
+/// Algorithm id of the built-in SHA-256 verifier
+pub const SHA256_ALGORITHM_ID: u8 = 0x00;
+
+/// A scheme for computing and verifying the integrity tag prepended to split secrets
+///
+/// # Security
+/// - `verify_ct` must compare tags in constant time; the default implementation does
+/// - `compute` must always return exactly `prefix_len()` bytes
+/// - Shares only record the algorithm id, so two verifiers must never share an id
+///
+/// # Example
+/// ```
+/// use shamir_share::{Config, IntegrityVerifier, ShamirShare};
+///
+/// /// SHA-256 truncated to 8 bytes
+/// struct TruncatedSha256;
+///
+/// impl IntegrityVerifier for TruncatedSha256 {
+///     fn algorithm_id(&self) -> u8 {
+///         0x80
+///     }
+///
+///     fn prefix_len(&self) -> usize {
+///         8
+///     }
+///
+///     fn compute(&self, data: &[u8]) -> Vec<u8> {
+///         use sha2::{Digest, Sha256};
+///         Sha256::digest(data)[..8].to_vec()
+///     }
+/// }
+///
+/// let config = Config::new().with_verifier(Box::new(TruncatedSha256));
+/// let mut scheme = ShamirShare::builder(5, 3).with_config(config).build().unwrap();
+/// let shares = scheme.split(b"secret").unwrap();
+/// assert_eq!(shares[0].integrity_algorithm, 0x80);
+///
+/// let secret = ShamirShare::reconstruct_with_verifier(&shares[..3], &TruncatedSha256).unwrap();
+/// assert_eq!(secret, b"secret");
+/// ```
+pub trait IntegrityVerifier: Send + Sync {
+    /// Identifier stored in each share to select this verifier during reconstruction
+    fn algorithm_id(&self) -> u8;
+
+    /// Length in bytes of the tag produced by [`compute`](Self::compute)
+    fn prefix_len(&self) -> usize;
+
+    /// Computes the integrity tag for `data`
+    fn compute(&self, data: &[u8]) -> Vec<u8>;
+
+    /// Checks in constant time whether `tag` is the correct integrity tag for `data`
+    fn verify_ct(&self, data: &[u8], tag: &[u8]) -> bool {
+        ct_eq(&self.compute(data), tag)
+    }
+}
+
+impl fmt::Debug for dyn IntegrityVerifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("IntegrityVerifier")
+            .field("algorithm_id", &self.algorithm_id())
+            .field("prefix_len", &self.prefix_len())
+            .finish()
+    }
+}
+
+/// The default verifier: a SHA-256 digest of the secret
+#[derive(Debug, Clone, Copy, Default)]
+pub struct Sha256Verifier;
+
+impl IntegrityVerifier for Sha256Verifier {
+    fn algorithm_id(&self) -> u8 {
+        SHA256_ALGORITHM_ID
+    }
+
+    fn prefix_len(&self) -> usize {
+        32
+    }
+
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        Sha256::digest(data).to_vec()
+    }
+}
+
+/// Returns the built-in verifier for `algorithm_id`, if there is one
+pub(crate) fn builtin_verifier(algorithm_id: u8) -> Option<&'static dyn IntegrityVerifier> {
+    match algorithm_id {
+        SHA256_ALGORITHM_ID => Some(&Sha256Verifier),
+        _ => None,
+    }
+}
+
+/// Incremental CRC-32 (IEEE 802.3) checksum
+///
+/// This is an error-detection code for accidental damage such as transcription mistakes
+/// or bit rot, not a cryptographic hash.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Crc32(u32);
+
+impl Crc32 {
+    pub(crate) fn new() -> Self {
+        Self(!0)
+    }
+
+    pub(crate) fn update(&mut self, bytes: &[u8]) {
+        for &byte in bytes {
+            self.0 ^= byte as u32;
+            for _ in 0..8 {
+                let mask = (self.0 & 1).wrapping_neg();
+                self.0 = (self.0 >> 1) ^ (0xEDB8_8320 & mask);
+            }
+        }
+    }
+
+    pub(crate) fn finish(self) -> u32 {
+        !self.0
+    }
+}
+
+/// Computes the CRC-32 checksum of `bytes` in one call
+pub(crate) fn crc32(bytes: &[u8]) -> u32 {
+    let mut crc = Crc32::new();
+    crc.update(bytes);
+    crc.finish()
+}
+
+/// Compares two byte slices without exiting early at the first difference
+///
+/// Slices of different lengths compare unequal; only the lengths, which are public tag
+/// sizes, affect timing.
+pub(crate) fn ct_eq(a: &[u8], b: &[u8]) -> bool {
+    if a.len() != b.len() {
+        return false;
+    }
+    let mut diff = 0u8;
+    for (x, y) in a.iter().zip(b) {
+        diff |= x ^ y;
+    }
+    diff == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sha256_verifier() {
+        let verifier = Sha256Verifier;
+        let tag = verifier.compute(b"data");
+        assert_eq!(tag.len(), verifier.prefix_len());
+        assert!(verifier.verify_ct(b"data", &tag));
+        assert!(!verifier.verify_ct(b"date", &tag));
+        assert!(!verifier.verify_ct(b"data", &tag[..31]));
+    }
+
+    #[test]
+    fn test_builtin_verifier_lookup() {
+        let verifier = builtin_verifier(SHA256_ALGORITHM_ID).unwrap();
+        assert_eq!(verifier.algorithm_id(), SHA256_ALGORITHM_ID);
+        assert!(builtin_verifier(0x80).is_none());
+    }
+
+    #[test]
+    fn test_ct_eq() {
+        assert!(ct_eq(b"", b""));
+        assert!(ct_eq(b"abc", b"abc"));
+        assert!(!ct_eq(b"abc", b"abd"));
+        assert!(!ct_eq(b"abc", b"ab"));
+    }
+}
@@ -0,0 +1,121 @@
+//! Interoperability with share formats produced by other Shamir implementations
+//!
+//! This module is only available with the `interop` feature.
+//!
+//! # Supported external format
+//!
+//! [`import_from_json`] accepts a JSON array of objects, one per share:
+//!
+//! ```text
+//! [
+//!   {"x": 1, "y": "<standard base64>"},
+//!   {"x": 2, "y": "<standard base64>"},
+//!   ...
+//! ]
+//! ```
+//!
+//! - `x` is the share index (the polynomial x-coordinate) as an integer in `1..=255`
+//! - `y` is the share data, encoded as standard (RFC 4648, padded) base64. Each byte is the
+//!   evaluation of one byte-wise polynomial at `x`
+//! - Unknown fields on each object are ignored
+//!
+//! The external implementation must use the same arithmetic as this crate: GF(2^8) with the
+//! irreducible polynomial x⁸ + x⁴ + x³ + x + 1 (0x11B) and the secret byte as the constant
+//! term. Shares from libraries that use a different field polynomial will import cleanly but
+//! reconstruct to garbage, and because imported shares carry no integrity hash this cannot
+//! be detected automatically.
+
+use base64::Engine;
+use base64::engine::general_purpose::STANDARD;
+use serde::Deserialize;
+
+use crate::error::{Result, ShamirError};
+use crate::shamir::Share;
+
+/// A single share in the external `{"x": n, "y": "base64..."}` JSON format
+#[derive(Deserialize)]
+struct ExternalShare {
+    x: u8,
+    y: String,
+}
+
+/// Imports shares from a JSON array of `{"x": n, "y": "base64..."}` objects
+///
+/// The resulting shares have integrity checking, compression, and the length check
+/// disabled, since the external format carries none of them. `total_shares` is set to the
+/// larger of `threshold` and the highest imported index.
+///
+/// # Arguments
+/// * `json` - JSON array in the format described in the [module documentation](self)
+/// * `threshold` - Threshold the shares were created with (not stored in the external
+///   format)
+///
+/// # Errors
+/// Returns `ShamirError` if:
+/// - `threshold` is 0
+/// - The input is not a JSON array of share objects, or a `y` value is not valid base64
+/// - A share has index 0 or an index appears more than once
+/// - Shares have different data lengths
+///
+/// # Example
+/// ```
+/// use shamir_share::ShamirShare;
+/// use shamir_share::interop::import_from_json;
+///
+/// let json = r#"[
+///     {"x": 1, "y": "Y1U5FvDkgGBHPQ/Ntw=="},
+///     {"x": 3, "y": "dTWT4tV3SXwhjfWSIg=="}
+/// ]"#;
+///
+/// let shares = import_from_json(json, 2).unwrap();
+/// let secret = ShamirShare::reconstruct(&shares).unwrap();
+/// assert_eq!(secret, b"hello interop");
+/// ```
+pub fn import_from_json(json: &str, threshold: u8) -> Result<Vec<Share>> {
+    if threshold == 0 {
+        return Err(ShamirError::InvalidThreshold(threshold));
+    }
+
+    let external: Vec<ExternalShare> =
+        serde_json::from_str(json).map_err(|_| ShamirError::InvalidShareFormat)?;
+
+    let mut seen = [false; 256];
+    let mut decoded = Vec::with_capacity(external.len());
+    for share in external {
+        if share.x == 0 || seen[share.x as usize] {
+            return Err(ShamirError::InvalidShareIndex(share.x));
+        }
+        seen[share.x as usize] = true;
+
+        let data = STANDARD
+            .decode(share.y.as_bytes())
+            .map_err(|_| ShamirError::InvalidShareFormat)?;
+        decoded.push((share.x, data));
+    }
+
+    if let Some((_, first)) = decoded.first()
+        && decoded.iter().any(|(_, data)| data.len() != first.len())
+    {
+        return Err(ShamirError::InconsistentShareLength);
+    }
+
+    let max_index = decoded.iter().map(|(x, _)| *x).max().unwrap_or(0);
+    let total_shares = max_index.max(threshold);
+
+    Ok(decoded
+        .into_iter()
+        .map(|(index, data)| Share {
+            index,
+            data,
+            threshold,
+            total_shares,
+            integrity_check: false,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        })
+        .collect())
+}
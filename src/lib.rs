@@ -8,8 +8,11 @@
 //!
 //! - **Constant-time GF(2^8) arithmetic** - No lookup tables, resistant to cache-timing attacks
 //! - **Cryptographically secure random generation** - Uses ChaCha20Rng seeded from OsRng  
-//! - **Integrity verification** - SHA-256 hash checking with constant-time comparison
-//! - **Memory safety** - Written in safe Rust with zero unsafe blocks
+//! - **Integrity verification** - SHA-256 hash checking with constant-time comparison,
+//!   or a custom [`IntegrityVerifier`]
+//! - **Memory safety** - Written in safe Rust; the default build has zero unsafe blocks.
+//!   The only unsafe code is feature-gated: memory-mapping the output file (`memmap`) and
+//!   reading the CPU time stamp counter (`timing-harness`)
 //!
 //! # Quick Start
 //!
@@ -61,19 +64,53 @@
 //! assert_eq!(reconstructed, secret);
 //! ```
 
+#[cfg(feature = "tokio")]
+mod async_collector;
+mod audit;
+mod bundle;
+mod commitment;
 mod config;
+#[cfg(feature = "diagnostics")]
+mod diagnostics;
+mod encoding;
+mod envelope;
 mod error;
+mod features;
 mod finite_field;
 pub mod hsss;
+mod integrity;
+#[cfg(feature = "interop")]
+pub mod interop;
+mod merkle;
+mod partition;
+#[cfg(feature = "protobuf")]
+mod protobuf;
 mod shamir;
 mod storage;
-
-pub use config::{Config, SplitMode};
+#[cfg(feature = "timing-harness")]
+pub mod timing;
+
+#[cfg(feature = "tokio")]
+pub use async_collector::AsyncCollector;
+pub use audit::AuditRecord;
+pub use bundle::ShareBundle;
+pub use commitment::Commitment;
+pub use config::{Config, OverheadReport, PaddingAdvice, Profile, SplitMode};
+pub use encoding::ShareInput;
 pub use error::{Result, ShamirError};
+pub use features::{Features, features};
 pub use finite_field::FiniteField;
 pub use hsss::{AccessLevel, HierarchicalShare, Hsss, HsssBuilder};
-pub use shamir::{Dealer, ShamirShare, ShamirShareBuilder, Share, ShareView};
-pub use storage::{FileShareStore, ShareStore};
+pub use integrity::{IntegrityVerifier, SHA256_ALGORITHM_ID, Sha256Verifier};
+pub use merkle::MerkleProof;
+pub use partition::PartitionedScheme;
+pub use shamir::{
+    Dealer, ReconstructionCheckpoint, ReconstructionMetadata, RngSource, ShamirShare,
+    ShamirShareBuilder, Share, ShareAudit, ShareView, SplitStats, StreamRecoveryReport,
+};
+pub use storage::{FileShareStore, ShareManifest, ShareStore};
+#[cfg(feature = "zeroize")]
+pub use zeroize::Zeroizing;
 
 // Re-export common types for convenience
 pub mod prelude {
@@ -0,0 +1,238 @@
+//! Merkle trees over share fingerprints for transparency logs
+//!
+//! A dealer publishes the root over all shares of a split; each holder receives a proof
+//! that their share is one of the leaves. Leaves and nodes are hashed with distinct
+//! prefixes as in RFC 6962, so a node can never be passed off as a leaf.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::encoding::hash_share_identity;
+use crate::error::{Result, ShamirError};
+use crate::integrity::ct_eq;
+use crate::shamir::{ShamirShare, Share};
+
+const LEAF_PREFIX: u8 = 0x00;
+const NODE_PREFIX: u8 = 0x01;
+
+/// Hashes a share's identifying fields into a leaf
+///
+/// The public metadata and not-before hints are left out, so editing them keeps the
+/// share's proof valid, as does rewriting the share in a newer format version.
+fn leaf_hash(share: &Share) -> [u8; 32] {
+    let mut hasher = Sha256::new_with_prefix([LEAF_PREFIX]);
+    hash_share_identity(&mut hasher, share);
+    hasher.finalize().into()
+}
+
+fn node_hash(left: &[u8; 32], right: &[u8; 32]) -> [u8; 32] {
+    Sha256::new()
+        .chain_update([NODE_PREFIX])
+        .chain_update(left)
+        .chain_update(right)
+        .finalize()
+        .into()
+}
+
+/// Returns the leaves in canonical order: sorted by share index, then by leaf hash
+fn sorted_leaves(shares: &[Share]) -> Vec<(u8, [u8; 32])> {
+    let mut leaves: Vec<(u8, [u8; 32])> = shares
+        .iter()
+        .map(|share| (share.index, leaf_hash(share)))
+        .collect();
+    leaves.sort_unstable();
+    leaves
+}
+
+/// Combines one tree level into the next; an unpaired last node moves up unchanged
+fn next_level(level: &[[u8; 32]]) -> Vec<[u8; 32]> {
+    level
+        .chunks(2)
+        .map(|pair| match pair {
+            [left, right] => node_hash(left, right),
+            [single] => *single,
+            _ => unreachable!("chunks(2) yields one or two nodes"),
+        })
+        .collect()
+}
+
+/// One step of a [`MerkleProof`]: a sibling hash and the side it is on
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+struct ProofStep {
+    sibling: [u8; 32],
+    sibling_is_left: bool,
+}
+
+/// Proof that a share is a leaf of a published share Merkle root
+///
+/// Created by [`Share::merkle_proof`] and checked with [`verify`](Self::verify). The proof
+/// holds only hashes, so it can be published alongside the root without revealing share
+/// data.
+///
+/// # Example
+/// ```
+/// use shamir_share::{ShamirShare, Share};
+///
+/// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+/// let shares = scheme.split(b"logged split").unwrap();
+///
+/// let root = ShamirShare::share_merkle_root(&shares);
+/// let proof = Share::merkle_proof(2, &shares).unwrap();
+/// assert!(proof.verify(&shares[1], &root));
+/// assert!(!proof.verify(&shares[2], &root));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MerkleProof {
+    index: u8,
+    path: Vec<ProofStep>,
+}
+
+impl MerkleProof {
+    /// Returns the index of the share this proof was made for
+    pub fn share_index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns true if `share` is the share this proof was made for, and a leaf of `root`
+    ///
+    /// Any change to the share's data, index, scheme parameters, flags or refresh
+    /// generation changes its leaf hash, so a tampered share fails. The final comparison
+    /// with `root` is constant-time.
+    pub fn verify(&self, share: &Share, root: &[u8; 32]) -> bool {
+        if share.index != self.index {
+            return false;
+        }
+        let computed = self.path.iter().fold(leaf_hash(share), |hash, step| {
+            if step.sibling_is_left {
+                node_hash(&step.sibling, &hash)
+            } else {
+                node_hash(&hash, &step.sibling)
+            }
+        });
+        ct_eq(&computed, root)
+    }
+}
+
+impl ShamirShare {
+    /// Computes the Merkle root over the fingerprints of all shares of a split
+    ///
+    /// Each leaf is the SHA-256 hash of a share's identifying fields (index, scheme
+    /// parameters, flags, refresh generation and data), so the root commits to every share
+    /// without revealing any of it. The public metadata and not-before hints are not
+    /// covered, and the root does not depend on the share format version. Leaves are
+    /// ordered by share index, making the root independent of the order of `shares`.
+    /// Publish the root in a transparency log and hand each holder their
+    /// [`Share::merkle_proof`].
+    ///
+    /// The root of an empty set is the SHA-256 hash of the empty string.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let reversed: Vec<_> = shares.iter().rev().cloned().collect();
+    /// assert_eq!(
+    ///     ShamirShare::share_merkle_root(&shares),
+    ///     ShamirShare::share_merkle_root(&reversed)
+    /// );
+    /// ```
+    pub fn share_merkle_root(shares: &[Share]) -> [u8; 32] {
+        let mut level: Vec<[u8; 32]> = sorted_leaves(shares)
+            .into_iter()
+            .map(|(_, hash)| hash)
+            .collect();
+        if level.is_empty() {
+            return Sha256::digest([]).into();
+        }
+        while level.len() > 1 {
+            level = next_level(&level);
+        }
+        level[0]
+    }
+}
+
+impl Share {
+    /// Builds the proof that the share with `index` is a leaf of
+    /// [`ShamirShare::share_merkle_root`] over `all_shares`
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareIndex` if no share in `all_shares` has `index`.
+    pub fn merkle_proof(index: u8, all_shares: &[Share]) -> Result<MerkleProof> {
+        let leaves = sorted_leaves(all_shares);
+        let Some(mut position) = leaves.iter().position(|&(i, _)| i == index) else {
+            return Err(ShamirError::InvalidShareIndex(index));
+        };
+
+        let mut level: Vec<[u8; 32]> = leaves.into_iter().map(|(_, hash)| hash).collect();
+        let mut path = Vec::new();
+        while level.len() > 1 {
+            let sibling = position ^ 1;
+            // An unpaired last node moves up without a sibling
+            if sibling < level.len() {
+                path.push(ProofStep {
+                    sibling: level[sibling],
+                    sibling_is_left: sibling < position,
+                });
+            }
+            level = next_level(&level);
+            position /= 2;
+        }
+
+        Ok(MerkleProof { index, path })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merkle_proofs_verify_for_every_share() {
+        // Odd and even set sizes exercise unpaired nodes at different levels
+        for total in [1, 2, 5, 7, 8] {
+            let mut scheme = ShamirShare::builder(total, 1).build().unwrap();
+            let shares = scheme.split(b"transparency").unwrap();
+            let root = ShamirShare::share_merkle_root(&shares);
+
+            for share in &shares {
+                let proof = Share::merkle_proof(share.index, &shares).unwrap();
+                assert_eq!(proof.share_index(), share.index);
+                assert!(proof.verify(share, &root));
+            }
+        }
+    }
+
+    #[test]
+    fn test_merkle_proof_rejects_tampered_share() {
+        let mut scheme = ShamirShare::builder(6, 3).build().unwrap();
+        let shares = scheme.split(b"published root").unwrap();
+        let root = ShamirShare::share_merkle_root(&shares);
+        let proof = Share::merkle_proof(4, &shares).unwrap();
+
+        let mut tampered_data = shares[3].clone();
+        tampered_data.data[0] ^= 1;
+        assert!(!proof.verify(&tampered_data, &root));
+
+        let mut tampered_metadata = shares[3].clone();
+        tampered_metadata.threshold = 2;
+        assert!(!proof.verify(&tampered_metadata, &root));
+
+        // Public hints are not part of the leaf
+        let mut labeled = shares[3].clone();
+        labeled.metadata = b"holder 4".to_vec();
+        assert!(proof.verify(&labeled, &root));
+
+        // Another split's share at the same index, and a different root
+        let other = scheme.split(b"published root").unwrap();
+        assert!(!proof.verify(&other[3], &root));
+        assert!(!proof.verify(&shares[3], &ShamirShare::share_merkle_root(&other)));
+
+        assert!(matches!(
+            Share::merkle_proof(7, &shares),
+            Err(ShamirError::InvalidShareIndex(7))
+        ));
+    }
+}
@@ -0,0 +1,314 @@
+//! Approximating mutually exclusive participants with independent splits
+
+use std::collections::HashMap;
+
+use crate::config::Config;
+use crate::error::{Result, ShamirError};
+use crate::shamir::{ShamirShare, Share};
+
+/// A secret shared among participants split into groups, for conflict-of-interest policies
+///
+/// A single Shamir polynomial cannot keep two participants from combining their shares.
+/// This scheme assigns participants to groups and splits the secret independently for
+/// each group, with the same threshold. Shares from different groups lie on unrelated
+/// polynomials, so they never combine. A policy lists forbidden participant sets, and
+/// the members of every forbidden set are placed so that they are not all in one group.
+///
+/// Participants are numbered `1..=participants`.
+///
+/// # Limitations
+/// - A forbidden set cannot pool its shares, but each member still reconstructs with
+///   `threshold` members of their own group. The policy prevents collusion between the
+///   forbidden members, not their participation in any reconstruction
+/// - Every group holds the whole secret, so `threshold` members of any group suffice.
+///   More groups therefore mean more, smaller coalitions that can reconstruct
+/// - Groups are chosen greedily, placing the most constrained participants first and
+///   then moving participants into groups smaller than `threshold`. A policy that only a
+///   different assignment could satisfy is reported as unsatisfiable
+///
+/// # Example
+/// ```
+/// use shamir_share::PartitionedScheme;
+///
+/// // Participants 1 and 2 must never be able to reconstruct together
+/// let scheme = PartitionedScheme::new(5, 2, &[&[1, 2]]).unwrap();
+/// let shares = scheme.split(b"audit key").unwrap();
+///
+/// let pair = |a: u8, b: u8| [(a, shares[&a].clone()), (b, shares[&b].clone())].into();
+/// assert!(scheme.reconstruct(&pair(1, 2)).is_err());
+///
+/// let partner = scheme.group(1).iter().copied().find(|&p| p != 1).unwrap();
+/// assert_eq!(scheme.reconstruct(&pair(1, partner)).unwrap(), b"audit key");
+/// ```
+#[derive(Debug, Clone)]
+pub struct PartitionedScheme {
+    threshold: u8,
+    /// Participants of each group, in ascending order
+    groups: Vec<Vec<u8>>,
+    config: Config,
+}
+
+impl PartitionedScheme {
+    /// Assigns `participants` participants to groups satisfying the forbidden sets
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `participants` or `threshold` is 0, or `threshold > participants`, as for
+    ///   [`ShamirShare::builder`]
+    /// - A forbidden set has fewer than two members or names a participant outside
+    ///   `1..=participants` (`InvalidConfig`)
+    /// - No group assignment with at least `threshold` participants per group keeps every
+    ///   forbidden set apart (`InvalidConfig`)
+    pub fn new(participants: u8, threshold: u8, forbidden: &[&[u8]]) -> Result<Self> {
+        Config::new().validate_for(participants, threshold)?;
+        for set in forbidden {
+            if set.len() < 2 {
+                return Err(ShamirError::InvalidConfig(format!(
+                    "Forbidden set {set:?} needs at least two participants"
+                )));
+            }
+            if let Some(&p) = set.iter().find(|&&p| p == 0 || p > participants) {
+                return Err(ShamirError::InvalidConfig(format!(
+                    "Forbidden set names participant {p}, outside 1..={participants}"
+                )));
+            }
+        }
+
+        let groups = assign_groups(participants, threshold, forbidden)?;
+        Ok(Self {
+            threshold,
+            groups,
+            config: Config::new(),
+        })
+    }
+
+    /// Uses `config` for every group's split
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if `config` is invalid for any group, e.g. it disallows
+    /// groups where every member is needed.
+    pub fn with_config(mut self, config: Config) -> Result<Self> {
+        for group in &self.groups {
+            config.validate_for(group.len() as u8, self.threshold)?;
+        }
+        self.config = config;
+        Ok(self)
+    }
+
+    /// Returns the participants of every group
+    pub fn groups(&self) -> &[Vec<u8>] {
+        &self.groups
+    }
+
+    /// Returns the participants in the same group as `participant`, including it
+    ///
+    /// # Panics
+    /// Panics if `participant` is outside `1..=participants`.
+    pub fn group(&self, participant: u8) -> &[u8] {
+        self.groups
+            .iter()
+            .find(|group| group.contains(&participant))
+            .unwrap_or_else(|| panic!("participant {participant} is not in the scheme"))
+    }
+
+    /// Splits `secret` independently for each group
+    ///
+    /// # Returns
+    /// Each participant's share, keyed by participant. Share indices count within the
+    /// group, so they repeat across groups.
+    ///
+    /// # Errors
+    /// Returns any error from [`ShamirShare::split`].
+    pub fn split(&self, secret: &[u8]) -> Result<HashMap<u8, Share>> {
+        let mut shares = HashMap::new();
+        for group in &self.groups {
+            let mut scheme = ShamirShare::builder(group.len() as u8, self.threshold)
+                .with_config(self.config.clone())
+                .build()?;
+            shares.extend(group.iter().copied().zip(scheme.split(secret)?));
+        }
+        Ok(shares)
+    }
+
+    /// Reconstructs the secret from the shares of any group with `threshold` members
+    /// present
+    ///
+    /// Shares of participants from other groups are ignored.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - A key is not a participant of the scheme (`InvalidShareIndex`)
+    /// - No group has `threshold` shares present (`InsufficientShares`, with the most
+    ///   shares any group has)
+    /// - Any error from [`ShamirShare::reconstruct`] for the chosen group
+    pub fn reconstruct(&self, shares: &HashMap<u8, Share>) -> Result<Vec<u8>> {
+        let participants = self.groups.iter().map(Vec::len).sum::<usize>();
+        if let Some(&p) = shares
+            .keys()
+            .find(|&&p| p == 0 || p as usize > participants)
+        {
+            return Err(ShamirError::InvalidShareIndex(p));
+        }
+
+        let mut most_present = 0;
+        for group in &self.groups {
+            let present: Vec<Share> = group
+                .iter()
+                .filter_map(|p| shares.get(p).cloned())
+                .collect();
+            if present.len() >= self.threshold as usize {
+                return ShamirShare::reconstruct(&present);
+            }
+            most_present = most_present.max(present.len());
+        }
+        Err(ShamirError::InsufficientShares {
+            needed: self.threshold,
+            got: most_present as u8,
+        })
+    }
+}
+
+/// Greedily assigns participants to groups that keep every forbidden set apart
+fn assign_groups(participants: u8, threshold: u8, forbidden: &[&[u8]]) -> Result<Vec<Vec<u8>>> {
+    // A participant may join a group unless that completes a forbidden set there
+    let allowed = |group: &[u8], participant: u8| {
+        !forbidden.iter().any(|set| {
+            set.contains(&participant)
+                && set
+                    .iter()
+                    .all(|&member| member == participant || group.contains(&member))
+        })
+    };
+
+    // Place the most constrained participants first, each into the smallest group that
+    // allows it, opening a new group only when none does
+    let mut order: Vec<u8> = (1..=participants).collect();
+    order.sort_by_key(|p| std::cmp::Reverse(forbidden.iter().filter(|s| s.contains(p)).count()));
+    let mut groups: Vec<Vec<u8>> = Vec::new();
+    for participant in order {
+        match groups
+            .iter_mut()
+            .filter(|group| allowed(group, participant))
+            .min_by_key(|group| group.len())
+        {
+            Some(group) => group.push(participant),
+            None => groups.push(vec![participant]),
+        }
+    }
+
+    // Fill groups below the threshold from groups that can spare participants
+    let threshold = threshold as usize;
+    while let Some(small) = groups.iter().position(|group| group.len() < threshold) {
+        let donor = (0..groups.len()).find_map(|from| {
+            if from == small || groups[from].len() <= threshold {
+                return None;
+            }
+            groups[from]
+                .iter()
+                .position(|&p| allowed(&groups[small], p))
+                .map(|position| (from, position))
+        });
+        let Some((from, position)) = donor else {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Forbidden sets cannot be kept apart with at least {threshold} participants \
+                 per group"
+            )));
+        };
+        let participant = groups[from].remove(position);
+        groups[small].push(participant);
+    }
+
+    for group in &mut groups {
+        group.sort_unstable();
+    }
+    Ok(groups)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pick(shares: &HashMap<u8, Share>, participants: &[u8]) -> HashMap<u8, Share> {
+        participants
+            .iter()
+            .map(|p| (*p, shares[p].clone()))
+            .collect()
+    }
+
+    #[test]
+    fn test_forbidden_pair_cannot_reconstruct() {
+        let secret = b"conflict of interest";
+        let scheme = PartitionedScheme::new(6, 2, &[&[1, 2], &[3, 4]]).unwrap();
+        assert_ne!(scheme.group(1), scheme.group(2));
+        assert_ne!(scheme.group(3), scheme.group(4));
+        assert!(scheme.groups().iter().all(|group| group.len() >= 2));
+
+        let shares = scheme.split(secret).unwrap();
+        assert_eq!(shares.len(), 6);
+        assert!(matches!(
+            scheme.reconstruct(&pick(&shares, &[1, 2])),
+            Err(ShamirError::InsufficientShares { needed: 2, got: 1 })
+        ));
+        assert!(scheme.reconstruct(&pick(&shares, &[3, 4])).is_err());
+
+        // Shares of different groups do not combine even through plain reconstruction
+        let mixed = [shares[&1].clone(), shares[&2].clone()];
+        assert_ne!(
+            ShamirShare::reconstruct(&mixed).ok().as_deref(),
+            Some(&secret[..])
+        );
+
+        // Every allowed pair, i.e. members of one group, reconstructs
+        for group in scheme.groups() {
+            for (i, &a) in group.iter().enumerate() {
+                for &b in &group[i + 1..] {
+                    let secret_again = scheme.reconstruct(&pick(&shares, &[a, b])).unwrap();
+                    assert_eq!(secret_again, secret);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_forbidden_triple_spans_groups() {
+        let scheme = PartitionedScheme::new(7, 3, &[&[1, 2, 3]]).unwrap();
+        let groups: Vec<&[u8]> = [1, 2, 3].map(|p| scheme.group(p)).to_vec();
+        assert!(!(groups[0] == groups[1] && groups[1] == groups[2]));
+
+        let shares = scheme.split(b"triple").unwrap();
+        assert!(scheme.reconstruct(&pick(&shares, &[1, 2, 3])).is_err());
+        assert_eq!(
+            scheme.reconstruct(&pick(&shares, scheme.group(1))).unwrap(),
+            b"triple"
+        );
+    }
+
+    #[test]
+    fn test_unsatisfiable_policies() {
+        // Participant 1 conflicts with everyone, so its group cannot reach the threshold
+        assert!(matches!(
+            PartitionedScheme::new(3, 2, &[&[1, 2], &[1, 3]]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            PartitionedScheme::new(4, 2, &[&[1]]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            PartitionedScheme::new(4, 2, &[&[1, 5]]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            PartitionedScheme::new(4, 5, &[]),
+            Err(ShamirError::ThresholdTooLarge { .. })
+        ));
+
+        // Without forbidden sets everyone shares one group
+        let scheme = PartitionedScheme::new(4, 2, &[]).unwrap();
+        assert_eq!(scheme.groups(), &[vec![1, 2, 3, 4]]);
+        assert!(scheme.clone().with_config(Config::new()).is_ok());
+        let strict = Config::new().with_allow_no_fault_tolerance(false);
+        let pairs = PartitionedScheme::new(4, 2, &[&[1, 2], &[3, 4]]).unwrap();
+        assert!(pairs.with_config(strict).is_err());
+    }
+}
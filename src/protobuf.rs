@@ -0,0 +1,465 @@
+//! Length-delimited protobuf encoding of shares
+//!
+//! The schema is documented on [`Share::to_protobuf_delimited`], since this module is not
+//! public.
+//!
+//! Only available with the `protobuf` feature.
+
+use std::io::{self, Read};
+
+use crate::error::{Result, ShamirError};
+use crate::shamir::Share;
+
+const FIELD_INDEX: u64 = 1;
+const FIELD_DATA: u64 = 2;
+const FIELD_THRESHOLD: u64 = 3;
+const FIELD_TOTAL_SHARES: u64 = 4;
+const FIELD_INTEGRITY_CHECK: u64 = 5;
+const FIELD_INTEGRITY_ALGORITHM: u64 = 6;
+const FIELD_COMPRESSION: u64 = 7;
+const FIELD_LENGTH_CHECK: u64 = 8;
+const FIELD_REFRESH_GENERATION: u64 = 9;
+const FIELD_METADATA: u64 = 10;
+const FIELD_NOT_BEFORE: u64 = 11;
+
+const WIRE_VARINT: u64 = 0;
+const WIRE_FIXED64: u64 = 1;
+const WIRE_LEN: u64 = 2;
+const WIRE_FIXED32: u64 = 5;
+
+/// Longest encoding of a 64-bit varint
+const MAX_VARINT_LEN: usize = 10;
+
+fn put_varint(out: &mut Vec<u8>, mut value: u64) {
+    while value >= 0x80 {
+        out.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+    out.push(value as u8);
+}
+
+/// Appends a varint field, omitting it when zero
+fn put_varint_field(out: &mut Vec<u8>, field: u64, value: u64) {
+    if value != 0 {
+        put_varint(out, field << 3 | WIRE_VARINT);
+        put_varint(out, value);
+    }
+}
+
+/// Reads a varint, returning `None` if the reader is at its end before the first byte
+fn read_varint<R: Read>(reader: &mut R) -> Result<Option<u64>> {
+    let mut value = 0u64;
+    for position in 0..MAX_VARINT_LEN {
+        let mut byte = [0u8; 1];
+        match reader.read_exact(&mut byte) {
+            Ok(()) => {}
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof && position == 0 => {
+                return Ok(None);
+            }
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(ShamirError::InvalidShareFormat);
+            }
+            Err(e) => return Err(e.into()),
+        }
+        value |= u64::from(byte[0] & 0x7F) << (7 * position);
+        if byte[0] & 0x80 == 0 {
+            return Ok(Some(value));
+        }
+    }
+    Err(ShamirError::InvalidShareFormat)
+}
+
+/// Reads a varint that must be present
+fn expect_varint(reader: &mut &[u8]) -> Result<u64> {
+    read_varint(reader)?.ok_or(ShamirError::InvalidShareFormat)
+}
+
+/// Reads the value of a length-delimited field
+fn expect_bytes<'a>(reader: &mut &'a [u8]) -> Result<&'a [u8]> {
+    let len =
+        usize::try_from(expect_varint(reader)?).map_err(|_| ShamirError::InvalidShareFormat)?;
+    let Some((value, rest)) = reader.split_at_checked(len) else {
+        return Err(ShamirError::InvalidShareFormat);
+    };
+    *reader = rest;
+    Ok(value)
+}
+
+/// Converts a decoded field to a share's `u8` metadata
+fn to_u8(value: u64) -> Result<u8> {
+    u8::try_from(value).map_err(|_| ShamirError::InvalidShareFormat)
+}
+
+/// Parses the fields of a `Share` message
+fn decode_message(mut message: &[u8]) -> Result<Share> {
+    let mut share = Share {
+        index: 0,
+        data: Vec::new(),
+        threshold: 0,
+        total_shares: 0,
+        integrity_check: false,
+        integrity_algorithm: 0,
+        compression: false,
+        length_check: false,
+        refresh_generation: 0,
+        metadata: Vec::new(),
+        not_before: None,
+    };
+
+    while !message.is_empty() {
+        let key = expect_varint(&mut message)?;
+        let (field, wire_type) = (key >> 3, key & 7);
+        match (field, wire_type) {
+            (FIELD_DATA, WIRE_LEN) => share.data = expect_bytes(&mut message)?.to_vec(),
+            (FIELD_METADATA, WIRE_LEN) => share.metadata = expect_bytes(&mut message)?.to_vec(),
+            (FIELD_INDEX..=FIELD_REFRESH_GENERATION, WIRE_VARINT) => {
+                let value = expect_varint(&mut message)?;
+                match field {
+                    FIELD_INDEX => share.index = to_u8(value)?,
+                    FIELD_THRESHOLD => share.threshold = to_u8(value)?,
+                    FIELD_TOTAL_SHARES => share.total_shares = to_u8(value)?,
+                    FIELD_INTEGRITY_CHECK => share.integrity_check = value != 0,
+                    FIELD_INTEGRITY_ALGORITHM => share.integrity_algorithm = to_u8(value)?,
+                    FIELD_COMPRESSION => share.compression = value != 0,
+                    FIELD_LENGTH_CHECK => share.length_check = value != 0,
+                    FIELD_REFRESH_GENERATION => {
+                        share.refresh_generation =
+                            u32::try_from(value).map_err(|_| ShamirError::InvalidShareFormat)?;
+                    }
+                    _ => return Err(ShamirError::InvalidShareFormat),
+                }
+            }
+            (FIELD_NOT_BEFORE, WIRE_VARINT) => {
+                share.not_before = Some(expect_varint(&mut message)?);
+            }
+            (FIELD_INDEX..=FIELD_NOT_BEFORE, _) => {
+                return Err(ShamirError::InvalidShareFormat);
+            }
+            // Unknown fields, skipped by wire type
+            (_, WIRE_VARINT) => {
+                expect_varint(&mut message)?;
+            }
+            (_, WIRE_LEN) => {
+                expect_bytes(&mut message)?;
+            }
+            (_, WIRE_FIXED64 | WIRE_FIXED32) => {
+                let len = if wire_type == WIRE_FIXED64 { 8 } else { 4 };
+                let Some((_, rest)) = message.split_at_checked(len) else {
+                    return Err(ShamirError::InvalidShareFormat);
+                };
+                message = rest;
+            }
+            // Groups (wire types 3 and 4) are deprecated and never valid here
+            _ => return Err(ShamirError::InvalidShareFormat),
+        }
+    }
+
+    Ok(share)
+}
+
+impl Share {
+    /// Encodes the share as a varint-length-delimited protobuf `Share` message
+    ///
+    /// The message follows the schema below and is preceded by its length as a varint,
+    /// the framing of `writeDelimitedTo` / `parseDelimitedFrom` in the protobuf libraries,
+    /// so any protobuf toolchain can exchange shares with this library from the schema
+    /// alone:
+    ///
+    /// ```text
+    /// syntax = "proto3";
+    ///
+    /// message Share {
+    ///   uint32 index = 1;
+    ///   bytes data = 2;
+    ///   uint32 threshold = 3;
+    ///   uint32 total_shares = 4;
+    ///   bool integrity_check = 5;
+    ///   uint32 integrity_algorithm = 6;
+    ///   bool compression = 7;
+    ///   bool length_check = 8;
+    ///   uint32 refresh_generation = 9;
+    ///   bytes metadata = 10;
+    ///   optional uint64 not_before = 11;
+    /// }
+    /// ```
+    ///
+    /// As in proto3, fields holding zero or `false` are omitted when encoding and default
+    /// to it when decoding, except the optional `not_before`, which is present exactly when
+    /// set. Unknown fields are skipped, so the schema can be extended. Several encoded
+    /// shares can be concatenated into one stream and read back with
+    /// [`read_protobuf_delimited`](Self::read_protobuf_delimited).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let bytes = shares[0].to_protobuf_delimited();
+    /// let decoded = Share::read_protobuf_delimited(&mut bytes.as_slice()).unwrap();
+    /// assert_eq!(decoded, Some(shares[0].clone()));
+    /// ```
+    pub fn to_protobuf_delimited(&self) -> Vec<u8> {
+        let mut message = Vec::with_capacity(self.data.len() + self.metadata.len() + 32);
+        put_varint_field(&mut message, FIELD_INDEX, self.index.into());
+        if !self.data.is_empty() {
+            put_varint(&mut message, FIELD_DATA << 3 | WIRE_LEN);
+            put_varint(&mut message, self.data.len() as u64);
+            message.extend_from_slice(&self.data);
+        }
+        put_varint_field(&mut message, FIELD_THRESHOLD, self.threshold.into());
+        put_varint_field(&mut message, FIELD_TOTAL_SHARES, self.total_shares.into());
+        put_varint_field(
+            &mut message,
+            FIELD_INTEGRITY_CHECK,
+            self.integrity_check.into(),
+        );
+        put_varint_field(
+            &mut message,
+            FIELD_INTEGRITY_ALGORITHM,
+            self.integrity_algorithm.into(),
+        );
+        put_varint_field(&mut message, FIELD_COMPRESSION, self.compression.into());
+        put_varint_field(&mut message, FIELD_LENGTH_CHECK, self.length_check.into());
+        put_varint_field(
+            &mut message,
+            FIELD_REFRESH_GENERATION,
+            self.refresh_generation.into(),
+        );
+        if !self.metadata.is_empty() {
+            put_varint(&mut message, FIELD_METADATA << 3 | WIRE_LEN);
+            put_varint(&mut message, self.metadata.len() as u64);
+            message.extend_from_slice(&self.metadata);
+        }
+        if let Some(not_before) = self.not_before {
+            put_varint(&mut message, FIELD_NOT_BEFORE << 3 | WIRE_VARINT);
+            put_varint(&mut message, not_before);
+        }
+
+        let mut delimited = Vec::with_capacity(MAX_VARINT_LEN + message.len());
+        put_varint(&mut delimited, message.len() as u64);
+        delimited.extend_from_slice(&message);
+        delimited
+    }
+
+    /// Reads the next varint-length-delimited protobuf `Share` message from `reader`
+    ///
+    /// Reads exactly one message, leaving the reader positioned at the next one. The
+    /// message body is read incrementally, so a corrupt length cannot force a large
+    /// allocation up front.
+    ///
+    /// # Returns
+    /// The share, or `None` if `reader` was already at its end
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The stream ends inside a message, or the message is malformed, e.g. a metadata
+    ///   field exceeds 255 or a known field has the wrong wire type (`InvalidShareFormat`)
+    /// - Reading fails (`IoError`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Share};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    /// let stream: Vec<u8> = shares.iter().flat_map(Share::to_protobuf_delimited).collect();
+    ///
+    /// let mut reader = stream.as_slice();
+    /// let mut decoded = Vec::new();
+    /// while let Some(share) = Share::read_protobuf_delimited(&mut reader).unwrap() {
+    ///     decoded.push(share);
+    /// }
+    /// assert_eq!(decoded, shares);
+    /// ```
+    pub fn read_protobuf_delimited<R: Read>(reader: &mut R) -> Result<Option<Share>> {
+        let Some(len) = read_varint(reader)? else {
+            return Ok(None);
+        };
+
+        let mut message = Vec::new();
+        reader.take(len).read_to_end(&mut message)?;
+        if message.len() as u64 != len {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        decode_message(&message).map(Some)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{Config, ShamirShare};
+
+    #[test]
+    fn test_protobuf_round_trip() {
+        let config = Config::new().with_length_check(true);
+        let mut scheme = ShamirShare::builder(200, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = scheme.split(&[0xA5; 300]).unwrap();
+
+        for share in [&shares[0], &shares[199]] {
+            let bytes = share.to_protobuf_delimited();
+            let mut reader = bytes.as_slice();
+            let decoded = Share::read_protobuf_delimited(&mut reader).unwrap();
+            assert_eq!(decoded.as_ref(), Some(share));
+            assert!(reader.is_empty());
+        }
+
+        // Default values are omitted, and the empty message decodes to them
+        let empty = Share {
+            index: 0,
+            data: Vec::new(),
+            threshold: 0,
+            total_shares: 0,
+            integrity_check: false,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+        assert_eq!(empty.to_protobuf_delimited(), [0]);
+        assert_eq!(
+            Share::read_protobuf_delimited(&mut [0u8].as_slice()).unwrap(),
+            Some(empty)
+        );
+    }
+
+    #[test]
+    fn test_protobuf_field_layout() {
+        let share = Share {
+            index: 2,
+            data: vec![0xAB, 0xCD],
+            threshold: 3,
+            total_shares: 5,
+            integrity_check: true,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: true,
+            refresh_generation: 300,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+        let expected = [
+            17, // message length
+            0x08, 2, // index = 2
+            0x12, 2, 0xAB, 0xCD, // data
+            0x18, 3, // threshold = 3
+            0x20, 5, // total_shares = 5
+            0x28, 1, // integrity_check = true
+            0x40, 1, // length_check = true
+            0x48, 0xAC, 0x02, // refresh_generation = 300
+        ];
+        assert_eq!(share.to_protobuf_delimited(), expected);
+    }
+
+    #[test]
+    fn test_protobuf_metadata_round_trip() {
+        let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+        let hints = [b"q1".to_vec(), Vec::new(), vec![0xFF; 300]];
+        let shares = scheme.split_with_metadata(b"hinted", &hints).unwrap();
+
+        let encoded = shares[0].to_protobuf_delimited();
+        assert!(encoded.ends_with(&[0x52, 2, b'q', b'1']));
+        for share in &shares {
+            let bytes = share.to_protobuf_delimited();
+            let decoded = Share::read_protobuf_delimited(&mut bytes.as_slice()).unwrap();
+            assert_eq!(decoded.as_ref(), Some(share));
+        }
+
+        // An optional field set to zero is still present
+        let mut timed = shares[1].clone();
+        timed.not_before = Some(0);
+        let bytes = timed.to_protobuf_delimited();
+        assert!(bytes.ends_with(&[0x58, 0]));
+        let decoded = Share::read_protobuf_delimited(&mut bytes.as_slice()).unwrap();
+        assert_eq!(decoded, Some(timed));
+    }
+
+    #[test]
+    fn test_read_concatenated_shares() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme.split(b"streamed shares").unwrap();
+        let stream: Vec<u8> = shares
+            .iter()
+            .flat_map(Share::to_protobuf_delimited)
+            .collect();
+
+        let mut reader = std::io::Cursor::new(stream);
+        let mut decoded = Vec::new();
+        while let Some(share) = Share::read_protobuf_delimited(&mut reader).unwrap() {
+            decoded.push(share);
+        }
+        assert_eq!(decoded, shares);
+        assert_eq!(
+            ShamirShare::reconstruct(&decoded[2..]).unwrap(),
+            b"streamed shares"
+        );
+    }
+
+    #[test]
+    fn test_read_skips_unknown_and_rejects_malformed() {
+        let share = Share {
+            index: 1,
+            data: vec![9],
+            threshold: 1,
+            total_shares: 1,
+            integrity_check: false,
+            integrity_algorithm: 0,
+            compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+        let decode = |message: &[u8]| {
+            let mut bytes = Vec::new();
+            put_varint(&mut bytes, message.len() as u64);
+            bytes.extend_from_slice(message);
+            Share::read_protobuf_delimited(&mut bytes.as_slice())
+        };
+        let known = &share.to_protobuf_delimited()[1..];
+
+        // Unknown fields of every wire type are skipped
+        let extended = [
+            known,
+            &[0x60, 0x96, 0x01],             // field 12, varint 150
+            &[0x69, 0, 0, 0, 0, 0, 0, 0, 0], // field 13, fixed64
+            &[0x72, 2, b'h', b'i'],          // field 14, length-delimited
+            &[0x7D, 0, 0, 0, 0],             // field 15, fixed32
+        ]
+        .concat();
+        assert_eq!(decode(&extended).unwrap(), Some(share.clone()));
+
+        // Metadata beyond u8, known fields with the wrong wire type, groups, truncation
+        assert!(matches!(
+            decode(&[0x08, 0x80, 0x02]),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(decode(&[0x0A, 0]).is_err());
+        assert!(decode(&[0x4B]).is_err());
+        assert!(decode(&[0x12, 5, 1]).is_err());
+        assert!(decode(&[0x08]).is_err());
+
+        let mut truncated = share.to_protobuf_delimited();
+        truncated.pop();
+        assert!(matches!(
+            Share::read_protobuf_delimited(&mut truncated.as_slice()),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(matches!(
+            Share::read_protobuf_delimited(&mut [0x80u8].as_slice()),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+        assert!(
+            Share::read_protobuf_delimited(&mut [].as_slice())
+                .unwrap()
+                .is_none()
+        );
+    }
+}
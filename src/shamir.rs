@@ -1,19 +1,57 @@
-use crate::config::Config;
+use crate::audit::AuditRecord;
+use crate::commitment::{COMMITMENT_SALT_SIZE, Commitment};
+use crate::config::{Config, DEFAULT_PARALLEL_THRESHOLD, PaddingAdvice};
+use crate::encoding::hash_share_identity;
 use crate::error::{Result, ShamirError};
 use crate::finite_field::FiniteField;
+use crate::integrity::{
+    IntegrityVerifier, SHA256_ALGORITHM_ID, Sha256Verifier, builtin_verifier, ct_eq,
+};
+#[cfg(feature = "memmap")]
+use crate::storage::parse_share_slice;
+use crate::storage::{
+    FileShareStore, encoded_len, write_new_file, write_share_files, write_shares_to_dirs,
+};
+use hkdf::Hkdf;
 use rand::rngs::OsRng;
 use rand_chacha::ChaCha20Rng;
 use rand_chacha::rand_core::RngCore;
-use rand_core::SeedableRng;
+use rand_core::{CryptoRng, SeedableRng, TryRngCore};
 use rayon::iter::ParallelIterator;
 use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::io::{Read, Write};
+use std::borrow::{Borrow, Cow};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::{BufReader, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 #[cfg(feature = "zeroize")]
-use zeroize::{Zeroize, ZeroizeOnDrop};
+use zeroize::{Zeroize, ZeroizeOnDrop, Zeroizing};
 
 const HASH_SIZE: usize = 32; // SHA-256 output size
+pub(crate) const LENGTH_PREFIX_SIZE: usize = 4; // Secret length stored under `Config::with_length_check`
+const STREAM_MAGIC: &[u8; 4] = b"SHSS";
+const STREAM_FORMAT_VERSION: u8 = 2; // Version 2 adds the whole-stream digest trailer
+/// Version written for streams without the digest trailer, which version 1 readers accept
+const STREAM_FORMAT_VERSION_NO_DIGEST: u8 = 1;
+pub(crate) const STREAM_HEADER_LEN: usize = STREAM_MAGIC.len() + 3;
+const DEFAULT_RNG_RETRIES: u8 = 3;
+const DETERMINISTIC_COEFFICIENTS_SALT: &[u8] = b"shamir_share deterministic coefficients";
+const DETERMINISTIC_COEFFICIENTS_INFO: &[u8] = b"shamir_share/v1/coefficients/threshold=";
+const SET_FINGERPRINT_DOMAIN: &[u8] = b"shamir_share/v1/set-fingerprint";
+const RECOVERY_KIT_README: &str = "README.txt";
+const MIN_PADDING_BUCKET: usize = 16;
+/// Largest power-of-two padding bucket; longer secrets are padded to multiples of it
+const MAX_PADDING_BUCKET: usize = 4096;
+#[cfg(feature = "test-helpers")]
+const RNG_FINGERPRINT_DOMAIN: &[u8] = b"shamir_share/v1/rng-fingerprint";
+const RNG_RETRY_BASE_DELAY: Duration = Duration::from_millis(10);
+/// Longest wait between two attempts to seed the RNG
+const MAX_RNG_RETRY_DELAY: Duration = Duration::from_secs(1);
 
 /// A share in Shamir's Secret Sharing scheme
 ///
@@ -25,6 +63,8 @@ const HASH_SIZE: usize = 32; // SHA-256 output size
 /// - Share data is computed using constant-time GF(2^8) arithmetic
 /// - Contains integrity metadata to detect tampering during reconstruction
 /// - Safe to store and transmit independently
+/// - With the `zeroize` feature the share is zeroized on drop, so fields cannot be moved
+///   out of a share
 ///
 /// # Example
 /// ```
@@ -38,12 +78,16 @@ const HASH_SIZE: usize = 32; // SHA-256 output size
 /// assert_eq!(share.threshold, 3);
 /// assert_eq!(share.total_shares, 5);
 /// ```
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 #[cfg_attr(feature = "zeroize", derive(Zeroize, ZeroizeOnDrop))]
 pub struct Share {
     /// Index of the share (x-coordinate in the polynomial)
     pub index: u8,
     /// The share data (y-coordinates for each byte of the secret)
+    ///
+    /// Must have exactly the length produced by splitting: reconstruction requires all
+    /// shares to have equal lengths and treats every byte as share data, so storage padding
+    /// has to be removed first (see [`Share::from_fixed_record`]).
     pub data: Vec<u8>,
     /// Minimum number of shares required for reconstruction
     pub threshold: u8,
@@ -51,8 +95,64 @@ pub struct Share {
     pub total_shares: u8,
     /// Whether integrity checking was enabled when this share was created
     pub integrity_check: bool,
+    /// Algorithm id of the [`IntegrityVerifier`] used when `integrity_check` is set
+    /// (`SHA256_ALGORITHM_ID` for the default verifier)
+    pub integrity_algorithm: u8,
     /// Whether the data was compressed before splitting
     pub compression: bool,
+    /// Whether the secret length was stored for checking on reconstruction (see
+    /// [`Config::with_length_check`])
+    #[serde(default)]
+    pub length_check: bool,
+    /// Number of [`ShamirShare::refresh_shares`] calls this share has been through
+    ///
+    /// 0 for freshly split shares. Shares of different generations lie on different
+    /// polynomials and never reconstruct together.
+    ///
+    /// Advisory only: the generation is not covered by the integrity check, so any holder
+    /// can relabel a share undetected. It catches accidental mixing and staleness, not
+    /// deliberate relabelling.
+    #[serde(default)]
+    pub refresh_generation: u32,
+    /// Public hint for the holder, such as `"quarter-1 backup"`, set by
+    /// [`ShamirShare::split_with_metadata`]
+    ///
+    /// Stored in every share encoding but not part of the secret sharing: it is not covered
+    /// by the integrity check and any holder can rewrite it undetected. Seal the share in
+    /// an [envelope](Share::seal) to authenticate it, and never put secrets in it.
+    #[serde(default)]
+    pub metadata: Vec<u8>,
+    /// Advisory earliest reconstruction time, in seconds since the Unix epoch, set by
+    /// [`ShamirShare::split_with_not_before`]
+    ///
+    /// Only [`ShamirShare::reconstruct_at`] checks it. It is a hint for tooling, not a
+    /// time lock: nothing in the shares depends on it, and like `metadata` any holder can
+    /// change or remove it.
+    #[serde(default)]
+    pub not_before: Option<u64>,
+}
+
+impl Share {
+    /// Moves the share data out into a container that zeroizes it on drop
+    ///
+    /// The share is left with empty data, so afterwards the returned [`Zeroizing`]
+    /// buffer is the only copy of the bytes and is wiped as soon as it goes out of scope.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let data = shares[0].take_data();
+    /// assert!(!data.is_empty());
+    /// assert!(shares[0].data.is_empty());
+    /// ```
+    #[cfg(feature = "zeroize")]
+    pub fn take_data(&mut self) -> Zeroizing<Vec<u8>> {
+        Zeroizing::new(std::mem::take(&mut self.data))
+    }
 }
 
 /// A lightweight view into share data for reconstruction without allocation
@@ -74,6 +174,78 @@ pub struct ShareView<'a> {
     pub data: &'a [u8],
 }
 
+/// Outcome of a [`ShamirShare::reconstruct_stream_lenient`] recovery run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StreamRecoveryReport {
+    /// Number of chunks that verified and were written to the destination
+    pub recovered_chunks: usize,
+    /// Zero-based indices of chunks that failed verification and were skipped
+    pub failed_chunks: Vec<usize>,
+}
+
+/// Progress of a [`ShamirShare::reconstruct_stream_checkpointed`] run, for resuming it
+///
+/// Serializable, so it can be persisted alongside the partial output.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReconstructionCheckpoint {
+    /// Number of chunks reconstructed and written to the destination
+    pub chunks: u64,
+    /// Number of bytes written to the destination
+    pub bytes_written: u64,
+    /// Number of bytes of chunk records read from every source after its header
+    pub source_offset: u64,
+}
+
+/// Byte and chunk counts of a [`ShamirShare::split_stream_with_stats`] run
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SplitStats {
+    /// Number of bytes read from the source
+    pub bytes_read: u64,
+    /// Number of bytes written to each destination, header included, in destination order
+    pub bytes_written: Vec<u64>,
+    /// Number of chunks the source was split into
+    pub chunks: usize,
+}
+
+/// Audit record of a [`ShamirShare::reconstruct_with_metadata`] call
+///
+/// Contains only share metadata, never secret data, so it is safe to log.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReconstructionMetadata {
+    /// Indices of the shares used for interpolation, in the order they were given
+    pub used_indices: Vec<u8>,
+    /// Threshold recorded in the shares
+    pub threshold: u8,
+    /// Whether the reconstructed secret passed an integrity check
+    pub integrity_verified: bool,
+    /// Whether the secret was stored compressed and had to be decompressed
+    pub compressed: bool,
+}
+
+/// Completeness report of a share set, produced by [`ShamirShare::audit_shares`]
+///
+/// Built from share metadata only, so it is safe to log.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ShareAudit {
+    /// Total number of shares recorded in the shares (the largest, if they disagree)
+    pub total_shares: u8,
+    /// Indices in `1..=total_shares` held at least once, in ascending order
+    pub present: Vec<u8>,
+    /// Indices in `1..=total_shares` not held by anyone, in ascending order
+    pub missing: Vec<u8>,
+    /// Indices held more than once, in ascending order
+    pub duplicated: Vec<u8>,
+    /// Indices outside `1..=total_shares`, which no valid share carries, in ascending order
+    pub unexpected: Vec<u8>,
+}
+
+impl ShareAudit {
+    /// Returns true if every index is held exactly once and nothing else is present
+    pub fn is_complete(&self) -> bool {
+        self.missing.is_empty() && self.duplicated.is_empty() && self.unexpected.is_empty()
+    }
+}
+
 /// Lazy iterator for generating shares using Shamir's Secret Sharing
 ///
 /// The `Dealer` provides a memory-efficient way to generate shares on-demand without
@@ -86,6 +258,17 @@ pub struct ShareView<'a> {
 /// - Each share evaluation uses constant-time GF(2^8) arithmetic
 /// - Maximum of 255 shares can be generated (GF(256) field limitation)
 ///
+/// # Memory Lifecycle
+///
+/// The dealer owns two secret-bearing buffers: the prepared secret (with integrity hash
+/// and compression applied) and the random polynomial coefficients. Both are moved in at
+/// construction, so no other copies exist. With the `zeroize` feature they are zeroized
+/// when the dealer is dropped, whether or not iteration ran to completion.
+///
+/// Each yielded [`Share`] owns a freshly allocated data buffer that is moved out of
+/// `next()` without intermediate copies. From then on the share belongs to the caller and
+/// is zeroized when it is dropped (with `zeroize`), independently of the dealer.
+///
 /// # Example
 /// ```
 /// use shamir_share::ShamirShare;
@@ -116,8 +299,12 @@ pub struct Dealer {
     total_shares: u8,
     /// Whether integrity checking is enabled
     integrity_check: bool,
+    /// Algorithm id of the integrity verifier
+    integrity_algorithm: u8,
     /// Whether the data was compressed before splitting
     compression: bool,
+    /// Whether the data starts with the secret length
+    length_check: bool,
 }
 
 /// Main implementation of Shamir's Secret Sharing scheme
@@ -157,7 +344,98 @@ pub struct ShamirShare {
     /// Configuration options for the sharing scheme
     config: Config,
     /// Cryptographically secure random number generator
-    rng: ChaCha20Rng,
+    rng: CoefficientRng,
+}
+
+/// Source of the randomness used for polynomial coefficients, salts and masks
+///
+/// Set with [`ShamirShareBuilder::with_rng_source`]. The default is a ChaCha20 CSPRNG
+/// seeded from the operating system.
+///
+/// # Security
+/// A custom generator replaces all randomness of the scheme: the shares reveal nothing
+/// about the secret only if its output is unpredictable. It must
+/// - be a cryptographically secure generator, such as an approved DRBG, seeded with
+///   enough entropy (at least 256 bits) from a trustworthy source
+/// - never be seeded with a fixed or reused value outside of tests; every split would
+///   repeat its coefficients, and two splits of different secrets under the same
+///   coefficients reveal their difference
+/// - not be observable by others, since its outputs determine the shares
+///
+/// # Example
+/// ```
+/// use rand_chacha::ChaCha20Rng;
+/// use rand_core::SeedableRng;
+/// use shamir_share::{RngSource, ShamirShare};
+///
+/// // A DRBG seeded from an external entropy source, e.g. an HSM
+/// let seed = [0x5A; 32]; // stand-in for 32 bytes read from the entropy source
+/// let drbg = ChaCha20Rng::from_seed(seed);
+///
+/// let mut scheme = ShamirShare::builder(3, 2)
+///     .with_rng_source(RngSource::Custom(Box::new(drbg)))
+///     .build()
+///     .unwrap();
+/// let shares = scheme.split(b"secret").unwrap();
+/// assert_eq!(ShamirShare::reconstruct(&shares[..2]).unwrap(), b"secret");
+/// ```
+#[derive(Default)]
+pub enum RngSource {
+    /// ChaCha20 seeded from the operating system RNG (the default)
+    #[default]
+    OsChaCha20,
+    /// A caller-provided cryptographically secure generator
+    Custom(Box<dyn CryptoRng + Send + Sync>),
+}
+
+impl std::fmt::Debug for RngSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::OsChaCha20 => f.write_str("OsChaCha20"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+/// The generator a [`ShamirShare`] draws from, as selected by [`RngSource`]
+// The default generator stays inline rather than behind a second allocation
+#[allow(clippy::large_enum_variant)]
+enum CoefficientRng {
+    ChaCha20(ChaCha20Rng),
+    Custom(Box<dyn CryptoRng + Send + Sync>),
+}
+
+impl std::fmt::Debug for CoefficientRng {
+    // Never prints generator state
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::ChaCha20(_) => f.write_str("ChaCha20Rng { .. }"),
+            Self::Custom(_) => f.write_str("Custom(..)"),
+        }
+    }
+}
+
+impl RngCore for CoefficientRng {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            Self::ChaCha20(rng) => rng.next_u32(),
+            Self::Custom(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            Self::ChaCha20(rng) => rng.next_u64(),
+            Self::Custom(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dest: &mut [u8]) {
+        match self {
+            Self::ChaCha20(rng) => rng.fill_bytes(dest),
+            Self::Custom(rng) => rng.fill_bytes(dest),
+        }
+    }
 }
 
 /// Builder for creating ShamirShare instances with custom configuration
@@ -184,6 +462,8 @@ pub struct ShamirShareBuilder {
     total_shares: u8,
     threshold: u8,
     config: Config,
+    rng_retries: u8,
+    rng_source: RngSource,
 }
 
 impl ShamirShareBuilder {
@@ -197,6 +477,8 @@ impl ShamirShareBuilder {
             total_shares,
             threshold,
             config: Config::default(),
+            rng_retries: DEFAULT_RNG_RETRIES,
+            rng_source: RngSource::default(),
         }
     }
 
@@ -220,6 +502,37 @@ impl ShamirShareBuilder {
         self
     }
 
+    /// Sets how many times seeding the RNG from the OS is retried after a failure
+    ///
+    /// On entropy starvation (e.g. early boot on embedded systems) `OsRng` may fail
+    /// transiently. `build` retries with an exponential backoff starting at 10ms and capped
+    /// at 1s per attempt, and returns `ShamirError::RngUnavailable` once all attempts have
+    /// failed. The longest total wait, at 255 retries, is about 4 minutes 10 seconds.
+    /// Defaults to 3; pass 0 to fail on the first error.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let shamir = ShamirShare::builder(5, 3)
+    ///     .with_rng_retries(5)
+    ///     .build()
+    ///     .unwrap();
+    /// ```
+    pub fn with_rng_retries(mut self, retries: u8) -> Self {
+        self.rng_retries = retries;
+        self
+    }
+
+    /// Sets the random number generator the scheme draws coefficients from
+    ///
+    /// Defaults to [`RngSource::OsChaCha20`]. See [`RngSource`] for the requirements on
+    /// custom generators. `with_rng_retries` only applies to the default source.
+    pub fn with_rng_source(mut self, source: RngSource) -> Self {
+        self.rng_source = source;
+        self
+    }
+
     /// Builds the ShamirShare instance with validation
     ///
     /// # Returns
@@ -230,34 +543,178 @@ impl ShamirShareBuilder {
     /// - `total_shares` is 0
     /// - `threshold` is 0
     /// - `threshold` > `total_shares`
-    /// - Configuration validation fails
+    /// - `threshold` == `total_shares` and `config.allow_no_fault_tolerance` is false
+    /// - Configuration validation fails (see [`Config::validate_for`])
+    /// - The OS RNG fails on every seeding attempt (`RngUnavailable`)
     pub fn build(self) -> Result<ShamirShare> {
-        // Validate parameters
-        if self.total_shares == 0 {
-            return Err(ShamirError::InvalidShareCount(self.total_shares));
-        }
-        if self.threshold == 0 {
-            return Err(ShamirError::InvalidThreshold(self.threshold));
-        }
-        if self.threshold > self.total_shares {
-            return Err(ShamirError::ThresholdTooLarge {
-                threshold: self.threshold,
-                total_shares: self.total_shares,
-            });
-        }
-
-        // Validate configuration
-        self.config.validate()?;
+        // Validate parameters and configuration together
+        self.config
+            .validate_for(self.total_shares, self.threshold)?;
 
+        let rng = match self.rng_source {
+            RngSource::OsChaCha20 => {
+                CoefficientRng::ChaCha20(seed_rng_with_retry(&mut OsRng, self.rng_retries)?)
+            }
+            RngSource::Custom(rng) => CoefficientRng::Custom(rng),
+        };
         Ok(ShamirShare {
             total_shares: self.total_shares,
             threshold: self.threshold,
             config: self.config,
-            rng: ChaCha20Rng::try_from_rng(&mut OsRng).unwrap(),
+            rng,
         })
     }
 }
 
+/// How the payload of reconstructed data is compressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PayloadCompression {
+    /// Stored as-is
+    None,
+    /// The whole payload is a zstd frame
+    Zstd,
+    /// The payload starts with a marker byte: 1 for a zstd frame, 0 for stored as-is
+    Marked,
+}
+
+impl PayloadCompression {
+    /// Maps the compression flag of a [`Share`]
+    fn from_flag(compression: bool) -> Self {
+        if compression { Self::Zstd } else { Self::None }
+    }
+
+    /// Maps the flags byte of a share stream header
+    ///
+    /// Streams written before per-chunk markers were introduced only set flag 2.
+    fn from_stream_flags(flags: u8) -> Self {
+        if flags & 4 != 0 {
+            Self::Marked
+        } else {
+            Self::from_flag(flags & 2 != 0)
+        }
+    }
+
+    /// Decompresses `payload`, borrowing it when it is stored as-is
+    fn decompress(self, payload: &[u8]) -> Result<Cow<'_, [u8]>> {
+        let (compressed, data) = match self {
+            Self::None => (false, payload),
+            Self::Zstd => (true, payload),
+            Self::Marked => match payload.split_first() {
+                Some((&0, data)) => (false, data),
+                Some((&1, data)) => (true, data),
+                #[cfg(feature = "compress")]
+                _ => {
+                    return Err(ShamirError::DecompressionError(std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        "Invalid chunk compression marker",
+                    )));
+                }
+                #[cfg(not(feature = "compress"))]
+                _ => return Err(ShamirError::InvalidShareFormat),
+            },
+        };
+
+        #[cfg(feature = "compress")]
+        if compressed {
+            return zstd::decode_all(data)
+                .map(Cow::Owned)
+                .map_err(ShamirError::DecompressionError);
+        }
+        #[cfg(not(feature = "compress"))]
+        let _ = compressed;
+
+        Ok(Cow::Borrowed(data))
+    }
+}
+
+/// Compresses `data` with zstd if `enabled` and the result is smaller than `data`
+///
+/// Returns the payload to split and whether it is compressed. zstd framing makes tiny or
+/// incompressible inputs grow, so those are kept as-is, like archivers storing
+/// incompressible blocks.
+fn compress_if_smaller(data: &[u8], enabled: bool) -> Result<(Cow<'_, [u8]>, bool)> {
+    #[cfg(feature = "compress")]
+    if enabled {
+        let compressed = zstd::encode_all(data, 0).map_err(ShamirError::CompressionError)?;
+        if compressed.len() < data.len() {
+            return Ok((Cow::Owned(compressed), true));
+        }
+    }
+    #[cfg(not(feature = "compress"))]
+    let _ = enabled;
+
+    Ok((Cow::Borrowed(data), false))
+}
+
+/// Returns the SHA-256 verifier if `enabled`, for formats that only support the default
+/// verifier (streams and column-oriented reconstruction)
+fn sha256_verifier_if(enabled: bool) -> Option<&'static dyn IntegrityVerifier> {
+    enabled.then_some(&Sha256Verifier)
+}
+
+/// Generates the README of a recovery kit from its shares and file names
+fn recovery_instructions(shares: &[Share], share_files: &[String], manifest_file: &str) -> String {
+    let Some(first) = shares.first() else {
+        return String::new();
+    };
+    let (threshold, total) = (first.threshold, first.total_shares);
+
+    let mut text = format!(
+        "SHAMIR SECRET SHARING RECOVERY KIT\n\
+         \n\
+         A secret was split into {total} shares. Recovering it takes any {threshold} of \
+         the {total} shares; fewer reveal nothing about it.\n\
+         \n\
+         Share files:\n"
+    );
+    for (share, name) in shares.iter().zip(share_files) {
+        text.push_str(&format!(
+            "  {name}  share {} of {total}, fingerprint {}\n",
+            share.index,
+            share.short_fingerprint()
+        ));
+    }
+    text.push_str(&format!(
+        "\n\
+         Give each share file to a different person or keep it in a different place. \
+         Anyone holding {threshold} share files can recover the secret, so never keep \
+         {threshold} or more of them together.\n\
+         \n\
+         {manifest_file} records the scheme parameters and the shares issued. It holds \
+         no secret data and can be copied to every holder.\n\
+         \n\
+         To recover the secret, copy {threshold} share files into one directory and load \
+         them with the shamir_share library:\n\
+         \n\
+         \x20   let store = FileShareStore::new(\"<directory>\")?;\n\
+         \x20   let shares = store.iter_shares()?.collect::<Result<Vec<_>>>()?;\n\
+         \x20   let secret = ShamirShare::reconstruct(&shares)?;\n\
+         \n\
+         Compare each share's fingerprint (Share::short_fingerprint) with the list above \
+         to check that it is an unaltered share of this kit.\n"
+    ));
+    text
+}
+
+/// Seeds a ChaCha20Rng from `source`, retrying up to `retries` times with exponential backoff
+fn seed_rng_with_retry<R: TryRngCore>(source: &mut R, retries: u8) -> Result<ChaCha20Rng> {
+    let mut delay = RNG_RETRY_BASE_DELAY;
+    let mut attempt = 0;
+    loop {
+        match ChaCha20Rng::try_from_rng(source) {
+            Ok(rng) => return Ok(rng),
+            Err(e) if attempt >= retries => {
+                return Err(ShamirError::RngUnavailable(e.to_string()));
+            }
+            Err(_) => {
+                thread::sleep(delay);
+                delay = delay.saturating_mul(2).min(MAX_RNG_RETRY_DELAY);
+                attempt += 1;
+            }
+        }
+    }
+}
+
 impl ShamirShare {
     /// Returns the threshold (minimum number of shares needed for reconstruction)
     pub fn threshold(&self) -> u8 {
@@ -269,6 +726,104 @@ impl ShamirShare {
         self.total_shares
     }
 
+    /// Returns how many shares can be lost while the secret stays recoverable
+    ///
+    /// This is `total_shares - threshold`. A value of zero means every share is required.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// assert_eq!(scheme.fault_tolerance(), 2);
+    ///
+    /// let fragile = ShamirShare::builder(3, 3).build().unwrap();
+    /// assert_eq!(fragile.fault_tolerance(), 0);
+    /// ```
+    pub fn fault_tolerance(&self) -> u8 {
+        self.total_shares - self.threshold
+    }
+
+    /// Recommends padding that hides the exact length of a secret
+    ///
+    /// Shares are as long as the secret plus fixed overhead, so anyone who sees a share
+    /// learns the secret's length, which can tell a password from a key or narrow down
+    /// a password. Padding to a size bucket leaves only the bucket visible. Buckets are
+    /// powers of two from 16 bytes up to 4 KiB, then multiples of 4 KiB, so padding at
+    /// most doubles a secret longer than 16 bytes and adds less than 4 KiB to one longer
+    /// than 4 KiB.
+    ///
+    /// Advisory and pure: this crate does not pad. Pad before splitting with a scheme the
+    /// reader can undo, e.g. a `0x80` byte followed by zeros, and strip it after
+    /// reconstruction. Compression defeats padding, since compressed sizes depend on the
+    /// content; leave it off for padded secrets.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if the padded length does not fit in `usize`.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let advice = ShamirShare::padding_recommendation(20).unwrap();
+    /// assert!(advice.recommended);
+    /// assert_eq!((advice.pad_to_multiple, advice.padded_len), (32, 32));
+    ///
+    /// assert!(!ShamirShare::padding_recommendation(32).unwrap().recommended);
+    /// ```
+    pub fn padding_recommendation(secret_len: usize) -> Result<PaddingAdvice> {
+        let pad_to_multiple = if secret_len <= MAX_PADDING_BUCKET {
+            secret_len.max(MIN_PADDING_BUCKET).next_power_of_two()
+        } else {
+            MAX_PADDING_BUCKET
+        };
+        let padded_len = secret_len
+            .div_ceil(pad_to_multiple)
+            .max(1)
+            .checked_mul(pad_to_multiple)
+            .ok_or_else(|| {
+                ShamirError::InvalidConfig(format!(
+                    "Cannot pad a {secret_len}-byte secret to a multiple of {pad_to_multiple} bytes"
+                ))
+            })?;
+        Ok(PaddingAdvice {
+            recommended: padded_len != secret_len,
+            pad_to_multiple,
+            padded_len,
+        })
+    }
+
+    /// Returns a short fingerprint of the RNG's next output, for test determinism checks
+    ///
+    /// Draws 32 throwaway bytes from the scheme's RNG and returns the first 8 bytes of
+    /// their domain-separated SHA-256 hash. Two schemes seeded differently yield different
+    /// fingerprints with overwhelming probability; schemes given identically seeded
+    /// [`RngSource::Custom`] generators yield equal ones.
+    ///
+    /// This advances the RNG: the drawn bytes are never used for shares, and the next
+    /// split differs from the one that would have been made without the call. Only
+    /// available with the `test-helpers` feature.
+    ///
+    /// # Security
+    /// The hash does not reveal the drawn bytes or the generator state, and the drawn
+    /// bytes are zeroized (with `zeroize`).
+    #[cfg(feature = "test-helpers")]
+    pub fn rng_fingerprint(&mut self) -> [u8; 8] {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut output = [0u8; 32];
+        self.rng.fill_bytes(&mut output);
+        let digest = Sha256::new()
+            .chain_update(RNG_FINGERPRINT_DOMAIN)
+            .chain_update(output)
+            .finalize();
+        #[cfg(feature = "zeroize")]
+        output.zeroize();
+
+        let mut fingerprint = [0u8; 8];
+        fingerprint.copy_from_slice(&digest[..8]);
+        fingerprint
+    }
+
     /// Creates a builder for configuring a ShamirShare instance
     ///
     /// This is the recommended way to create ShamirShare instances as it allows
@@ -296,6 +851,99 @@ impl ShamirShare {
         ShamirShareBuilder::new(total_shares, threshold)
     }
 
+    /// Creates a builder for `holders` shares that survives losing a fraction of them
+    ///
+    /// Translates a resilience goal such as "tolerate losing 30% of holders" into scheme
+    /// parameters: `total_shares = holders` and
+    /// `threshold = ceil(holders * (1 - loss_tolerance_fraction))`. When the tolerated
+    /// fraction is not a whole number of holders, it is rounded down, so the scheme never
+    /// promises more than requested: losing 30% of 5 holders tolerates 1 loss, not 2.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `loss_tolerance_fraction` is not in `0.0..1.0` (`InvalidConfig`); losing every
+    ///   holder can never be tolerated
+    /// - The resulting parameters are invalid for the default configuration, e.g.
+    ///   `holders` is 0 (see [`Config::validate_for`])
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let scheme = ShamirShare::builder_for_resilience(10, 0.3)
+    ///     .unwrap()
+    ///     .build()
+    ///     .unwrap();
+    /// assert_eq!(scheme.total_shares(), 10);
+    /// assert_eq!(scheme.threshold(), 7);
+    /// ```
+    pub fn builder_for_resilience(
+        holders: u8,
+        loss_tolerance_fraction: f64,
+    ) -> Result<ShamirShareBuilder> {
+        if !(0.0..1.0).contains(&loss_tolerance_fraction) {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Loss tolerance must be in 0.0..1.0, got {loss_tolerance_fraction}"
+            )));
+        }
+
+        // The small offset absorbs float error, e.g. 100.0 * 0.29 == 28.999999999999996
+        let tolerated = (f64::from(holders) * loss_tolerance_fraction + 1e-9).floor() as u8;
+        let threshold = holders - tolerated;
+        Config::new().validate_for(holders, threshold)?;
+        Ok(Self::builder(holders, threshold))
+    }
+
+    /// Builds a scheme matching the parameters recorded in `share`
+    ///
+    /// For recovery tools holding only stored shares: the scheme gets the share's
+    /// `threshold` and `total_shares`, and a configuration with its integrity check,
+    /// compression and length check flags. All other settings are defaults. New shares
+    /// from the scheme, e.g. from [`refresh_shares`](Self::refresh_shares), carry the same
+    /// metadata as `share`.
+    ///
+    /// `compression` records whether this share's secret was actually compressed, so a
+    /// scheme configured to compress whose secret did not shrink is rebuilt without
+    /// compression.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The share uses a custom integrity algorithm, whose verifier cannot be recovered
+    ///   from its id (`InvalidConfig`); build the scheme with
+    ///   [`Config::with_verifier`] instead
+    /// - The recorded parameters are invalid, e.g. `threshold > total_shares` or
+    ///   compression without the `compress` feature (see [`Config::validate_for`])
+    /// - The OS RNG fails on every seeding attempt (`RngUnavailable`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut original = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = original.split(b"recovered").unwrap();
+    ///
+    /// let scheme = ShamirShare::from_share_metadata(&shares[0]).unwrap();
+    /// assert_eq!((scheme.total_shares(), scheme.threshold()), (5, 3));
+    /// ```
+    pub fn from_share_metadata(share: &Share) -> Result<ShamirShare> {
+        if share.integrity_check && builtin_verifier(share.integrity_algorithm).is_none() {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Share uses custom integrity algorithm {}; supply its verifier with \
+                 Config::with_verifier",
+                share.integrity_algorithm
+            )));
+        }
+        let config = Config {
+            integrity_check: share.integrity_check,
+            compression: share.compression,
+            length_check: share.length_check,
+            ..Config::new()
+        };
+        Self::builder(share.total_shares, share.threshold)
+            .with_config(config)
+            .build()
+    }
+
     /// Creates a lazy iterator for generating shares on-demand
     ///
     /// This method provides a memory-efficient way to generate shares without storing
@@ -314,6 +962,14 @@ impl ShamirShare {
     /// - SHA-256 integrity hash is included if `config.integrity_check` is true
     /// - Maximum of 255 shares can be generated (GF(256) field limitation)
     ///
+    /// # Panics
+    /// Panics where [`split`](Self::split) returns an error instead:
+    /// - Compression is enabled and zstd fails to compress the secret, e.g. because it
+    ///   cannot allocate its context
+    /// - The length check is enabled and the secret is 4 GiB or longer
+    /// - `config.fixed_record_size` is set and the shares do not fit in records of that
+    ///   size
+    ///
     /// # Example
     /// ```
     /// use shamir_share::ShamirShare;
@@ -332,83 +988,174 @@ impl ShamirShare {
     /// }
     /// ```
     pub fn dealer(&mut self, secret: &[u8]) -> Dealer {
-        // Prepare data to split based on integrity check configuration
-        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
-        let mut data_to_split = if self.config.integrity_check {
-            // Calculate hash of the secret and prepend it
-            let hash = Sha256::digest(secret);
-            let mut data = Vec::with_capacity(HASH_SIZE + secret.len());
-            data.extend_from_slice(&hash);
-            #[cfg(feature = "compress")]
-            if self.config.compression {
-                let compressed_secret = zstd::encode_all(secret, 0)
-                    .map_err(|e| ShamirError::CompressionError(e.to_string()))
-                    .unwrap();
-                data.extend_from_slice(&compressed_secret);
-            } else {
-                data.extend_from_slice(secret);
-            }
-            #[cfg(not(feature = "compress"))]
-            data.extend_from_slice(secret);
-            data
-        } else {
-            // Use secret data directly without integrity hash
-            #[cfg(feature = "compress")]
-            if self.config.compression {
-                zstd::encode_all(secret, 0)
-                    .map_err(|e| ShamirError::CompressionError(e.to_string()))
-                    .unwrap()
-            } else {
-                secret.to_vec()
-            }
-            #[cfg(not(feature = "compress"))]
-            secret.to_vec()
-        };
+        self.try_dealer(secret)
+            .unwrap_or_else(|e| panic!("cannot split the secret: {e}"))
+    }
+
+    /// Builds a dealer for `secret`, returning compression errors instead of panicking
+    fn try_dealer(&mut self, secret: &[u8]) -> Result<Dealer> {
+        let (data_to_split, compression) = self.prepare_secret(secret)?;
 
         let secret_len = data_to_split.len();
         let t = self.threshold as usize;
 
         // Pre-compute all random polynomial coefficients (for coefficients 1..t)
         let mut coefficients = vec![0u8; secret_len * (t - 1)];
-        self.rng.fill_bytes(&mut coefficients);
+        if self.config.deterministic_coefficients {
+            let mut rng = self.derive_coefficient_rng(&data_to_split);
+            rng.fill_bytes(&mut coefficients);
+        } else {
+            self.rng.fill_bytes(&mut coefficients);
+        }
 
-        let dealer = Dealer {
-            data: data_to_split.clone(),
-            coefficients: coefficients.clone(),
+        Ok(self.dealer_from_parts(data_to_split, coefficients, compression))
+    }
+
+    /// Builds a dealer for prepared data and its polynomial coefficients
+    ///
+    /// Moves the buffers into the dealer rather than copying them, so no extra copies of
+    /// the sensitive data exist; the dealer zeroizes them on drop.
+    fn dealer_from_parts(&self, data: Vec<u8>, coefficients: Vec<u8>, compression: bool) -> Dealer {
+        Dealer {
+            data,
+            coefficients,
             current_x: 1,
             threshold: self.threshold,
             total_shares: self.total_shares,
             integrity_check: self.config.integrity_check,
-            compression: self.config.compression,
+            integrity_algorithm: self.config.verifier.algorithm_id(),
+            compression,
+            length_check: self.config.length_check,
+        }
+    }
+
+    /// Prepares a secret for splitting: compresses it if configured and worthwhile, and
+    /// prepends the integrity tag if integrity checking is enabled
+    ///
+    /// # Returns
+    /// The data to split and whether it is compressed
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if zstd fails (`CompressionError`), or the secret is too long
+    /// for the 4-byte length check (`InvalidConfig`).
+    fn prepare_secret(&self, secret: &[u8]) -> Result<(Vec<u8>, bool)> {
+        let length_prefix = if self.config.length_check {
+            let secret_len = u32::try_from(secret.len()).map_err(|_| {
+                ShamirError::InvalidConfig(format!(
+                    "The length check supports secrets below 4 GiB, got {} bytes",
+                    secret.len()
+                ))
+            })?;
+            Some(secret_len.to_le_bytes())
+        } else {
+            None
         };
 
-        // Zeroize sensitive buffers before returning
+        // Compress the secret only if that makes it smaller, recording the outcome per secret
+        let (payload, compression) = compress_if_smaller(secret, self.config.compression)?;
+
+        // Calculate the integrity tag of the secret if integrity checking is enabled
+        let tag = self
+            .config
+            .integrity_check
+            .then(|| self.config.verifier.compute(secret));
+
+        // Layout: [secret length, if length-checked][integrity tag, if enabled][payload]
+        let length_len = length_prefix.map_or(0, |prefix| prefix.len());
+        let tag_len = tag.as_ref().map_or(0, Vec::len);
+        let mut data_to_split = Vec::with_capacity(length_len + tag_len + payload.len());
+        if let Some(prefix) = &length_prefix {
+            data_to_split.extend_from_slice(prefix);
+        }
+        if let Some(tag) = &tag {
+            data_to_split.extend_from_slice(tag);
+        }
+        data_to_split.extend_from_slice(&payload);
+
         #[cfg(feature = "zeroize")]
-        {
-            data_to_split.zeroize();
-            coefficients.zeroize();
+        if let Cow::Owned(mut compressed) = payload {
+            compressed.zeroize();
         }
 
-        dealer
+        match self.check_record_size(data_to_split.len(), 0, false) {
+            Ok(()) => Ok((data_to_split, compression)),
+            Err(e) => {
+                #[cfg(feature = "zeroize")]
+                data_to_split.zeroize();
+                Err(e)
+            }
+        }
     }
 
-    /// Splits a secret into multiple shares using polynomial interpolation
-    ///
-    /// This method uses constant-time GF(2^8) arithmetic and cryptographically secure
-    /// random number generation to create shares. If `config.integrity_check` is enabled,
-    /// a SHA-256 hash is prepended to the secret for integrity verification during reconstruction.
-    ///
-    /// # Arguments
-    /// * `secret` - Byte slice to protect
+    /// Checks that shares with these fields fit in `config.fixed_record_size` bytes
     ///
-    /// # Returns
-    /// Vector of [`Share`] objects containing the split data
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if a record size is set and the encoded share
+    /// is larger.
+    fn check_record_size(
+        &self,
+        data_len: usize,
+        metadata_len: usize,
+        not_before: bool,
+    ) -> Result<()> {
+        if let Some(record_size) = self.config.fixed_record_size {
+            let needed = encoded_len(data_len, metadata_len, not_before);
+            if needed > record_size {
+                return Err(ShamirError::InvalidConfig(format!(
+                    "Share needs {needed} bytes but the record size is {record_size}"
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Derives a coefficient generator from the prepared secret for deterministic splitting
+    ///
+    /// HKDF-SHA256 over the prepared data (integrity hash, compression already applied)
+    /// with a fixed domain-separation salt produces a 32-byte seed for ChaCha20. The
+    /// threshold is bound into the HKDF info, so the same secret split with a different
+    /// threshold yields an unrelated polynomial.
+    fn derive_coefficient_rng(&self, prepared_data: &[u8]) -> ChaCha20Rng {
+        let hkdf = Hkdf::<Sha256>::new(Some(DETERMINISTIC_COEFFICIENTS_SALT), prepared_data);
+        let mut info = Vec::with_capacity(DETERMINISTIC_COEFFICIENTS_INFO.len() + 1);
+        info.extend_from_slice(DETERMINISTIC_COEFFICIENTS_INFO);
+        info.push(self.threshold);
+
+        let mut seed = [0u8; 32];
+        hkdf.expand(&info, &mut seed)
+            .expect("32 bytes is a valid HKDF-SHA256 output length");
+        let rng = ChaCha20Rng::from_seed(seed);
+
+        #[cfg(feature = "zeroize")]
+        seed.zeroize();
+
+        rng
+    }
+
+    /// Splits a secret into multiple shares using polynomial interpolation
+    ///
+    /// This method uses constant-time GF(2^8) arithmetic and cryptographically secure
+    /// random number generation to create shares. If `config.integrity_check` is enabled,
+    /// a SHA-256 hash is prepended to the secret for integrity verification during reconstruction.
+    ///
+    /// # Arguments
+    /// * `secret` - Byte slice to protect
+    ///
+    /// # Returns
+    /// Vector of [`Share`] objects containing the split data
     ///
     /// # Security
     /// - Uses ChaCha20Rng for generating polynomial coefficients
     /// - All operations are constant-time to prevent side-channel attacks
     /// - SHA-256 integrity hash is included if `config.integrity_check` is true
     ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Compression is enabled and zstd fails (`CompressionError`)
+    /// - The length check is enabled and the secret is 4 GiB or longer (`InvalidConfig`)
+    /// - `config.fixed_record_size` is set and the shares do not fit in records of that
+    ///   size (`InvalidConfig`)
+    ///
     /// # Example
     /// ```
     /// use shamir_share::ShamirShare;
@@ -418,2000 +1165,7782 @@ impl ShamirShare {
     /// assert_eq!(shares.len(), 5);
     /// ```
     pub fn split(&mut self, secret: &[u8]) -> Result<Vec<Share>> {
+        #[cfg(feature = "timing-harness")]
+        let _timer = crate::timing::OperationTimer::start(crate::timing::Operation::Split);
         // Use the new dealer for backward compatibility
         Ok(self
-            .dealer(secret)
+            .try_dealer(secret)?
             .take(self.total_shares as usize)
             .collect())
     }
 
-    /// Reconstructs the original secret from shares using Lagrange interpolation
+    /// Splits a secret using the given RNG for the polynomial coefficients
     ///
-    /// This method uses constant-time GF(2^8) arithmetic for reconstruction and performs
-    /// integrity verification using constant-time hash comparison to detect tampering if
-    /// the shares were created with integrity checking enabled.
+    /// Behaves like [`split`](Self::split), but draws the coefficients from `rng` instead
+    /// of the scheme's internal CSPRNG, e.g. a hardware RNG, or a seeded generator in tests
+    /// to get reproducible shares. `rng` is used even when deterministic coefficients are
+    /// configured.
     ///
-    /// # Arguments
-    /// * `shares` - Slice of shares to use for reconstruction
+    /// # Security
+    /// The shares are only as unpredictable as `rng`. Never use a seeded generator with a
+    /// fixed or guessable seed for real secrets.
+    ///
+    /// # Example
+    /// ```
+    /// use rand_chacha::ChaCha20Rng;
+    /// use rand_core::SeedableRng;
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let mut rng = ChaCha20Rng::seed_from_u64(7);
+    /// let shares = scheme.split_with_rng(b"secret", &mut rng).unwrap();
+    ///
+    /// assert_eq!(ShamirShare::reconstruct(&shares[..3]).unwrap(), b"secret");
+    /// ```
+    pub fn split_with_rng<R: RngCore + CryptoRng>(
+        &mut self,
+        secret: &[u8],
+        rng: &mut R,
+    ) -> Result<Vec<Share>> {
+        let (data, compression) = self.prepare_secret(secret)?;
+
+        let mut coefficients = vec![0u8; data.len() * (self.threshold as usize - 1)];
+        rng.fill_bytes(&mut coefficients);
+
+        Ok(self
+            .dealer_from_parts(data, coefficients, compression)
+            .take(self.total_shares as usize)
+            .collect())
+    }
+
+    /// Splits a secret into `total_shares` shares plus `parity_count` parity shares
+    ///
+    /// Parity shares are further evaluations of the same polynomial at indices
+    /// `total_shares + 1..=total_shares + parity_count`, which makes the full set a
+    /// Reed-Solomon codeword. Passing all shares to
+    /// [`verify_with_parity`](Self::verify_with_parity) then detects and locates corrupted
+    /// shares without relying on the integrity hash, so integrity checking can be disabled.
     ///
     /// # Returns
-    /// The original secret data if reconstruction and integrity verification succeed
+    /// The `total_shares` data shares followed by the parity shares. Every returned share
+    /// records `total_shares + parity_count` as its [`total_shares`](Share::total_shares),
+    /// so index checks such as [`reconstruct_strict`](Self::reconstruct_strict) and
+    /// [`audit_shares`](Self::audit_shares) count the parity shares as part of the set.
     ///
     /// # Security
-    /// - All GF(2^8) operations are constant-time
-    /// - SHA-256 integrity verification with constant-time comparison (if enabled)
-    /// - Parallel processing maintains security guarantees
+    /// Parity shares are ordinary shares: any `threshold` shares, parity or not, reconstruct
+    /// the secret. Keep them with the auditor rather than handing them out as extra shares.
     ///
     /// # Errors
-    /// Returns `ShamirError` if:
-    /// - No shares provided
-    /// - Insufficient shares for threshold
-    /// - Shares have inconsistent lengths or integrity check settings
-    /// - Invalid share data
-    /// - Integrity check fails (tampering detected)
+    /// Returns `ShamirError::InvalidConfig` if `total_shares + parity_count` exceeds 255.
     ///
     /// # Example
     /// ```
-    /// use shamir_share::ShamirShare;
+    /// use shamir_share::{Config, ShamirShare};
     ///
-    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
-    /// let shares = scheme.split(b"data").unwrap();
+    /// let config = Config::new().with_integrity_check(false);
+    /// let mut scheme = ShamirShare::builder(5, 3).with_config(config).build().unwrap();
+    /// let shares = scheme.split_with_parity(b"secret", 2).unwrap();
     ///
-    /// // Reconstruct with first 3 shares
-    /// let secret = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-    /// assert_eq!(secret, b"data");
+    /// assert_eq!(shares.len(), 7);
+    /// assert_eq!(shares[5].index, 6);
+    /// assert!(ShamirShare::verify_with_parity(&shares).unwrap().is_empty());
+    /// assert!(ShamirShare::audit_shares(&shares).is_complete());
     /// ```
-    pub fn reconstruct(shares: &[Share]) -> Result<Vec<u8>> {
-        if shares.is_empty() {
-            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
-        }
-
-        let threshold = shares[0].threshold;
-        if shares.len() < threshold as usize {
-            return Err(ShamirError::InsufficientShares {
-                needed: threshold,
-                got: shares.len() as u8,
-            });
-        }
-
-        let integrity_check = shares[0].integrity_check;
-        let compression = shares[0].compression;
-
-        // Ensure all shares have consistent properties
-        if !shares.iter().all(|s| {
-            s.data.len() == shares[0].data.len()
-                && s.integrity_check == integrity_check
-                && s.compression == compression
-        }) {
-            return Err(ShamirError::InconsistentShareLength);
+    pub fn split_with_parity(&mut self, secret: &[u8], parity_count: u8) -> Result<Vec<Share>> {
+        let count = self.total_shares as usize + parity_count as usize;
+        if count > u8::MAX as usize {
+            return Err(ShamirError::InvalidConfig(format!(
+                "{} shares plus {parity_count} parity shares exceed the 255 share limit",
+                self.total_shares
+            )));
         }
 
-        // Use the unified reconstruct_chunk method for the core reconstruction logic
-        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
-        let mut reconstructed_data = Self::reconstruct_chunk(shares)?;
-
-        // Handle integrity checking based on share configuration
-        let result = if integrity_check {
-            // Shares were created with integrity checking - verify hash
-            if reconstructed_data.len() < HASH_SIZE {
-                return Err(ShamirError::IntegrityCheckFailed);
-            }
-            let (reconstructed_hash, compressed_secret) = reconstructed_data.split_at(HASH_SIZE);
-
-            let secret = {
-                #[cfg(feature = "compress")]
-                if compression {
-                    zstd::decode_all(compressed_secret)
-                        .map_err(|e| ShamirError::DecompressionError(e.to_string()))?
-                } else {
-                    compressed_secret.to_vec()
-                }
-                #[cfg(not(feature = "compress"))]
-                compressed_secret.to_vec()
-            };
-
-            // Verify the integrity of the secret using constant-time comparison
-            let calculated_hash = Sha256::digest(&secret);
-            let mut hash_match = 0u8;
-            for (a, b) in calculated_hash
-                .as_slice()
-                .iter()
-                .zip(reconstructed_hash.iter())
-            {
-                hash_match |= a ^ b;
-            }
-            if hash_match != 0 {
-                return Err(ShamirError::IntegrityCheckFailed);
-            }
-
-            Ok(secret)
-        } else {
-            // Shares were created without integrity checking - return data directly
-            #[cfg(feature = "compress")]
-            if compression {
-                zstd::decode_all(reconstructed_data.as_slice())
-                    .map_err(|e| ShamirError::DecompressionError(e.to_string()))
-            } else {
-                Ok(reconstructed_data.clone())
-            }
-            #[cfg(not(feature = "compress"))]
-            Ok(reconstructed_data.clone())
-        };
-
-        // Zeroize sensitive reconstructed data buffer before returning
-        #[cfg(feature = "zeroize")]
-        reconstructed_data.zeroize();
-
-        result
+        let total_shares = count as u8;
+        Ok(self
+            .try_dealer(secret)?
+            .take(count)
+            .map(|mut share| {
+                share.total_shares = total_shares;
+                share
+            })
+            .collect())
     }
 
-    /// Splits data from a stream into multiple share streams using chunk-based processing
+    /// Splits a secret like [`split`](Self::split) and commits to it
     ///
-    /// This method reads data from the source in chunks of `config.chunk_size`, splits each chunk
-    /// independently, and writes the resulting shares to the destination writers. Each chunk is
-    /// processed with optional integrity checking and written with length prefixes for reconstruction.
+    /// The returned [`Commitment`] is a salted SHA-256 of the secret, with a fresh salt
+    /// from the scheme's CSPRNG. Publish it alongside the split; once the secret is
+    /// reconstructed, [`Commitment::verify`] shows anyone that it is the value the dealer
+    /// committed to.
     ///
-    /// # Arguments
-    /// * `source` - Reader to read data from
-    /// * `destinations` - Array of writers, one for each share (must equal `total_shares`)
+    /// # Security
+    /// The commitment lets anyone test guesses of the secret offline. Only commit to
+    /// secrets with enough entropy to resist guessing, such as keys.
     ///
-    /// # Data Format
-    /// Each destination stream contains a header followed by a sequence of chunks:
-    /// ```text
-    /// [1-byte integrity flag][1-byte share index][4-byte length][share data for chunk 1][4-byte length][share data for chunk 2]...
+    /// # Errors
+    /// Same as [`split`](Self::split).
+    ///
+    /// # Example
     /// ```
-    /// - The integrity flag indicates whether integrity checking was used (1 = enabled, 0 = disabled)
-    /// - The share index indicates which share this stream represents (1-based)
-    /// - The length is written in little-endian format and represents the size of the following share data
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let (shares, commitment) = scheme.split_with_commitment(b"sealed bid").unwrap();
+    ///
+    /// let secret = ShamirShare::reconstruct(&shares[1..]).unwrap();
+    /// assert!(commitment.verify(&secret));
+    /// ```
+    pub fn split_with_commitment(&mut self, secret: &[u8]) -> Result<(Vec<Share>, Commitment)> {
+        let shares = self.split(secret)?;
+        let mut salt = [0u8; COMMITMENT_SALT_SIZE];
+        self.rng.fill_bytes(&mut salt);
+        Ok((shares, Commitment::new(secret, salt)))
+    }
+
+    /// Splits a secret like [`split`](Self::split) and attaches a public hint to each share
+    ///
+    /// `per_share_metadata[i]` becomes the [`metadata`](Share::metadata) of the share with
+    /// index `i + 1`, e.g. a label telling its holder what the share is for.
     ///
     /// # Security
-    /// - Each chunk is processed independently with its own integrity hash (if enabled)
-    /// - Constant-time operations maintain security guarantees
-    /// - Chunk-level integrity checking allows for early detection of corruption
+    /// The metadata is stored in the clear and is not covered by the integrity check, so
+    /// it is unauthenticated: a holder can change it without affecting reconstruction.
+    /// Send shares in an [envelope](Share::seal) if the hint must be trusted.
     ///
     /// # Errors
-    /// Returns `ShamirError` if:
-    /// - Number of destinations doesn't match `total_shares`
-    /// - I/O errors occur during reading or writing
-    /// - Memory allocation fails for large chunks
+    /// Returns `ShamirError::InvalidConfig` if `per_share_metadata` does not hold exactly
+    /// one entry per share, or any error from [`split`](Self::split).
     ///
     /// # Example
     /// ```
-    /// use shamir_share::{ShamirShare, Config};
-    /// use std::io::Cursor;
+    /// use shamir_share::ShamirShare;
     ///
-    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-    /// let data = b"This is a test message for streaming";
-    /// let mut source = Cursor::new(data);
-    /// let mut destinations = vec![Vec::new(); 3];
-    /// let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-    ///     .iter_mut()
-    ///     .map(|d| Cursor::new(std::mem::take(d)))
-    ///     .collect();
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let hints = [b"office safe".to_vec(), b"bank box".to_vec(), b"lawyer".to_vec()];
+    /// let shares = scheme.split_with_metadata(b"secret", &hints).unwrap();
     ///
-    /// shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+    /// assert_eq!(shares[1].metadata, b"bank box");
+    /// assert_eq!(ShamirShare::reconstruct(&shares[1..]).unwrap(), b"secret");
     /// ```
-    pub fn split_stream<R: Read, W: Write>(
+    pub fn split_with_metadata(
         &mut self,
-        source: &mut R,
-        destinations: &mut [W],
-    ) -> Result<()> {
-        // Validate that we have the correct number of destinations
-        if destinations.len() != self.total_shares as usize {
+        secret: &[u8],
+        per_share_metadata: &[Vec<u8>],
+    ) -> Result<Vec<Share>> {
+        if per_share_metadata.len() != self.total_shares as usize {
             return Err(ShamirError::InvalidConfig(format!(
-                "Expected {} destinations, got {}",
+                "Expected metadata for {} shares, got {}",
                 self.total_shares,
-                destinations.len()
+                per_share_metadata.len()
             )));
         }
-
-        // Write header (flags + share index) to all destinations
-        let integrity_flag = if self.config.integrity_check { 1 } else { 0 };
-        let compression_flag = if self.config.compression { 2 } else { 0 };
-        let flags = integrity_flag | compression_flag;
-
-        for (i, dest) in destinations.iter_mut().enumerate() {
-            dest.write_all(&[flags, (i + 1) as u8])
-                .map_err(ShamirError::IoError)?;
+        let mut shares = self.split(secret)?;
+        for (share, metadata) in shares.iter_mut().zip(per_share_metadata) {
+            self.check_record_size(share.data.len(), metadata.len(), false)?;
+            share.metadata = metadata.clone();
         }
+        Ok(shares)
+    }
 
-        let chunk_size = self.config.chunk_size;
+    /// Splits a fixed-size scalar, such as an Ed25519 private scalar, into shares
+    ///
+    /// The scalar is shared as its `N`-byte encoding, byte by byte over GF(2^8), so
+    /// [`reconstruct_scalar`](Self::reconstruct_scalar) returns exactly the same bytes.
+    /// Pair the two to have the reconstructed length checked against `N`.
+    ///
+    /// # Security
+    /// The sharing knows nothing of the scalar's own field. Shares cannot be combined with
+    /// scalar arithmetic (e.g. for threshold signatures), and the reconstructed bytes are
+    /// not checked to be a canonical encoding; validate them with the scalar type's own
+    /// parser.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if `N` is 0, or any error from
+    /// [`split`](Self::split).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let scalar = [0x42u8; 32];
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split_scalar(&scalar).unwrap();
+    ///
+    /// let restored: [u8; 32] = ShamirShare::reconstruct_scalar(&shares[1..]).unwrap();
+    /// assert_eq!(restored, scalar);
+    /// ```
+    pub fn split_scalar<const N: usize>(&mut self, scalar: &[u8; N]) -> Result<Vec<Share>> {
+        if N == 0 {
+            return Err(ShamirError::InvalidConfig(
+                "A scalar must be at least one byte long".to_string(),
+            ));
+        }
+        self.split(scalar)
+    }
 
-        // Reuse buffers to avoid allocations in the hot loop
-        let mut chunk_read_buffer = vec![0u8; chunk_size];
-        let mut chunk_with_hash_buffer = Vec::with_capacity(if self.config.integrity_check {
-            HASH_SIZE + chunk_size
-        } else {
-            chunk_size
-        });
+    /// Reconstructs a scalar split with [`split_scalar`](Self::split_scalar)
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The reconstructed secret is not `N` bytes long (`InvalidReconstructedLength`),
+    ///   e.g. when the shares hold a scalar of another size or a byte string
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    pub fn reconstruct_scalar<const N: usize>(shares: &[Share]) -> Result<[u8; N]> {
+        Self::reconstruct_array(shares)
+    }
 
-        // Pre-allocate share data buffers to reuse across chunks
-        let max_chunk_size_with_hash = if self.config.integrity_check {
-            HASH_SIZE + chunk_size
-        } else {
-            chunk_size
-        };
-        let mut share_data_buffers: Vec<Vec<u8>> = (0..self.total_shares)
-            .map(|_| Vec::with_capacity(max_chunk_size_with_hash))
-            .collect();
+    /// Splits a secret like [`split`](Self::split), marking the shares with an advisory
+    /// not-before time
+    ///
+    /// `not_before` is in seconds since the Unix epoch and is stored as every share's
+    /// [`not_before`](Share::not_before), for [`reconstruct_at`](Self::reconstruct_at) to
+    /// check.
+    ///
+    /// # Security
+    /// The time is a hint, not a time lock: the shares reconstruct at any time with
+    /// [`reconstruct`](Self::reconstruct), and holders can change the stored time.
+    ///
+    /// # Errors
+    /// Same as [`split`](Self::split).
+    pub fn split_with_not_before(&mut self, secret: &[u8], not_before: u64) -> Result<Vec<Share>> {
+        let mut shares = self.split(secret)?;
+        for share in &mut shares {
+            self.check_record_size(share.data.len(), 0, true)?;
+            share.not_before = Some(not_before);
+        }
+        Ok(shares)
+    }
 
-        loop {
-            // Read a chunk from the source
-            let bytes_read = source
-                .read(&mut chunk_read_buffer)
-                .map_err(ShamirError::IoError)?;
-            if bytes_read == 0 {
-                break; // EOF reached
-            }
+    /// Splits a secret like [`split`](Self::split) and describes the split in an
+    /// [`AuditRecord`]
+    ///
+    /// The record holds the scheme parameters, the short fingerprint of every share, the
+    /// set fingerprint and the current time, but never the secret or share data. Store it
+    /// in a governance log separately from the shares, together with its
+    /// [`digest`](AuditRecord::digest) to make later edits evident.
+    ///
+    /// A system clock set before 1970 is recorded as timestamp 0.
+    ///
+    /// # Errors
+    /// Same as [`split`](Self::split).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let (shares, record) = scheme.split_with_audit(b"signing key").unwrap();
+    ///
+    /// assert_eq!(record.total_shares, 5);
+    /// assert_eq!(record.share_fingerprints.len(), shares.len());
+    /// ```
+    pub fn split_with_audit(&mut self, secret: &[u8]) -> Result<(Vec<Share>, AuditRecord)> {
+        let shares = self.split(secret)?;
+        let timestamp = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0, |elapsed| elapsed.as_secs());
+        let record = AuditRecord::new(&shares, self.threshold, timestamp);
+        Ok((shares, record))
+    }
 
-            // Process only the bytes that were actually read
-            let chunk = &chunk_read_buffer[..bytes_read];
+    /// Splits a secret and writes every share to its own file in `dir`, all or nothing
+    ///
+    /// The shares are written as `share_<index>` files in the [`FileShareStore`] format,
+    /// so a store opened on `dir` loads them. Each file is created fresh and synced to
+    /// the device; if anything fails, the files written so far are removed, so `dir`
+    /// never holds part of a split.
+    ///
+    /// # Returns
+    /// The paths of the share files, in share index order
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Splitting fails, as for [`split`](Self::split)
+    /// - A `share_<index>` file already exists in `dir`, as `IoError` with kind
+    ///   `AlreadyExists`; existing files are left untouched
+    /// - Any file cannot be created, written, or renamed (`IoError`)
+    ///
+    /// [`FileShareStore`]: crate::FileShareStore
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let paths = scheme.backup_to_dir(b"backed up", dir.path()).unwrap();
+    /// assert_eq!(paths.len(), 3);
+    ///
+    /// let store = FileShareStore::new(dir.path()).unwrap();
+    /// let shares = vec![store.load_share(1).unwrap(), store.load_share(3).unwrap()];
+    /// assert_eq!(ShamirShare::reconstruct(&shares).unwrap(), b"backed up");
+    /// ```
+    pub fn backup_to_dir<P: AsRef<Path>>(&mut self, secret: &[u8], dir: P) -> Result<Vec<PathBuf>> {
+        let shares = self.split(secret)?;
+        write_share_files(dir.as_ref(), &shares)
+    }
 
-            // Prepare data for splitting (with or without integrity check)
-            // Reuse buffer to avoid allocations in the hot loop
-            chunk_with_hash_buffer.clear();
-            if self.config.integrity_check {
-                let hash = Sha256::digest(chunk);
-                chunk_with_hash_buffer.extend_from_slice(&hash);
-            }
-
-            #[cfg(feature = "compress")]
-            if self.config.compression {
-                let compressed_chunk = zstd::encode_all(chunk, 0)
-                    .map_err(|e| ShamirError::CompressionError(e.to_string()))?;
-                chunk_with_hash_buffer.extend_from_slice(&compressed_chunk);
-            } else {
-                chunk_with_hash_buffer.extend_from_slice(chunk);
-            }
-            #[cfg(not(feature = "compress"))]
-            chunk_with_hash_buffer.extend_from_slice(chunk);
-
-            // Split the chunk using the unified split_chunk method
-            let chunk_share_data = self.split_chunk(&chunk_with_hash_buffer)?;
-
-            // Copy the results into our reusable buffers for writing
-            for (share_idx, chunk_data) in chunk_share_data.iter().enumerate() {
-                let share_buffer = &mut share_data_buffers[share_idx];
-                share_buffer.clear();
-                share_buffer.extend_from_slice(chunk_data);
-            }
-
-            // Write each share to its corresponding destination with length prefix
-            for (i, share_data) in share_data_buffers.iter().enumerate() {
-                // Write length prefix (4 bytes, little-endian)
-                let length = share_data.len() as u32;
-                destinations[i]
-                    .write_all(&length.to_le_bytes())
-                    .map_err(ShamirError::IoError)?;
-
-                // Write the share data
-                destinations[i]
-                    .write_all(share_data)
-                    .map_err(ShamirError::IoError)?;
-            }
-        }
-
-        // Zeroize sensitive buffers before returning
-        #[cfg(feature = "zeroize")]
-        {
-            chunk_read_buffer.zeroize();
-            chunk_with_hash_buffer.zeroize();
-            for buffer in &mut share_data_buffers {
-                buffer.zeroize();
+    /// Splits a secret into a recovery kit directory: share files, a manifest, and a
+    /// README with recovery instructions
+    ///
+    /// The share files are written as by [`backup_to_dir`](Self::backup_to_dir), followed
+    /// by a [`FileShareStore`] manifest and a `README.txt` generated from the scheme
+    /// parameters: how many shares are needed, which files they are, and how to
+    /// reconstruct with this library. Every file is created fresh and synced; if any of
+    /// them cannot be written, the files this call created are removed again.
+    ///
+    /// # Security
+    /// The kit holds every share, so anyone with the whole directory can reconstruct the
+    /// secret. It is a staging area: hand each share file to a different holder, together
+    /// with copies of the manifest and README (which hold no secret data), and do not
+    /// keep the complete kit.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Splitting fails, as for [`split`](Self::split)
+    /// - A `share_<index>` file, the manifest or `README.txt` already exists in `dir`
+    ///   (`IoError` with kind `AlreadyExists`); existing files are left untouched
+    /// - Any file cannot be created or written (`IoError`)
+    ///
+    /// [`FileShareStore`]: crate::FileShareStore
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare};
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// scheme.export_recovery_kit(b"family vault", dir.path()).unwrap();
+    ///
+    /// let readme = std::fs::read_to_string(dir.path().join("README.txt")).unwrap();
+    /// assert!(readme.contains("any 3 of the 5 shares"));
+    /// let manifest = FileShareStore::new(dir.path()).unwrap().read_manifest().unwrap();
+    /// assert_eq!(manifest.indices, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn export_recovery_kit<P: AsRef<Path>>(&mut self, secret: &[u8], dir: P) -> Result<()> {
+        let dir = dir.as_ref();
+        let shares = self.split(secret)?;
+        let mut created = write_share_files(dir, &shares)?;
+
+        let file_name = |path: &Path| {
+            path.file_name()
+                .map_or_else(String::new, |name| name.to_string_lossy().into_owned())
+        };
+        let share_files: Vec<String> = created.iter().map(|path| file_name(path)).collect();
+        let result = FileShareStore::new(dir).and_then(|store| {
+            let manifest_path = store.create_manifest(&shares)?;
+            created.push(manifest_path);
+            let readme =
+                recovery_instructions(&shares, &share_files, &file_name(&store.manifest_path()));
+            write_new_file(&dir.join(RECOVERY_KIT_README), readme.as_bytes())?;
+            Ok(())
+        });
+        if result.is_err() {
+            // Best effort: a kit without its manifest or README is not left behind, and
+            // files that existed before this call are never removed
+            for path in &created {
+                let _ = std::fs::remove_file(path);
             }
         }
+        result
+    }
 
-        // Flush all destinations
-        for dest in destinations.iter_mut() {
-            dest.flush().map_err(ShamirError::IoError)?;
+    /// Splits a secret and writes each share to its own directory, one per holder
+    ///
+    /// Share `i + 1` is written to `dirs[i]` as a single `share_<index>` file in the
+    /// [`FileShareStore`] format, modelling per-holder media such as USB sticks in
+    /// air-gapped setups. The directories must already exist, so an unmounted drive is
+    /// reported instead of being written past. Existing files are never overwritten, and
+    /// if any write fails the files already written are removed.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `dirs.len()` differs from the scheme's total share count (`InvalidConfig`)
+    /// - Splitting fails, as for [`split`](Self::split)
+    /// - A share file cannot be created or written, including because it already exists
+    ///   (`ShareWriteFailed`, naming the file)
+    ///
+    /// [`FileShareStore`]: crate::FileShareStore
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let holders: Vec<_> = (0..3).map(|_| tempfile::tempdir().unwrap()).collect();
+    /// let dirs: Vec<_> = holders.iter().map(|d| d.path().to_path_buf()).collect();
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// scheme.distribute_to_dirs(b"air gapped", &dirs).unwrap();
+    ///
+    /// let first = FileShareStore::new(&dirs[0]).unwrap().load_share(1).unwrap();
+    /// let third = FileShareStore::new(&dirs[2]).unwrap().load_share(3).unwrap();
+    /// assert_eq!(ShamirShare::reconstruct(&[first, third]).unwrap(), b"air gapped");
+    /// ```
+    pub fn distribute_to_dirs(&mut self, secret: &[u8], dirs: &[PathBuf]) -> Result<()> {
+        if dirs.len() != self.total_shares as usize {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Expected {} directories, one per share, got {}",
+                self.total_shares,
+                dirs.len()
+            )));
         }
+        let shares = self.split(secret)?;
+        write_shares_to_dirs(dirs, &shares)
+    }
 
-        Ok(())
+    /// Splits a UTF-8 string secret into shares
+    ///
+    /// Equivalent to calling [`split`](Self::split) on the string's UTF-8 bytes. Use
+    /// [`reconstruct_str`](Self::reconstruct_str) to get the string back.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split_str("pässwörd 🔑").unwrap();
+    ///
+    /// let secret = ShamirShare::reconstruct_str(&shares[1..4]).unwrap();
+    /// assert_eq!(secret, "pässwörd 🔑");
+    /// ```
+    pub fn split_str(&mut self, secret: &str) -> Result<Vec<Share>> {
+        self.split(secret.as_bytes())
     }
 
-    /// Reconstructs data from multiple share streams using chunk-based processing
+    /// Splits a numeric secret, such as a key id, as its 8 big-endian bytes
     ///
-    /// This method reads share data from multiple sources in lock-step, reconstructs each chunk
-    /// independently, and writes the original data to the destination. It reads the integrity
-    /// checking flag from the stream header to determine how to process the data.
+    /// Use [`reconstruct_u64`](Self::reconstruct_u64) to get the value back.
     ///
-    /// # Arguments
-    /// * `sources` - Array of readers, one for each share (must have at least `threshold` sources)
-    /// * `destination` - Writer to write reconstructed data to
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
     ///
-    /// # Data Format
-    /// Each source stream must contain chunks in the format written by `split_stream`:
-    /// ```text
-    /// [1-byte integrity flag][1-byte share index][4-byte length][share data for chunk 1][4-byte length][share data for chunk 2]...
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split_u64(0xDEAD_BEEF).unwrap();
+    ///
+    /// assert_eq!(ShamirShare::reconstruct_u64(&shares[1..]).unwrap(), 0xDEAD_BEEF);
     /// ```
+    pub fn split_u64(&mut self, value: u64) -> Result<Vec<Share>> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut bytes = value.to_be_bytes();
+        let shares = self.split(&bytes);
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        shares
+    }
+
+    /// Splits a numeric secret as its 16 big-endian bytes
     ///
-    /// # Security
-    /// - Chunk-level integrity verification (if enabled during splitting)
-    /// - Constant-time reconstruction operations
-    /// - Early failure on integrity check violations
+    /// Use [`reconstruct_u128`](Self::reconstruct_u128) to get the value back.
+    pub fn split_u128(&mut self, value: u128) -> Result<Vec<Share>> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut bytes = value.to_be_bytes();
+        let shares = self.split(&bytes);
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        shares
+    }
+
+    /// Splits a secret so that reconstruction always requires the share at `mandatory_index`
+    ///
+    /// Reconstruction needs the mandatory share plus any `threshold - 1` other shares; no
+    /// number of other shares suffices on its own. This is enforced cryptographically, not
+    /// just by [`reconstruct_requiring`](Self::reconstruct_requiring): the mandatory share
+    /// holds a uniformly random mask, and the other shares split the masked secret with
+    /// threshold `threshold - 1`. Each part alone is independent of the secret.
+    ///
+    /// The shares carry the scheme's `threshold` in their metadata, but can only be
+    /// reconstructed with [`reconstruct_requiring`](Self::reconstruct_requiring); plain
+    /// [`reconstruct`](Self::reconstruct) fails the integrity check (or, without integrity
+    /// checking, returns garbage).
     ///
     /// # Errors
     /// Returns `ShamirError` if:
-    /// - Insufficient sources for reconstruction
-    /// - I/O errors occur during reading or writing
-    /// - Integrity check fails for any chunk
-    /// - Inconsistent chunk sizes across sources
+    /// - `mandatory_index` is not in `1..=total_shares` (`InvalidShareIndex`)
+    /// - The threshold is 1, where the mandatory share would have to be the secret itself
+    ///   (`InvalidThreshold`)
+    /// - The secret cannot be prepared, as for [`split`](Self::split)
     ///
     /// # Example
     /// ```
-    /// use shamir_share::ShamirShare;
-    /// use std::io::Cursor;
+    /// use shamir_share::{ShamirError, ShamirShare};
     ///
-    /// // First, create some share data using split_stream
-    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-    /// let data = b"test data";
-    /// let mut source = Cursor::new(data);
-    /// let mut destinations = vec![Vec::new(); 3];
-    /// let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-    ///     .iter_mut()
-    ///     .map(|d| Cursor::new(std::mem::take(d)))
-    ///     .collect();
-    /// shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
-    /// let share_data: Vec<Vec<u8>> = dest_cursors
-    ///     .into_iter()
-    ///     .map(|cursor| cursor.into_inner())
-    ///     .collect();
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split_with_mandatory(b"master key", 1).unwrap();
     ///
-    /// // Now reconstruct from the first 2 shares
-    /// let mut sources = vec![
-    ///     Cursor::new(share_data[0].clone()),
-    ///     Cursor::new(share_data[1].clone()),
-    /// ];
-    /// let mut destination = Vec::new();
-    /// let mut dest_cursor = Cursor::new(&mut destination);
+    /// // The master share plus any two others
+    /// let secret = ShamirShare::reconstruct_requiring(&shares[..3], 1).unwrap();
+    /// assert_eq!(secret, b"master key");
     ///
-    /// ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
-    /// assert_eq!(&destination, data);
+    /// // Without the master share, even four shares are not enough
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_requiring(&shares[1..], 1),
+    ///     Err(ShamirError::MandatoryShareMissing(1))
+    /// ));
     /// ```
-    pub fn reconstruct_stream<R: Read, W: Write>(
-        sources: &mut [R],
-        destination: &mut W,
-    ) -> Result<()> {
-        if sources.is_empty() {
-            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+    pub fn split_with_mandatory(
+        &mut self,
+        secret: &[u8],
+        mandatory_index: u8,
+    ) -> Result<Vec<Share>> {
+        if mandatory_index == 0 || mandatory_index > self.total_shares {
+            return Err(ShamirError::InvalidShareIndex(mandatory_index));
         }
-
-        // Read integrity check flag and share indices from all sources
-        let mut headers: Vec<[u8; 2]> = Vec::with_capacity(sources.len());
-        for source in sources.iter_mut() {
-            let mut header = [0u8; 2];
-            source
-                .read_exact(&mut header)
-                .map_err(ShamirError::IoError)?;
-            headers.push(header);
+        if self.threshold < 2 {
+            return Err(ShamirError::InvalidThreshold(self.threshold));
         }
 
-        let first_flags = headers[0][0];
-        let integrity_check = (first_flags & 1) != 0;
-        let compression = (first_flags & 2) != 0;
+        let (mut data, compression) = self.prepare_secret(secret)?;
 
-        for header in headers.iter().skip(1) {
-            if header[0] != first_flags {
-                return Err(ShamirError::InvalidConfig(
-                    "Inconsistent flags across sources".to_string(),
-                ));
-            }
+        // The mandatory share's data is a one-time pad over the prepared secret
+        let mut mask = vec![0u8; data.len()];
+        self.rng.fill_bytes(&mut mask);
+        for (byte, &pad) in data.iter_mut().zip(&mask) {
+            *byte ^= pad;
         }
 
-        let share_indices: Vec<u8> = headers.iter().map(|h| h[1]).collect();
-
-        // Pre-allocate buffers to reuse across chunks to avoid allocations in hot loop
-        let mut chunk_lengths_buffer = Vec::with_capacity(sources.len());
-        let mut share_chunk_data_buffers: Vec<Vec<u8>> =
-            (0..sources.len()).map(|_| Vec::new()).collect();
-        let mut reconstructed_chunk_buffer = Vec::new();
-
-        loop {
-            // Read length prefixes from all sources
-            // Reuse buffer to avoid allocations in the hot loop
-            chunk_lengths_buffer.clear();
-            let mut eof_reached = false;
-
-            for source in sources.iter_mut() {
-                let mut length_bytes = [0u8; 4];
-                match source.read_exact(&mut length_bytes) {
-                    Ok(()) => {
-                        let length = u32::from_le_bytes(length_bytes) as usize;
-                        chunk_lengths_buffer.push(length);
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => {
-                        eof_reached = true;
-                        break;
-                    }
-                    Err(e) => return Err(ShamirError::IoError(e)),
-                }
-            }
-
-            if eof_reached {
-                break; // All sources should reach EOF simultaneously
-            }
-
-            // Read share data from all sources
-            // Reuse buffers to avoid allocations in the hot loop
-            for (i, source) in sources.iter_mut().enumerate() {
-                let share_chunk_buffer = &mut share_chunk_data_buffers[i];
-                let chunk_length = chunk_lengths_buffer[i];
-
-                // Resize buffer only if needed to avoid unnecessary allocations
-                if share_chunk_buffer.len() != chunk_length {
-                    share_chunk_buffer.resize(chunk_length, 0);
-                }
-
-                source
-                    .read_exact(share_chunk_buffer)
-                    .map_err(ShamirError::IoError)?;
-            }
-
-            // Create temporary ShareView objects for reconstruction without allocation
-            // This avoids the expensive clone() operation in the hot loop
-            let share_views: Vec<ShareView> = share_chunk_data_buffers
-                .iter()
-                .enumerate()
-                .map(|(i, share_chunk_data)| ShareView {
-                    index: share_indices[i], // Use the actual share index from the stream
-                    data: share_chunk_data,  // Borrow the data instead of cloning
-                })
-                .collect();
-
-            // Reconstruct the chunk using optimized reconstruction with borrowed data
-            let reconstructed_chunk = Self::reconstruct_chunk_from_views(
-                &share_views,
-                &mut reconstructed_chunk_buffer,
-            )?;
-
-            // Handle integrity checking based on the flag we read
-            if integrity_check {
-                // Integrity checking was used - verify hash and extract data
-                if reconstructed_chunk.len() < HASH_SIZE {
-                    return Err(ShamirError::IntegrityCheckFailed);
-                }
-                let (reconstructed_hash, compressed_data) = reconstructed_chunk.split_at(HASH_SIZE);
-
-                let data = {
-                    #[cfg(feature = "compress")]
-                    if compression {
-                        zstd::decode_all(compressed_data)
-                            .map_err(|e| ShamirError::DecompressionError(e.to_string()))?
-                    } else {
-                        compressed_data.to_vec()
-                    }
-                    #[cfg(not(feature = "compress"))]
-                    compressed_data.to_vec()
-                };
-
-                // Verify the integrity of the data using constant-time comparison
-                let calculated_hash = Sha256::digest(&data);
-                let mut hash_match = 0u8;
-                for (a, b) in calculated_hash
-                    .as_slice()
-                    .iter()
-                    .zip(reconstructed_hash.iter())
-                {
-                    hash_match |= a ^ b;
-                }
-                if hash_match != 0 {
-                    return Err(ShamirError::IntegrityCheckFailed);
-                }
+        // The remaining shares split the masked data with one less required share
+        let inner_threshold = self.threshold - 1;
+        let mut coefficients = vec![0u8; data.len() * (inner_threshold as usize - 1)];
+        self.rng.fill_bytes(&mut coefficients);
+        let dealer = Dealer {
+            data,
+            coefficients,
+            current_x: 1,
+            threshold: inner_threshold,
+            total_shares: self.total_shares,
+            integrity_check: self.config.integrity_check,
+            integrity_algorithm: self.config.verifier.algorithm_id(),
+            compression,
+            length_check: self.config.length_check,
+        };
 
-                // Write only the data part (without hash) to destination
-                destination.write_all(&data).map_err(ShamirError::IoError)?;
-            } else {
-                // No integrity checking - write data directly
-                #[cfg(feature = "compress")]
-                if compression {
-                    let data = zstd::decode_all(reconstructed_chunk)
-                        .map_err(|e| ShamirError::DecompressionError(e.to_string()))?;
-                    destination.write_all(&data).map_err(ShamirError::IoError)?;
-                } else {
-                    destination
-                        .write_all(reconstructed_chunk)
-                        .map_err(ShamirError::IoError)?;
+        let shares = dealer
+            .take(self.total_shares as usize)
+            .map(|mut share| {
+                share.threshold = self.threshold;
+                if share.index == mandatory_index {
+                    share.data.copy_from_slice(&mask);
                 }
-                #[cfg(not(feature = "compress"))]
-                destination
-                    .write_all(reconstructed_chunk)
-                    .map_err(ShamirError::IoError)?;
-            };
-        }
+                share
+            })
+            .collect();
 
-        // Zeroize sensitive buffers before returning
         #[cfg(feature = "zeroize")]
-        {
-            for buffer in &mut share_chunk_data_buffers {
-                buffer.zeroize();
-            }
-            reconstructed_chunk_buffer.zeroize();
-        }
-
-        // Flush the destination
-        destination.flush().map_err(ShamirError::IoError)?;
+        mask.zeroize();
 
-        Ok(())
+        Ok(shares)
     }
 
-    /// Helper method to split a single chunk of data into share data
+    /// Splits a secret while holding at most one share's data in memory at a time
     ///
-    /// This is the canonical implementation for splitting data using Shamir's Secret Sharing.
-    /// It takes a data chunk and returns the raw share data for each share.
-    /// Used internally by both `split` and `split_stream` methods to ensure consistency.
+    /// Unlike [`split`](Self::split), which materializes all `total_shares` shares at once,
+    /// this returns an iterator that evaluates the polynomial for each share only when it
+    /// is requested. Peak memory is the prepared secret, the polynomial coefficients, and a
+    /// single share buffer, provided the caller consumes (stores, sends, or drops) each share
+    /// before requesting the next. This is the split-side analogue of the buffer reuse in
+    /// [`reconstruct_stream`](Self::reconstruct_stream).
+    ///
+    /// The yielded shares are identical in format to those returned by `split`.
     ///
     /// # Arguments
-    /// * `data` - The data chunk to split
+    /// * `secret` - Byte slice to protect
     ///
     /// # Returns
-    /// A vector where each element contains the share data for one share.
-    /// The outer vector index corresponds to the share number (0-based).
+    /// An iterator yielding exactly `total_shares` shares, with indices `1..=total_shares`
     ///
-    /// # Security
-    /// - Uses cryptographically secure random coefficients
-    /// - Constant-time polynomial evaluation
-    /// - Parallel processing for performance while maintaining security
-    #[inline]
-    fn split_chunk(&mut self, data: &[u8]) -> Result<Vec<Vec<u8>>> {
-        let secret_len = data.len();
-        let t = self.threshold as usize;
-
-        // Bulk generate random coefficients for all secret bytes (for coefficients 1..t)
-        let mut random_data = vec![0u8; secret_len * (t - 1)];
-        self.rng.fill_bytes(&mut random_data);
-
-        // Precompute x values for each share
-        let x_values: Vec<FiniteField> = (1..=self.total_shares).map(FiniteField::new).collect();
-
-        // Evaluate the polynomial for each share in parallel
-        // For each secret byte at index idx, the polynomial is:
-        // P(x) = data[idx] + random_coef1 * x + random_coef2 * x^2 + ... + random_coef_{t-1} * x^(t-1)
-        let share_data: Vec<Vec<u8>> = x_values
-            .into_par_iter()
-            .map(|x| {
-                (0..secret_len)
-                    .map(|idx| {
-                        let mut acc = FiniteField::new(0);
-                        // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
-                        for j in (0..t).rev() {
-                            let coeff = if j == 0 {
-                                FiniteField::new(data[idx])
-                            } else {
-                                // Random coefficient for x^j is stored in random_data at position idx*(t-1) + (j-1)
-                                FiniteField::new(random_data[idx * (t - 1) + (j - 1)])
-                            };
-                            acc = acc * x + coeff;
-                        }
-                        acc.0
-                    })
-                    .collect()
-            })
-            .collect();
-
-        // Zeroize sensitive random coefficients before returning
-        #[cfg(feature = "zeroize")]
-        random_data.zeroize();
-
-        Ok(share_data)
+    /// # Panics
+    /// Panics in the same cases as [`dealer`](Self::dealer), before any share is yielded.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let mut store = FileShareStore::new(temp_dir.path()).unwrap();
+    ///
+    /// // Each share is written out and dropped before the next one is computed
+    /// for share in scheme.split_bounded(b"large secret") {
+    ///     store.store_share(&share).unwrap();
+    /// }
+    /// assert_eq!(store.list_shares().unwrap().len(), 5);
+    /// ```
+    pub fn split_bounded(&mut self, secret: &[u8]) -> impl ExactSizeIterator<Item = Share> + use<> {
+        let total_shares = self.total_shares as usize;
+        self.dealer(secret).take(total_shares)
     }
 
-    /// Helper method to compute Lagrange coefficients for reconstruction
+    /// Reconstructs the original secret from shares using Lagrange interpolation
     ///
-    /// This is the shared implementation for computing Lagrange interpolation coefficients.
-    /// Used by both reconstruction helper methods to ensure consistency and reduce code duplication.
+    /// This method uses constant-time GF(2^8) arithmetic for reconstruction and performs
+    /// integrity verification using constant-time hash comparison to detect tampering if
+    /// the shares were created with integrity checking enabled.
     ///
     /// # Arguments
-    /// * `shares` - Slice of shares to compute coefficients for
+    /// * `shares` - Slice of shares to use for reconstruction
     ///
     /// # Returns
-    /// Vector of Lagrange coefficients for each share
+    /// The original secret data if reconstruction and integrity verification succeed
     ///
     /// # Security
-    /// - Constant-time coefficient computation
-    /// - Validates share indices for uniqueness
-    #[inline]
-    fn compute_lagrange_coefficients(shares: &[Share]) -> Result<Vec<FiniteField>> {
-        let xs: Vec<FiniteField> = shares
-            .iter()
-            .map(|share| FiniteField::new(share.index))
-            .collect();
-
-        // Check for duplicate share indices
-        for i in 0..xs.len() {
-            for j in (i + 1)..xs.len() {
-                if xs[i] == xs[j] {
-                    return Err(ShamirError::InvalidShareFormat);
-                }
-            }
-        }
-
-        let p = xs.iter().fold(FiniteField::new(1), |acc, &x| acc * x);
-        let lagrange_coefficients: Result<Vec<FiniteField>> = xs
-            .iter()
-            .enumerate()
-            .map(|(i, &x_i)| {
-                // Since x_i != 0, division by x_i is safe via multiplication by its inverse
-                let numerator = p * x_i.inverse().unwrap();
-                let mut denominator = FiniteField::new(1);
-                for (j, &x_j) in xs.iter().enumerate() {
-                    if i != j {
-                        denominator = denominator * (x_i + x_j);
-                    }
-                }
-                denominator
-                    .inverse()
-                    .ok_or(ShamirError::InvalidShareFormat)
-                    .map(|inv| numerator * inv)
-            })
-            .collect();
+    /// - All GF(2^8) operations are constant-time
+    /// - SHA-256 integrity verification with constant-time comparison (if enabled)
+    /// - Parallel processing maintains security guarantees
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - No shares provided
+    /// - Insufficient shares for threshold
+    /// - Shares come from different [`refresh_shares`](Self::refresh_shares) generations
+    ///   (`MixedGenerations`)
+    /// - Shares have inconsistent lengths or integrity check settings
+    /// - Integrity-checked shares are too short to hold the integrity tag (`ShareTooShort`).
+    ///   With threshold 1 the share data is the tagged secret itself, so this is how a
+    ///   truncated single share is reported
+    /// - Invalid share data
+    /// - Integrity check fails (tampering detected)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"data").unwrap();
+    ///
+    /// // Reconstruct with first 3 shares
+    /// let secret = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+    /// assert_eq!(secret, b"data");
+    /// ```
+    pub fn reconstruct(shares: &[Share]) -> Result<Vec<u8>> {
+        Self::reconstruct_impl(shares, None, false, DEFAULT_PARALLEL_THRESHOLD)
+    }
 
-        lagrange_coefficients
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), with hardened
+    /// Lagrange coefficient computation
+    ///
+    /// `reconstruct` stops at the first duplicate share index it finds, and takes a faster
+    /// path for two shares. This variant always uses general interpolation and computes
+    /// every coefficient, examining all index pairs without early exits. Invalid indices
+    /// only set an error flag, which is checked once at the end, so the time taken does
+    /// not depend on where a duplicate occurs.
+    ///
+    /// # Security
+    /// - Share indices are examined in time independent of their values
+    /// - Checks of public metadata (share count, lengths, flags) still return early
+    ///
+    /// # Errors
+    /// Same as [`reconstruct`](Self::reconstruct). Duplicate share indices and index 0
+    /// are both reported as `InvalidShareFormat`.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"data").unwrap();
+    ///
+    /// assert_eq!(ShamirShare::reconstruct_ct(&shares[1..4]).unwrap(), b"data");
+    /// ```
+    pub fn reconstruct_ct(shares: &[Share]) -> Result<Vec<u8>> {
+        Self::reconstruct_impl(shares, None, true, DEFAULT_PARALLEL_THRESHOLD)
     }
 
-    /// Helper method to compute Lagrange coefficients for reconstruction using ShareView
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct) from a mix of owned
+    /// and borrowed shares
     ///
-    /// This version works with borrowed share data to avoid allocations in hot paths.
-    /// Used internally by `reconstruct_stream` for performance optimization.
+    /// Callers holding some shares by value, e.g. just loaded from storage, and others by
+    /// reference can pass them together without cloning the borrowed ones into a
+    /// `Vec<Share>`. Reconstruction reads the shares in place.
     ///
-    /// # Arguments
-    /// * `share_views` - Slice of share views to compute coefficients for
+    /// # Errors
+    /// Same as [`reconstruct`](Self::reconstruct).
     ///
-    /// # Returns
-    /// Vector of Lagrange coefficients for each share
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::borrow::Cow;
     ///
-    /// # Security
-    /// - Constant-time coefficient computation
-    /// - Validates share indices for uniqueness
-    #[inline]
-    fn compute_lagrange_coefficients_from_views(share_views: &[ShareView]) -> Result<Vec<FiniteField>> {
-        let xs: Vec<FiniteField> = share_views
-            .iter()
-            .map(|view| FiniteField::new(view.index))
-            .collect();
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut shares = scheme.split(b"mixed").unwrap();
+    /// let loaded = shares.pop().unwrap();
+    ///
+    /// let mixed = [Cow::Borrowed(&shares[0]), Cow::Owned(loaded)];
+    /// assert_eq!(ShamirShare::reconstruct_cow(&mixed).unwrap(), b"mixed");
+    /// ```
+    pub fn reconstruct_cow(shares: &[Cow<'_, Share>]) -> Result<Vec<u8>> {
+        Self::reconstruct_impl(shares, None, false, DEFAULT_PARALLEL_THRESHOLD)
+    }
 
-        // Check for duplicate share indices
-        for i in 0..xs.len() {
-            for j in (i + 1)..xs.len() {
-                if xs[i] == xs[j] {
-                    return Err(ShamirError::InvalidShareFormat);
-                }
-            }
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), rejecting a result
+    /// that is all zero bytes
+    ///
+    /// A sanity guard for key material, where an all-zero key almost always means a bug
+    /// upstream (an uninitialized buffer was split, or the wrong shares were combined)
+    /// rather than a real key. Plain `reconstruct` accepts zero secrets, so callers whose
+    /// secrets may legitimately be zero are unaffected. An empty secret has no nonzero byte
+    /// and is rejected as well.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    /// - The reconstructed secret is empty or all zeros (`TrivialSecret`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(&[0u8; 32]).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_nonzero(&shares[..2]),
+    ///     Err(ShamirError::TrivialSecret)
+    /// ));
+    /// assert_eq!(ShamirShare::reconstruct(&shares[..2]).unwrap(), [0u8; 32]);
+    /// ```
+    pub fn reconstruct_nonzero(shares: &[Share]) -> Result<Vec<u8>> {
+        let secret = Self::reconstruct(shares)?;
+        // Fold over every byte so the check does not stop at the first nonzero byte
+        if secret.iter().fold(0u8, |acc, &byte| acc | byte) == 0 {
+            return Err(ShamirError::TrivialSecret);
         }
+        Ok(secret)
+    }
 
-        let p = xs.iter().fold(FiniteField::new(1), |acc, &x| acc * x);
-        let lagrange_coefficients: Result<Vec<FiniteField>> = xs
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), dropping shares
+    /// whose index was already seen
+    ///
+    /// For workflows where the same share can legitimately arrive twice, e.g. a holder
+    /// submitting through two channels. Only the first share with each index is kept;
+    /// later ones are ignored without being compared to it. `reconstruct` treats any
+    /// duplicate index as an error.
+    ///
+    /// # Security
+    /// Deduplication hides conflicting shares. If an attacker submits a forged share
+    /// before the genuine one with the same index, the forgery is used and the genuine
+    /// share silently discarded. With integrity checks enabled the forgery makes
+    /// reconstruction fail; without them it yields a wrong secret. Use `reconstruct` where
+    /// duplicates should be investigated rather than absorbed.
+    ///
+    /// # Errors
+    /// Same as [`reconstruct`](Self::reconstruct), applied to the deduplicated shares;
+    /// too few distinct indices are reported as `InsufficientShares`.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"resubmitted").unwrap();
+    ///
+    /// let received = [&shares[0], &shares[1], &shares[1], &shares[3]].map(Clone::clone);
+    /// assert!(ShamirShare::reconstruct(&received).is_err());
+    /// assert_eq!(ShamirShare::reconstruct_dedupe(&received).unwrap(), b"resubmitted");
+    /// ```
+    pub fn reconstruct_dedupe(shares: &[Share]) -> Result<Vec<u8>> {
+        let mut seen = [false; 256];
+        let unique: Vec<&Share> = shares
             .iter()
-            .enumerate()
-            .map(|(i, &x_i)| {
-                // Since x_i != 0, division by x_i is safe via multiplication by its inverse
-                let numerator = p * x_i.inverse().unwrap();
-                let mut denominator = FiniteField::new(1);
-                for (j, &x_j) in xs.iter().enumerate() {
-                    if i != j {
-                        denominator = denominator * (x_i + x_j);
-                    }
-                }
-                denominator
-                    .inverse()
-                    .ok_or(ShamirError::InvalidShareFormat)
-                    .map(|inv| numerator * inv)
-            })
+            .filter(|share| !std::mem::replace(&mut seen[share.index as usize], true))
             .collect();
-
-        lagrange_coefficients
+        Self::reconstruct_impl(&unique, None, false, DEFAULT_PARALLEL_THRESHOLD)
     }
 
-    /// Helper method to reconstruct data from shares using Lagrange interpolation
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), accepting only
+    /// shares refreshed at least to generation `min_generation`
     ///
-    /// This is the canonical implementation for reconstructing data using Shamir's Secret Sharing.
-    /// It takes a slice of shares and returns the reconstructed data.
-    /// Used internally by both `reconstruct` and `reconstruct_stream` methods to ensure consistency.
+    /// After a rotation with [`refresh_shares`](Self::refresh_shares), pass the new
+    /// generation to refuse shares that predate it. `reconstruct` already rejects a mix of
+    /// generations, but a full set of old shares still reconstructs; this rejects it too.
     ///
-    /// # Arguments
-    /// * `shares` - Slice of shares to use for reconstruction
+    /// # Security
+    /// The check is advisory and guards against mistakes, such as restoring from an
+    /// outdated backup. The generation is unauthenticated and a refresh keeps the secret
+    /// unchanged, so anyone holding a full set of old shares can relabel them with the new
+    /// generation and reconstruct. Old shares must be destroyed to be revoked.
     ///
-    /// # Returns
-    /// The reconstructed data (may include integrity hash if shares were created with integrity checking)
+    /// Generations are checked before anything else, so a stale share is reported even
+    /// when the set would fail for another reason.
     ///
-    /// # Security
-    /// - Constant-time Lagrange interpolation
-    /// - Parallel processing for performance while maintaining security
-    /// - Validates share consistency before processing
-    #[inline]
-    fn reconstruct_chunk(shares: &[Share]) -> Result<Vec<u8>> {
-        if shares.is_empty() {
-            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - A share's `refresh_generation` is below `min_generation` (`StaleShare`, naming
+    ///   the first such share)
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let old = scheme.split(b"rotated").unwrap();
+    /// let new = scheme.refresh_shares(&old).unwrap();
+    ///
+    /// assert_eq!(ShamirShare::reconstruct_min_generation(&new[..2], 1).unwrap(), b"rotated");
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_min_generation(&old[..2], 1),
+    ///     Err(ShamirError::StaleShare { index: 1, generation: 0 })
+    /// ));
+    /// ```
+    pub fn reconstruct_min_generation(shares: &[Share], min_generation: u32) -> Result<Vec<u8>> {
+        if let Some(stale) = shares
+            .iter()
+            .find(|share| share.refresh_generation < min_generation)
+        {
+            return Err(ShamirError::StaleShare {
+                index: stale.index,
+                generation: stale.refresh_generation,
+            });
         }
+        Self::reconstruct(shares)
+    }
 
-        let secret_len = shares[0].data.len();
-
-        // Ensure all shares have consistent length
-        if !shares.iter().all(|s| s.data.len() == secret_len) {
-            return Err(ShamirError::InconsistentShareLength);
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), refusing if `now`
+    /// is before the shares' [`not_before`](Share::not_before) time
+    ///
+    /// `now` is in seconds since the Unix epoch. Shares without a not-before time are
+    /// always accepted; if shares disagree, the latest time applies.
+    ///
+    /// # Security
+    /// The not-before time is advisory metadata, not cryptographic enforcement. It lets
+    /// tooling warn about an early attempt, e.g. in a dead man's switch, but `reconstruct`
+    /// ignores it, and holders can rewrite it or pass any `now`. Anyone with `threshold`
+    /// shares can recover the secret at any time.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `now` is before the latest not-before time of the shares (`SharesNotYetValid`)
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split_with_not_before(b"will", 1_900_000_000).unwrap();
+    ///
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_at(&shares[..2], 1_800_000_000),
+    ///     Err(ShamirError::SharesNotYetValid { .. })
+    /// ));
+    /// assert_eq!(ShamirShare::reconstruct_at(&shares[..2], 1_900_000_000).unwrap(), b"will");
+    /// ```
+    pub fn reconstruct_at(shares: &[Share], now: u64) -> Result<Vec<u8>> {
+        if let Some(not_before) = shares.iter().filter_map(|share| share.not_before).max()
+            && now < not_before
+        {
+            return Err(ShamirError::SharesNotYetValid { not_before, now });
         }
-
-        // Use shared Lagrange coefficient computation
-        let lagrange_coefficients = Self::compute_lagrange_coefficients(shares)?;
-
-        // Parallelize reconstruction across bytes for performance
-        let reconstructed_data = (0..secret_len)
-            .into_par_iter()
-            .map(|byte_idx| {
-                shares
-                    .iter()
-                    .zip(&lagrange_coefficients)
-                    .fold(FiniteField::new(0), |acc, (share, &coeff)| {
-                        acc + coeff * FiniteField::new(share.data[byte_idx])
-                    })
-                    .0
-            })
-            .collect::<Vec<u8>>();
-
-        Ok(reconstructed_data)
+        Self::reconstruct(shares)
     }
 
+    /// Reconstructs a secret whose shares were made with a custom [`IntegrityVerifier`]
+    ///
+    /// Behaves like [`reconstruct`](Self::reconstruct), but verifies shares whose
+    /// `integrity_algorithm` matches `verifier.algorithm_id()` with `verifier`. Shares
+    /// using a built-in algorithm are still verified with the built-in implementation.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::UnsupportedIntegrityAlgorithm` if the shares use an algorithm
+    /// that is neither built in nor provided, or any error from `reconstruct`.
+    pub fn reconstruct_with_verifier(
+        shares: &[Share],
+        verifier: &dyn IntegrityVerifier,
+    ) -> Result<Vec<u8>> {
+        Self::reconstruct_impl(shares, Some(verifier), false, DEFAULT_PARALLEL_THRESHOLD)
+    }
 
-    /// Optimized helper method to reconstruct a single chunk from share views with buffer reuse
+    /// Reconstructs the secret like [`reconstruct`](Self::reconstruct), with the
+    /// reconstruction settings of `config`
     ///
-    /// This version uses borrowed share data to eliminate allocations in hot paths.
-    /// Used internally by `reconstruct_stream` for maximum performance optimization.
+    /// `reconstruct` has no configuration and interpolates in parallel from the default
+    /// [`parallel_threshold`](Config::parallel_threshold) of 4096 bytes. This variant uses
+    /// `config.parallel_threshold` instead, and verifies shares whose `integrity_algorithm`
+    /// matches `config.verifier` with it, like
+    /// [`reconstruct_with_verifier`](Self::reconstruct_with_verifier). Pass the `Config`
+    /// the shares were split with to tune both sides alike.
     ///
-    /// # Arguments
-    /// * `share_views` - Slice of share views to use for reconstruction
-    /// * `output_buffer` - Reusable buffer for the reconstructed data
+    /// # Errors
+    /// Same as [`reconstruct_with_verifier`](Self::reconstruct_with_verifier).
     ///
-    /// # Returns
-    /// Slice reference to the reconstructed data in the output buffer
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
     ///
-    /// # Security
-    /// - Constant-time Lagrange interpolation
-    /// - Uses borrowed data to avoid allocations
-    /// - Validates share consistency before processing
-    #[inline]
-    fn reconstruct_chunk_from_views<'a>(
-        share_views: &[ShareView],
-        output_buffer: &'a mut Vec<u8>,
-    ) -> Result<&'a [u8]> {
-        if share_views.is_empty() {
+    /// let config = Config::new().with_parallel_threshold(usize::MAX);
+    /// let mut scheme = ShamirShare::builder(5, 3).with_config(config.clone()).build().unwrap();
+    /// let shares = scheme.split(b"serial").unwrap();
+    ///
+    /// let secret = ShamirShare::reconstruct_with_config(&shares[..3], &config).unwrap();
+    /// assert_eq!(secret, b"serial");
+    /// ```
+    pub fn reconstruct_with_config(shares: &[Share], config: &Config) -> Result<Vec<u8>> {
+        Self::reconstruct_impl(
+            shares,
+            Some(config.verifier.as_ref()),
+            false,
+            config.parallel_threshold,
+        )
+    }
+
+    fn reconstruct_impl<S: Borrow<Share> + Sync>(
+        shares: &[S],
+        custom_verifier: Option<&dyn IntegrityVerifier>,
+        constant_time: bool,
+        parallel_threshold: usize,
+    ) -> Result<Vec<u8>> {
+        Self::reconstruct_tagged(shares, custom_verifier, constant_time, parallel_threshold)
+            .map(|(secret, _)| secret)
+    }
+
+    /// Reconstructs the secret like `reconstruct_impl`, also returning the verified
+    /// integrity tag, or `None` for shares without integrity checking
+    fn reconstruct_tagged<S: Borrow<Share> + Sync>(
+        shares: &[S],
+        custom_verifier: Option<&dyn IntegrityVerifier>,
+        constant_time: bool,
+        parallel_threshold: usize,
+    ) -> Result<(Vec<u8>, Option<Vec<u8>>)> {
+        #[cfg(feature = "timing-harness")]
+        let _timer = crate::timing::OperationTimer::start(crate::timing::Operation::Reconstruct);
+        let verifier = Self::check_reconstruction_input(
+            shares,
+            |i| shares[i].borrow().data.len(),
+            custom_verifier,
+        )?;
+        let first = shares[0].borrow();
+        let length_len = if first.length_check {
+            LENGTH_PREFIX_SIZE
+        } else {
+            0
+        };
+
+        // Use the unified reconstruct_chunk method for the core reconstruction logic
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut reconstructed_data = if constant_time {
+            Self::reconstruct_chunk_ct(shares, parallel_threshold)?
+        } else {
+            Self::reconstruct_chunk(shares, parallel_threshold)?
+        };
+
+        // Handle integrity checking and decompression based on share configuration
+        let result = Self::decode_secret(&reconstructed_data, first, verifier).map(|secret| {
+            // The tag follows the length prefix and has just been verified
+            let tag = verifier.map(|verifier| {
+                reconstructed_data[length_len..length_len + verifier.prefix_len()].to_vec()
+            });
+            (secret.into_owned(), tag)
+        });
+
+        // Zeroize sensitive reconstructed data buffer before returning
+        #[cfg(feature = "zeroize")]
+        reconstructed_data.zeroize();
+
+        result
+    }
+
+    /// Runs the checks every reconstruction makes before interpolating, and returns the
+    /// verifier for the reconstructed secret
+    ///
+    /// `data_len(i)` is the data length of `shares[i]`; zero-copy reconstruction keeps the
+    /// data outside the parsed shares. Checks the share count, that the shares agree as
+    /// for [`ensure_consistent_metadata`](Self::ensure_consistent_metadata), and that the
+    /// shares are long enough to hold the integrity tag.
+    fn check_reconstruction_input<'a, S: Borrow<Share>>(
+        shares: &[S],
+        data_len: impl Fn(usize) -> usize,
+        custom_verifier: Option<&'a dyn IntegrityVerifier>,
+    ) -> Result<Option<&'a dyn IntegrityVerifier>> {
+        let Some(first) = shares.first().map(Borrow::borrow) else {
             return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+
+        let threshold = first.threshold;
+        if shares.len() < threshold as usize {
+            return Err(ShamirError::InsufficientShares {
+                needed: threshold,
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
+            });
         }
 
-        let secret_len = share_views[0].data.len();
+        Self::ensure_consistent_headers(shares, &data_len)?;
+        let verifier = Self::resolve_verifier(first, custom_verifier)?;
 
-        // Ensure all share views have consistent length
-        if !share_views.iter().all(|v| v.data.len() == secret_len) {
-            return Err(ShamirError::InconsistentShareLength);
+        // Shares are as long as the tagged secret, so shorter ones were truncated rather
+        // than tampered with; report that instead of a failed integrity check
+        let length_len = if first.length_check {
+            LENGTH_PREFIX_SIZE
+        } else {
+            0
+        };
+        if let Some(verifier) = verifier
+            && data_len(0) < length_len + verifier.prefix_len()
+        {
+            return Err(ShamirError::ShareTooShort {
+                length: data_len(0),
+                minimum: length_len + verifier.prefix_len(),
+            });
         }
+        Ok(verifier)
+    }
 
-        // Use shared Lagrange coefficient computation for views
-        let lagrange_coefficients = Self::compute_lagrange_coefficients_from_views(share_views)?;
+    /// Checks that all shares agree on the parameters that affect reconstruction
+    ///
+    /// Only effective parameters are compared: the integrity algorithm id is ignored for
+    /// shares without integrity checking, since it is never used for them. Flags are
+    /// checked before the data length, as differing flags usually explain a length
+    /// difference.
+    pub(crate) fn ensure_consistent_metadata<S: Borrow<Share>>(shares: &[S]) -> Result<()> {
+        Self::ensure_consistent_headers(shares, |i| shares[i].borrow().data.len())
+    }
 
-        // Reuse output buffer to avoid allocations in the hot loop
-        output_buffer.clear();
-        output_buffer.reserve(secret_len);
+    /// [`ensure_consistent_metadata`](Self::ensure_consistent_metadata) with the data
+    /// length of `shares[i]` given by `data_len(i)`
+    fn ensure_consistent_headers<S: Borrow<Share>>(
+        shares: &[S],
+        data_len: impl Fn(usize) -> usize,
+    ) -> Result<()> {
+        let Some((first, rest)) = shares.split_first() else {
+            return Ok(());
+        };
+        let first = first.borrow();
+        for (i, share) in rest.iter().map(Borrow::borrow).enumerate() {
+            if share.refresh_generation != first.refresh_generation {
+                return Err(ShamirError::MixedGenerations);
+            }
+            let field = if share.integrity_check != first.integrity_check {
+                "integrity_check"
+            } else if first.integrity_check
+                && share.integrity_algorithm != first.integrity_algorithm
+            {
+                "integrity_algorithm"
+            } else if share.compression != first.compression {
+                "compression"
+            } else if share.length_check != first.length_check {
+                "length_check"
+            } else if data_len(i + 1) != data_len(0) {
+                "data length"
+            } else {
+                continue;
+            };
+            return Err(ShamirError::InconsistentShareMetadata { field });
+        }
+        Ok(())
+    }
 
-        // Reconstruct each byte directly into the output buffer
-        for byte_idx in 0..secret_len {
-            let reconstructed_byte = share_views
-                .iter()
-                .zip(&lagrange_coefficients)
-                .fold(FiniteField::new(0), |acc, (view, &coeff)| {
-                    acc + coeff * FiniteField::new(view.data[byte_idx])
-                })
-                .0;
-            output_buffer.push(reconstructed_byte);
+    /// Checks that all shares come from one scheme: the checks of
+    /// [`ensure_consistent_metadata`](Self::ensure_consistent_metadata), plus equal
+    /// threshold and total share count
+    ///
+    /// Reconstruction only needs the former; collecting and grouping shares before
+    /// reconstruction uses this stricter check.
+    pub(crate) fn ensure_same_scheme<S: Borrow<Share>>(shares: &[S]) -> Result<()> {
+        if let Some(first) = shares.first().map(Borrow::borrow) {
+            for share in shares.iter().map(Borrow::borrow) {
+                let field = if share.threshold != first.threshold {
+                    "threshold"
+                } else if share.total_shares != first.total_shares {
+                    "total_shares"
+                } else {
+                    continue;
+                };
+                return Err(ShamirError::InconsistentShareMetadata { field });
+            }
         }
+        Self::ensure_consistent_metadata(shares)
+    }
 
-        Ok(output_buffer)
+    /// Dispatches to the verifier named by the share's algorithm id
+    ///
+    /// Returns `None` for shares without integrity checking, `custom_verifier` if its id
+    /// matches, and otherwise the built-in verifier for the id.
+    fn resolve_verifier<'a>(
+        share: &Share,
+        custom_verifier: Option<&'a dyn IntegrityVerifier>,
+    ) -> Result<Option<&'a dyn IntegrityVerifier>> {
+        if !share.integrity_check {
+            return Ok(None);
+        }
+        let algorithm = share.integrity_algorithm;
+        let verifier = match custom_verifier {
+            Some(v) if v.algorithm_id() == algorithm => Some(v),
+            _ => builtin_verifier(algorithm),
+        };
+        verifier
+            .map(Some)
+            .ok_or(ShamirError::UnsupportedIntegrityAlgorithm(algorithm))
     }
 
-    /// Generates share deltas by creating and evaluating a random polynomial whose secret is zero
+    /// Reconstructs the secret together with a record of how it was reconstructed
     ///
-    /// This private helper method creates a polynomial of degree `k-1` where the constant term
-    /// (the "secret") is zero, and evaluates it at the given share indices. The resulting
-    /// delta values can be added to existing shares for share refreshing.
+    /// Behaves like [`reconstruct`](Self::reconstruct), additionally returning a
+    /// [`ReconstructionMetadata`] for audit logs. `integrity_verified` is false for shares
+    /// created without integrity checking, whose secret cannot be verified.
     ///
-    /// # Arguments
-    /// * `share_indices` - Slice of x-coordinates (share indices) to evaluate the polynomial at
-    /// * `data_length` - Length of the zero secret data to generate deltas for
+    /// # Errors
+    /// Returns any error from `reconstruct`; no metadata is produced on failure.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"audited").unwrap();
+    ///
+    /// let (secret, metadata) = ShamirShare::reconstruct_with_metadata(&shares[2..]).unwrap();
+    /// assert_eq!(secret, b"audited");
+    /// assert_eq!(metadata.used_indices, vec![3, 4, 5]);
+    /// assert!(metadata.integrity_verified);
+    /// ```
+    pub fn reconstruct_with_metadata(
+        shares: &[Share],
+    ) -> Result<(Vec<u8>, ReconstructionMetadata)> {
+        let secret = Self::reconstruct(shares)?;
+        let metadata = ReconstructionMetadata {
+            used_indices: shares.iter().map(|s| s.index).collect(),
+            threshold: shares[0].threshold,
+            integrity_verified: shares[0].integrity_check,
+            compressed: shares[0].compression,
+        };
+        Ok((secret, metadata))
+    }
+
+    /// Reconstructs the secret together with its reconstructed SHA-256 integrity hash
+    ///
+    /// Behaves like [`reconstruct`](Self::reconstruct), including verifying the hash, and
+    /// additionally returns the hash prefix recovered from the shares so callers can log
+    /// it or cross-check it under their own policy. It equals SHA-256 of the secret.
     ///
     /// # Returns
-    /// Vector where each element contains the delta data for the corresponding share index
+    /// The secret, and its hash, or `None` for shares created without integrity checking
     ///
     /// # Security
-    /// - Uses cryptographically secure random coefficients
-    /// - Constant-time polynomial evaluation using Horner's method
-    /// - Zero constant term ensures deltas maintain the secret sharing property
-    fn generate_zero_polynomial_shares(
-        &mut self,
-        share_indices: &[u8],
-        data_length: usize,
-    ) -> Result<Vec<Vec<u8>>> {
-        let t = self.threshold as usize;
-
-        // Generate random coefficients for all data bytes (for coefficients 1..t)
-        // The constant term (coefficient 0) is always zero for all bytes
-        let mut random_data = vec![0u8; data_length * (t - 1)];
-        self.rng.fill_bytes(&mut random_data);
+    /// The hash lets anyone holding it test guesses of the secret. Treat it like the secret
+    /// when the secret has little entropy.
+    ///
+    /// # Errors
+    /// Returns any error from `reconstruct`.
+    ///
+    /// # Example
+    /// ```
+    /// use sha2::{Digest, Sha256};
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"hashed").unwrap();
+    ///
+    /// let (secret, hash) = ShamirShare::reconstruct_with_hash(&shares[..2]).unwrap();
+    /// assert_eq!(hash, Some(Sha256::digest(&secret).into()));
+    /// ```
+    pub fn reconstruct_with_hash(shares: &[Share]) -> Result<(Vec<u8>, Option<[u8; 32]>)> {
+        let (secret, tag) =
+            Self::reconstruct_tagged(shares, None, false, DEFAULT_PARALLEL_THRESHOLD)?;
+        let hash = tag.map(|tag| {
+            tag.try_into()
+                .expect("the built-in SHA-256 verifier has 32-byte tags")
+        });
+        Ok((secret, hash))
+    }
 
-        // Evaluate the polynomial for each share index
-        let delta_shares: Vec<Vec<u8>> = share_indices
-            .par_iter()
-            .map(|&index| {
-                let x = FiniteField::new(index);
+    /// Reconstructs the secret into `out` one window of `chunk_bytes` bytes at a time
+    ///
+    /// For large secrets held as in-memory shares: instead of allocating the whole secret
+    /// like [`reconstruct`](Self::reconstruct), each window is interpolated from the share
+    /// data and written to `out` before the next one, so the only buffer of secret data is
+    /// the window. Compressed shares are decompressed as they are read, which adds the
+    /// zstd decoder's buffers.
+    ///
+    /// The integrity hash covers the whole secret, so it is verified at the end: the
+    /// reconstructed hash prefix is kept aside, every window is fed into a running SHA-256
+    /// before it is written, and the digest is compared after the last window. The
+    /// recorded length of length-checked shares is compared the same way.
+    ///
+    /// # Returns
+    /// The number of bytes written to `out`
+    ///
+    /// # Security
+    /// `out` has received the data before it is verified. If this returns an error, treat
+    /// everything written as untrusted and discard it.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `chunk_bytes` is 0 (`InvalidConfig`)
+    /// - Writing to `out` fails (`IoError`)
+    /// - Any error from `reconstruct`, including a failed integrity check or length
+    ///   mismatch detected after the data was written
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let secret = vec![3u8; 10_000];
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(&secret).unwrap();
+    ///
+    /// let mut out = Vec::new();
+    /// let written = ShamirShare::reconstruct_chunked_to_writer(&shares[..2], &mut out, 1024).unwrap();
+    /// assert_eq!(written, 10_000);
+    /// assert_eq!(out, secret);
+    /// ```
+    pub fn reconstruct_chunked_to_writer<W: Write>(
+        shares: &[Share],
+        out: &mut W,
+        chunk_bytes: usize,
+    ) -> Result<u64> {
+        if chunk_bytes == 0 {
+            return Err(ShamirError::InvalidConfig(
+                "The window must be at least one byte".to_string(),
+            ));
+        }
+        let verifier = Self::check_reconstruction_input(shares, |i| shares[i].data.len(), None)?;
+        let first = &shares[0];
+
+        // Only SHA-256 can be computed incrementally over the windows
+        let mut hasher = match verifier {
+            None => None,
+            Some(_) if first.integrity_algorithm == SHA256_ALGORITHM_ID => Some(Sha256::new()),
+            Some(_) => {
+                return Err(ShamirError::UnsupportedIntegrityAlgorithm(
+                    first.integrity_algorithm,
+                ));
+            }
+        };
+        let length_len = if first.length_check {
+            LENGTH_PREFIX_SIZE
+        } else {
+            0
+        };
+        let mut length = [0u8; LENGTH_PREFIX_SIZE];
+        let mut tag = [0u8; HASH_SIZE];
+        let tag_len = if hasher.is_some() { HASH_SIZE } else { 0 };
 
-                // For each byte position, evaluate the polynomial at x
-                (0..data_length)
-                    .map(|byte_idx| {
-                        let mut acc = FiniteField::new(0);
+        let views: Vec<ShareView> = shares
+            .iter()
+            .map(|share| ShareView {
+                index: share.index,
+                data: &share.data,
+            })
+            .collect();
+        let coefficients = Self::compute_lagrange_coefficients_from_views(&views)?;
+        let mut reader = InterpolatingReader {
+            views: &views,
+            coefficients: &coefficients,
+            position: 0,
+        };
+        reader.read_exact(&mut length[..length_len])?;
+        reader.read_exact(&mut tag[..tag_len])?;
 
-                        // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
-                        // P(x) = 0 + random_coef1 * x + random_coef2 * x^2 + ... + random_coef_{t-1} * x^(t-1)
-                        for j in (1..t).rev() {
-                            // Random coefficient for x^j is stored in random_data at position byte_idx*(t-1) + (j-1)
-                            let coeff = FiniteField::new(random_data[byte_idx * (t - 1) + (j - 1)]);
-                            acc = acc * x + coeff;
-                        }
+        let mut window = vec![0u8; chunk_bytes];
+        let result: Result<u64> = (|| {
+            #[cfg(feature = "compress")]
+            let read_error = ShamirError::DecompressionError;
+            #[cfg(feature = "compress")]
+            let mut payload: Box<dyn Read> = if first.compression {
+                let input = std::io::BufReader::with_capacity(chunk_bytes, &mut reader);
+                Box::new(
+                    zstd::stream::read::Decoder::with_buffer(input)
+                        .map_err(ShamirError::DecompressionError)?,
+                )
+            } else {
+                Box::new(&mut reader)
+            };
+            #[cfg(not(feature = "compress"))]
+            let (read_error, payload) = (ShamirError::IoError, &mut reader);
 
-                        // Note: We skip j=0 because the constant term is always FiniteField(0)
-                        // The final multiplication by x handles the last coefficient
-                        acc = acc * x;
+            let mut written = 0u64;
+            loop {
+                let n = payload.read(&mut window).map_err(read_error)?;
+                if n == 0 {
+                    break;
+                }
+                if let Some(hasher) = &mut hasher {
+                    hasher.update(&window[..n]);
+                }
+                out.write_all(&window[..n])?;
+                written += n as u64;
+            }
 
-                        acc.0
-                    })
-                    .collect()
-            })
-            .collect();
+            if let Some(hasher) = hasher
+                && !ct_eq(&hasher.finalize(), &tag)
+            {
+                return Err(ShamirError::IntegrityCheckFailed);
+            }
+            if first.length_check {
+                let expected = u32::from_le_bytes(length) as usize;
+                if written != expected as u64 {
+                    return Err(ShamirError::SecretLengthMismatch {
+                        expected,
+                        actual: written as usize,
+                    });
+                }
+            }
+            Ok(written)
+        })();
 
-        // Zeroize sensitive random coefficients before returning
+        // Zeroize the window, the only buffer of secret data
         #[cfg(feature = "zeroize")]
-        random_data.zeroize();
+        window.zeroize();
 
-        Ok(delta_shares)
+        result
     }
 
-    /// Refreshes existing shares by adding zero-polynomial deltas to invalidate old shares
+    /// Reconstructs the secret, additionally rejecting shares with out-of-range indices
     ///
-    /// This method generates new shares that maintain the same secret but have different share data,
-    /// effectively invalidating the old shares for security purposes. The refreshing process uses
-    /// additive sharing of a zero-secret polynomial, ensuring that the underlying secret remains
-    /// unchanged while the share values are completely refreshed.
+    /// [`reconstruct`](Self::reconstruct) accepts any unique, non-zero index, including
+    /// indices above `total_shares` (which [`dealer`](Self::dealer) can legitimately
+    /// produce). This variant requires every `share.index` to lie in
+    /// `1..=share.total_shares`, catching forged or misparsed indices before interpolation.
     ///
-    /// # Arguments
-    /// * `shares` - Slice of existing shares to refresh (must have at least `threshold` shares)
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareIndex` for the first share whose index is out of
+    /// range, or any error from `reconstruct`.
     ///
-    /// # Returns
-    /// Vector of refreshed shares with the same indices and metadata but new share data
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
     ///
-    /// # Security Purpose
-    /// Share refreshing is a critical security operation that:
-    /// - **Invalidates old shares**: Previous share values become useless after refreshing
-    /// - **Maintains secret integrity**: The underlying secret remains exactly the same
-    /// - **Prevents share accumulation**: Attackers cannot combine old and new shares
-    /// - **Enables proactive security**: Regular refreshing limits exposure windows
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares: Vec<_> = scheme.dealer(b"secret").skip(4).take(3).collect();
     ///
-    /// # Mechanism
-    /// The refreshing process works by:
-    /// 1. Generating a random polynomial with zero constant term (zero-secret)
-    /// 2. Evaluating this polynomial at the same x-coordinates as the input shares
-    /// 3. Adding (XOR) the resulting deltas to the original share data
-    /// 4. Since the polynomial has zero secret, the refreshed shares reconstruct to the same value
+    /// // Shares 5, 6 and 7 are on-curve, but 6 and 7 exceed total_shares
+    /// assert!(ShamirShare::reconstruct(&shares).is_ok());
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_strict(&shares),
+    ///     Err(ShamirError::InvalidShareIndex(6))
+    /// ));
+    /// ```
+    pub fn reconstruct_strict(shares: &[Share]) -> Result<Vec<u8>> {
+        if let Some(share) = shares
+            .iter()
+            .find(|s| s.index == 0 || s.index > s.total_shares)
+        {
+            return Err(ShamirError::InvalidShareIndex(share.index));
+        }
+        Self::reconstruct(shares)
+    }
+
+    /// Reconstructs the secret after trimming all shares to the shortest data length
     ///
-    /// # Input Validation
-    /// This method performs comprehensive validation:
-    /// - Ensures the shares slice is not empty
-    /// - Verifies sufficient shares (at least `threshold` shares required)
-    /// - Checks that all shares have consistent data length
-    /// - Validates that all shares have the same integrity check setting
+    /// Share data always has the same length, so a storage layer that pads blocks with
+    /// trailing bytes makes [`reconstruct`](Self::reconstruct) fail with
+    /// `InconsistentShareMetadata`. This variant cuts every share down to the shortest one
+    /// and reconstructs the trimmed copies as usual, including integrity verification.
+    ///
+    /// # Security
+    /// Trimming cannot tell padding from data. If the shortest share was itself truncated,
+    /// real data is discarded from all shares. Integrity-checked shares then fail with
+    /// `IntegrityCheckFailed`, but shares without integrity checking silently reconstruct
+    /// a truncated or wrong secret. Only use this on shares with integrity checking, or
+    /// where every share is known to hold at least the real data.
     ///
     /// # Errors
-    /// Returns `ShamirError` if:
-    /// - No shares provided (empty slice)
-    /// - Insufficient shares for the threshold requirement
-    /// - Shares have inconsistent data lengths
-    /// - Shares have different integrity check settings
-    /// - Internal polynomial generation fails
+    /// Same as [`reconstruct`](Self::reconstruct), except that differing data lengths are
+    /// not an error.
     ///
     /// # Example
     /// ```
     /// use shamir_share::ShamirShare;
     ///
-    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
-    /// let secret = b"sensitive data";
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut shares = scheme.split(b"padded").unwrap();
+    /// shares[0].data.extend_from_slice(&[0; 16]);
     ///
-    /// // Create initial shares
-    /// let original_shares = scheme.split(secret).unwrap();
+    /// assert!(ShamirShare::reconstruct(&shares[..2]).is_err());
+    /// assert_eq!(ShamirShare::reconstruct_trimmed(&shares[..2]).unwrap(), b"padded");
+    /// ```
+    pub fn reconstruct_trimmed(shares: &[Share]) -> Result<Vec<u8>> {
+        let Some(min_len) = shares.iter().map(|s| s.data.len()).min() else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+
+        // Copies are zeroized on drop with the zeroize feature
+        let trimmed: Vec<Share> = shares
+            .iter()
+            .map(|share| {
+                let mut share = share.clone();
+                share.data.truncate(min_len);
+                share
+            })
+            .collect();
+        Self::reconstruct(&trimmed)
+    }
+
+    /// Computes a 16-byte fingerprint identifying exactly the given set of shares
     ///
-    /// // Refresh the shares to invalidate old ones
-    /// let refreshed_shares = scheme.refresh_shares(&original_shares[0..3]).unwrap();
+    /// The fingerprint is a truncated SHA-256 hash over the binary format of every share
+    /// (data and all metadata), with the encodings sorted so the order of `shares` does
+    /// not matter. A dealer can publish the fingerprint of the set that will later be
+    /// presented, and [`reconstruct_verified_set`](Self::reconstruct_verified_set) checks
+    /// it before interpolating.
     ///
-    /// // Both sets reconstruct to the same secret
-    /// let original_secret = ShamirShare::reconstruct(&original_shares[0..3]).unwrap();
-    /// let refreshed_secret = ShamirShare::reconstruct(&refreshed_shares).unwrap();
-    /// assert_eq!(original_secret, refreshed_secret);
+    /// The fingerprint covers the set as a whole: a subset, a superset or a set with one
+    /// share swapped for another split's share all have different fingerprints. It is not
+    /// secret and reveals nothing about the shares beyond equality.
     ///
-    /// // But the share data is completely different
-    /// assert_ne!(original_shares[0].data, refreshed_shares[0].data);
+    /// # Example
     /// ```
+    /// use shamir_share::ShamirShare;
     ///
-    /// # Performance
-    /// - Time complexity: O(n * m * k) where n = number of shares, m = data length, k = threshold
-    /// - Space complexity: O(n * m) for the output shares
-    /// - Uses constant-time operations to prevent side-channel attacks
-    pub fn refresh_shares(&mut self, shares: &[Share]) -> Result<Vec<Share>> {
-        // Input validation: Check if shares slice is empty
-        if shares.is_empty() {
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// let reversed: Vec<_> = shares.iter().rev().cloned().collect();
+    /// assert_eq!(
+    ///     ShamirShare::set_fingerprint(&shares),
+    ///     ShamirShare::set_fingerprint(&reversed)
+    /// );
+    /// assert_ne!(
+    ///     ShamirShare::set_fingerprint(&shares),
+    ///     ShamirShare::set_fingerprint(&shares[..2])
+    /// );
+    /// ```
+    pub fn set_fingerprint(shares: &[Share]) -> [u8; 16] {
+        let mut digests: Vec<[u8; HASH_SIZE]> = shares
+            .iter()
+            .map(|share| {
+                let mut hasher = Sha256::new_with_prefix(SET_FINGERPRINT_DOMAIN);
+                hash_share_identity(&mut hasher, share);
+                hasher.finalize().into()
+            })
+            .collect();
+        digests.sort_unstable();
+
+        let mut hasher = Sha256::new_with_prefix(SET_FINGERPRINT_DOMAIN);
+        hasher.update((digests.len() as u64).to_be_bytes());
+        for digest in &digests {
+            hasher.update(digest);
+        }
+        let digest = hasher.finalize();
+
+        let mut fingerprint = [0u8; 16];
+        fingerprint.copy_from_slice(&digest[..16]);
+        fingerprint
+    }
+
+    /// Reconstructs the secret after checking the shares against a published set
+    /// fingerprint
+    ///
+    /// The fingerprint from [`set_fingerprint`](Self::set_fingerprint) is checked first,
+    /// so a wrong or mixed set is rejected by a single hash pass before any interpolation.
+    /// The shares must be exactly the set the fingerprint was computed for.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The shares' fingerprint differs from `expected_fingerprint` (`ShareSetMismatch`)
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"vault key").unwrap();
+    ///
+    /// // The dealer publishes the fingerprint of the shares held by the recovery team
+    /// let fingerprint = ShamirShare::set_fingerprint(&shares[..3]);
+    ///
+    /// let secret = ShamirShare::reconstruct_verified_set(&shares[..3], &fingerprint).unwrap();
+    /// assert_eq!(secret, b"vault key");
+    /// ```
+    pub fn reconstruct_verified_set(
+        shares: &[Share],
+        expected_fingerprint: &[u8; 16],
+    ) -> Result<Vec<u8>> {
+        if !ct_eq(&Self::set_fingerprint(shares), expected_fingerprint) {
+            return Err(ShamirError::ShareSetMismatch);
+        }
+        Self::reconstruct(shares)
+    }
+
+    /// Reconstructs several share sets of the same secret and checks that they agree
+    ///
+    /// Each set must be internally consistent, but sets may differ in their settings, e.g.
+    /// backups of one secret split with and without compression, or with different
+    /// thresholds. Every set is reconstructed with [`reconstruct`](Self::reconstruct) and
+    /// the results are compared in constant time. This cross-validates backups made under
+    /// different configurations.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `sets` is empty (`InsufficientShares`)
+    /// - Any set fails to reconstruct, with that set's error
+    /// - A set yields a different secret than the first (`SecretMismatch`, carrying the
+    ///   position of the first disagreeing set)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    ///
+    /// let mut plain = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut strict = ShamirShare::builder(5, 4).build().unwrap();
+    /// let a = plain.split(b"backup").unwrap();
+    /// let b = strict.split(b"backup").unwrap();
+    ///
+    /// let secret = ShamirShare::reconstruct_multi(&[&a[..2], &b[1..]]).unwrap();
+    /// assert_eq!(secret, b"backup");
+    /// ```
+    pub fn reconstruct_multi(sets: &[&[Share]]) -> Result<Vec<u8>> {
+        let Some((first, rest)) = sets.split_first() else {
             return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut secret = Self::reconstruct(first)?;
+        let result = rest.iter().enumerate().try_for_each(|(offset, set)| {
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let mut other = Self::reconstruct(set)?;
+            let agrees = ct_eq(&secret, &other);
+
+            #[cfg(feature = "zeroize")]
+            other.zeroize();
+
+            if agrees {
+                Ok(())
+            } else {
+                Err(ShamirError::SecretMismatch {
+                    set_index: offset + 1,
+                })
+            }
+        });
+
+        match result {
+            Ok(()) => Ok(secret),
+            Err(e) => {
+                #[cfg(feature = "zeroize")]
+                secret.zeroize();
+                Err(e)
+            }
         }
+    }
 
-        // Input validation: Check if we have sufficient shares for the threshold
-        if shares.len() < self.threshold as usize {
+    /// Reconstructs a secret split with [`split_with_mandatory`](Self::split_with_mandatory)
+    ///
+    /// Requires the share at `mandatory_index` plus at least `threshold - 1` other shares.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - No share has index `mandatory_index` (`MandatoryShareMissing`)
+    /// - Fewer than `threshold - 1` other shares are given (`InsufficientShares`)
+    /// - Shares are inconsistent, duplicated, or fail the integrity check, as for
+    ///   [`reconstruct`](Self::reconstruct)
+    pub fn reconstruct_requiring(shares: &[Share], mandatory_index: u8) -> Result<Vec<u8>> {
+        let Some(mandatory) = shares.iter().find(|s| s.index == mandatory_index) else {
+            return Err(ShamirError::MandatoryShareMissing(mandatory_index));
+        };
+
+        let others: Vec<ShareView> = shares
+            .iter()
+            .filter(|s| s.index != mandatory_index)
+            .map(|s| ShareView {
+                index: s.index,
+                data: &s.data,
+            })
+            .collect();
+        // Duplicate indices make the interpolation meaningless
+        if others.len() + 1 < shares.len() {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        let threshold = mandatory.threshold;
+        if others.len() + 1 < threshold as usize {
             return Err(ShamirError::InsufficientShares {
-                needed: self.threshold,
-                got: shares.len() as u8,
+                needed: threshold,
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
             });
         }
 
-        // Extract reference values from the first share for consistency checking
-        let data_length = shares[0].data.len();
-        let integrity_check = shares[0].integrity_check;
+        Self::ensure_consistent_metadata(shares)?;
+        let verifier = Self::resolve_verifier(mandatory, None)?;
 
-        // Input validation: Check that all shares have consistent data length and integrity check setting
-        if !shares
-            .iter()
-            .all(|s| s.data.len() == data_length && s.integrity_check == integrity_check)
-        {
-            return Err(ShamirError::InconsistentShareLength);
-        }
+        // Recover the masked data from the other shares, then remove the mask
+        let mut reconstructed_data = Vec::new();
+        let result: Result<Vec<u8>> = (|| {
+            Self::reconstruct_chunk_from_views(&others, &mut reconstructed_data)?;
+            for (byte, &pad) in reconstructed_data.iter_mut().zip(&mandatory.data) {
+                *byte ^= pad;
+            }
+            Self::decode_secret(&reconstructed_data, mandatory, verifier).map(Cow::into_owned)
+        })();
 
-        // Extract the indices from the input shares
-        let indices: Vec<u8> = shares.iter().map(|s| s.index).collect();
+        // Zeroize sensitive reconstructed data buffer before returning
+        #[cfg(feature = "zeroize")]
+        reconstructed_data.zeroize();
 
-        // Generate zero-polynomial deltas using the private helper
-        let deltas = self.generate_zero_polynomial_shares(&indices, data_length)?;
+        result
+    }
 
-        // Create refreshed shares by XORing original data with deltas
-        let refreshed_shares: Vec<Share> = shares
-            .iter()
-            .zip(deltas.iter())
-            .map(|(old_share, delta_data)| {
-                // XOR the original share data with the delta to create new share data
-                let new_data: Vec<u8> = old_share
-                    .data
-                    .iter()
-                    .zip(delta_data.iter())
-                    .map(|(&old_byte, &delta_byte)| old_byte ^ delta_byte)
-                    .collect();
+    /// Reconstructs the secret from shares held in a map keyed by share index
+    ///
+    /// Uses every share in the map, like passing them all to
+    /// [`reconstruct`](Self::reconstruct). Use
+    /// [`reconstruct_map_subset`](Self::reconstruct_map_subset) to pick participants.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareIndex` if a key differs from its share's `index`,
+    /// or any error from `reconstruct`.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::collections::HashMap;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares: HashMap<u8, _> = scheme
+    ///     .split(b"secret")
+    ///     .unwrap()
+    ///     .into_iter()
+    ///     .map(|share| (share.index, share))
+    ///     .collect();
+    ///
+    /// assert_eq!(ShamirShare::reconstruct_map(&shares).unwrap(), b"secret");
+    /// assert_eq!(ShamirShare::reconstruct_map_subset(&shares, &[1, 4, 5]).unwrap(), b"secret");
+    /// ```
+    pub fn reconstruct_map(shares: &HashMap<u8, Share>) -> Result<Vec<u8>> {
+        let mut keys: Vec<u8> = shares.keys().copied().collect();
+        keys.sort_unstable();
+        Self::reconstruct_map_subset(shares, &keys)
+    }
 
-                // Create new share with refreshed data but same metadata
-                Share {
-                    index: old_share.index,
-                    data: new_data,
-                    threshold: old_share.threshold,
-                    total_shares: old_share.total_shares,
-                    integrity_check: old_share.integrity_check,
-                    compression: old_share.compression,
-                }
+    /// Reconstructs the secret from the shares stored under `keys` in a map keyed by index
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidShareIndex` for the first key that is missing from the
+    /// map or differs from its share's `index`, or any error from
+    /// [`reconstruct`](Self::reconstruct).
+    pub fn reconstruct_map_subset(shares: &HashMap<u8, Share>, keys: &[u8]) -> Result<Vec<u8>> {
+        let selected = keys
+            .iter()
+            .map(|&key| match shares.get(&key) {
+                Some(share) if share.index == key => Ok(share.clone()),
+                _ => Err(ShamirError::InvalidShareIndex(key)),
             })
-            .collect();
+            .collect::<Result<Vec<Share>>>()?;
+        Self::reconstruct(&selected)
+    }
 
-        Ok(refreshed_shares)
+    /// Reconstructs a string secret split with [`split_str`](Self::split_str)
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidUtf8` if the reconstructed bytes are not valid UTF-8.
+    /// With integrity checking enabled this cannot happen for shares made by `split_str`,
+    /// since corruption is caught first; without it, corrupted shares can produce invalid
+    /// UTF-8. The invalid bytes are zeroized (with `zeroize`) rather than returned.
+    /// Any error from [`reconstruct`](Self::reconstruct) is also returned.
+    pub fn reconstruct_str(shares: &[Share]) -> Result<String> {
+        let bytes = Self::reconstruct(shares)?;
+        String::from_utf8(bytes).map_err(|e| {
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut, unused_variables))]
+            let mut bytes = e.into_bytes();
+            #[cfg(feature = "zeroize")]
+            bytes.zeroize();
+            ShamirError::InvalidUtf8
+        })
     }
-}
 
-impl Iterator for Dealer {
-    type Item = Share;
+    /// Reconstructs a numeric secret split with [`split_u64`](Self::split_u64)
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidReconstructedLength` if the reconstructed secret is not 8
+    /// bytes long, e.g. because the shares hold some other secret, or any error from
+    /// [`reconstruct`](Self::reconstruct).
+    pub fn reconstruct_u64(shares: &[Share]) -> Result<u64> {
+        Self::reconstruct_array(shares).map(u64::from_be_bytes)
+    }
 
-    /// Generates the next share by evaluating the polynomial at the current x-coordinate
+    /// Reconstructs a numeric secret split with [`split_u128`](Self::split_u128)
     ///
-    /// This method uses constant-time polynomial evaluation with Horner's method to compute
-    /// the share data. It automatically stops after 255 shares (GF(256) field limitation).
+    /// # Errors
+    /// Returns `ShamirError::InvalidReconstructedLength` if the reconstructed secret is not 16
+    /// bytes long, or any error from [`reconstruct`](Self::reconstruct).
+    pub fn reconstruct_u128(shares: &[Share]) -> Result<u128> {
+        Self::reconstruct_array(shares).map(u128::from_be_bytes)
+    }
+
+    /// Reconstructs a secret of exactly `N` bytes
     ///
-    /// # Returns
-    /// - `Some(Share)` - The next share in the sequence
-    /// - `None` - When all possible shares have been generated (x > 255)
+    /// The reconstructed buffer is zeroized (with `zeroize`) whether or not it fits.
+    fn reconstruct_array<const N: usize>(shares: &[Share]) -> Result<[u8; N]> {
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut bytes = Self::reconstruct(shares)?;
+        let array = <[u8; N]>::try_from(bytes.as_slice()).map_err(|_| {
+            ShamirError::InvalidReconstructedLength {
+                expected: N,
+                actual: bytes.len(),
+            }
+        });
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        array
+    }
+
+    /// Reconstructs a secret from share indices and share data stored as separate columns
     ///
-    /// # Security
-    /// - Constant-time polynomial evaluation using Horner's method
-    /// - No data-dependent branching or memory access patterns
-    fn next(&mut self) -> Option<Self::Item> {
-        // Stop after 255 shares (GF(256) field limitation - x=0 is not used)
-        if self.current_x == 0 {
-            return None;
+    /// For column-oriented storage that keeps all share indices in one array and all share
+    /// data in another. `indices[i]` is the index of the share whose data is `data[i]`. The
+    /// columns are borrowed directly, without building intermediate [`Share`] values.
+    ///
+    /// # Arguments
+    /// * `indices` - Share indices (x-coordinates)
+    /// * `data` - Share data, parallel to `indices`
+    /// * `threshold` - Threshold the shares were created with
+    /// * `integrity_check` - Whether the shares were created with integrity checking
+    /// * `compression` - Whether the secret was compressed before splitting
+    ///
+    /// Shares split with [`Config::with_length_check`] are not supported, since the columns
+    /// carry no flag for it; use [`reconstruct`](Self::reconstruct) for them.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `indices` and `data` have different lengths (`InvalidConfig`)
+    /// - Fewer than `threshold` shares are given
+    /// - Share data lengths differ or indices are duplicated
+    /// - Integrity check or decompression fails
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"columnar").unwrap();
+    ///
+    /// let indices: Vec<u8> = shares.iter().map(|s| s.index).collect();
+    /// let data: Vec<&[u8]> = shares.iter().map(|s| s.data.as_slice()).collect();
+    ///
+    /// let secret = ShamirShare::reconstruct_columns(&indices[2..], &data[2..], 3, true, false)
+    ///     .unwrap();
+    /// assert_eq!(secret, b"columnar");
+    /// ```
+    pub fn reconstruct_columns(
+        indices: &[u8],
+        data: &[&[u8]],
+        threshold: u8,
+        integrity_check: bool,
+        compression: bool,
+    ) -> Result<Vec<u8>> {
+        if indices.len() != data.len() {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Got {} share indices but {} data columns",
+                indices.len(),
+                data.len()
+            )));
+        }
+        if indices.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+        if indices.len() < threshold as usize {
+            return Err(ShamirError::InsufficientShares {
+                needed: threshold,
+                got: u8::try_from(indices.len()).unwrap_or(u8::MAX),
+            });
         }
 
-        let x = FiniteField::new(self.current_x);
-        let secret_len = self.data.len();
-        let t = self.threshold as usize;
+        let share_views: Vec<ShareView> = indices
+            .iter()
+            .zip(data)
+            .map(|(&index, &data)| ShareView { index, data })
+            .collect();
 
-        // Evaluate polynomial for each byte at the current x-coordinate
-        let share_data: Vec<u8> = (0..secret_len)
-            .map(|byte_idx| {
-                let mut acc = FiniteField::new(0);
-                // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
-                for j in (0..t).rev() {
-                    let coeff = if j == 0 {
-                        FiniteField::new(self.data[byte_idx])
-                    } else {
-                        // Random coefficient for x^j is stored in coefficients at position byte_idx*(t-1) + (j-1)
-                        FiniteField::new(self.coefficients[byte_idx * (t - 1) + (j - 1)])
-                    };
-                    acc = acc * x + coeff;
-                }
-                acc.0
+        let mut reconstructed_data = Vec::new();
+        let result = Self::reconstruct_chunk_from_views(&share_views, &mut reconstructed_data)
+            .and_then(|chunk| {
+                Self::decode_reconstructed(
+                    chunk,
+                    sha256_verifier_if(integrity_check),
+                    PayloadCompression::from_flag(compression),
+                )
             })
-            .collect();
+            .map(Cow::into_owned);
 
-        let share = Share {
-            index: self.current_x,
-            data: share_data,
-            threshold: self.threshold,
-            total_shares: self.total_shares,
-            integrity_check: self.integrity_check,
-            compression: self.compression,
+        // Zeroize sensitive reconstructed data buffer before returning
+        #[cfg(feature = "zeroize")]
+        reconstructed_data.zeroize();
+
+        result
+    }
+
+    /// Reports which share indices of a distributed set are present, missing, or duplicated
+    ///
+    /// Checks a full share set gathered from all holders for completeness. Only the share
+    /// indices and recorded `total_shares` are read; share data is never touched.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(4, 2).build().unwrap();
+    /// let mut shares = scheme.split(b"secret").unwrap();
+    /// assert!(ShamirShare::audit_shares(&shares).is_complete());
+    ///
+    /// shares[3] = shares[0].clone();
+    /// let audit = ShamirShare::audit_shares(&shares);
+    /// assert_eq!(audit.missing, vec![4]);
+    /// assert_eq!(audit.duplicated, vec![1]);
+    /// ```
+    pub fn audit_shares(shares: &[Share]) -> ShareAudit {
+        let total_shares = shares.iter().map(|s| s.total_shares).max().unwrap_or(0);
+
+        let mut counts = [0usize; 256];
+        for share in shares {
+            counts[share.index as usize] += 1;
+        }
+
+        let mut audit = ShareAudit {
+            total_shares,
+            ..ShareAudit::default()
         };
+        for index in 0..=u8::MAX {
+            let count = counts[index as usize];
+            let expected = (1..=total_shares).contains(&index);
+            match (expected, count) {
+                (true, 0) => audit.missing.push(index),
+                (true, _) => audit.present.push(index),
+                (false, 0) => continue,
+                (false, _) => audit.unexpected.push(index),
+            }
+            if count > 1 {
+                audit.duplicated.push(index);
+            }
+        }
+        audit
+    }
+
+    /// Returns true if the shares are a consistent set with exactly `threshold` shares
+    ///
+    /// For UI hints: at the minimum, every share is needed and removing any one makes
+    /// reconstruction impossible. A set is consistent if its shares agree on threshold,
+    /// total share count, flags and data length, and have distinct indices in
+    /// `1..=total_shares`.
+    ///
+    /// Only share metadata is read; a minimal set may still fail to reconstruct, e.g.
+    /// because a share was tampered with.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// assert!(ShamirShare::is_minimal_set(&shares[..3]));
+    /// assert!(!ShamirShare::is_minimal_set(&shares[..4]));
+    /// ```
+    pub fn is_minimal_set(shares: &[Share]) -> bool {
+        let Some(first) = shares.first() else {
+            return false;
+        };
+        if shares.len() != first.threshold as usize
+            || Self::ensure_consistent_metadata(shares).is_err()
+        {
+            return false;
+        }
+
+        let mut seen = [false; 256];
+        shares.iter().all(|share| {
+            let fresh = !std::mem::replace(&mut seen[share.index as usize], true);
+            fresh
+                && (1..=first.total_shares).contains(&share.index)
+                && share.threshold == first.threshold
+                && share.total_shares == first.total_shares
+        })
+    }
+
+    /// Verifies that a share set lies on a single polynomial, without using integrity hashes
+    ///
+    /// The first `threshold` shares define the polynomial. Every additional share is checked
+    /// against that polynomial's evaluation at its index. This detects tampered or mismatched
+    /// shares even when integrity checking is disabled, but needs more than `threshold`
+    /// shares to have anything to check.
+    ///
+    /// # Arguments
+    /// * `shares` - Share set to verify (at least `threshold` shares)
+    ///
+    /// # Returns
+    /// - `Ok(true)` if every share beyond the first `threshold` lies on the polynomial
+    ///   (trivially true when exactly `threshold` shares are given)
+    /// - `Ok(false)` if any extra share is off-curve
+    ///
+    /// # Security
+    /// - Constant-time GF(2^8) arithmetic
+    /// - Each extra share is compared in full, without exiting at the first differing byte
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Fewer than `threshold` shares are provided
+    /// - Shares come from different refresh generations (`MixedGenerations`) or disagree
+    ///   on their flags or data length (`InconsistentShareMetadata`)
+    /// - Share indices are duplicated
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    ///
+    /// let config = Config::new().with_integrity_check(false);
+    /// let mut scheme = ShamirShare::builder(5, 3).with_config(config).build().unwrap();
+    /// let mut shares = scheme.split(b"no hashes here").unwrap();
+    /// assert!(ShamirShare::verify_consistency(&shares).unwrap());
+    ///
+    /// shares[4].data[0] ^= 0x01;
+    /// assert!(!ShamirShare::verify_consistency(&shares).unwrap());
+    /// ```
+    pub fn verify_consistency(shares: &[Share]) -> Result<bool> {
+        if shares.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+
+        let threshold = shares[0].threshold as usize;
+        if threshold == 0 || shares.len() < threshold {
+            return Err(ShamirError::InsufficientShares {
+                needed: shares[0].threshold.max(1),
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
+            });
+        }
+
+        Self::ensure_consistent_metadata(shares)?;
+
+        // Duplicate indices make the interpolation meaningless
+        Self::compute_lagrange_coefficients(shares)?;
+
+        let (basis, extra) = shares.split_at(threshold);
+        let xs: Vec<FiniteField> = basis.iter().map(|s| FiniteField::new(s.index)).collect();
+
+        let mut consistent = true;
+        for share in extra {
+            let weights = Self::lagrange_weights_at(&xs, FiniteField::new(share.index))?;
+
+            let mut diff = 0u8;
+            for (byte_idx, &actual) in share.data.iter().enumerate() {
+                let expected = basis
+                    .iter()
+                    .zip(&weights)
+                    .fold(FiniteField::new(0), |acc, (s, &w)| {
+                        acc + w * FiniteField::new(s.data[byte_idx])
+                    });
+                diff |= expected.0 ^ actual;
+            }
+            consistent &= diff == 0;
+        }
+
+        Ok(consistent)
+    }
+
+    /// Checks that shares claiming the same scheme lie on one polynomial
+    ///
+    /// With threshold 2 this is collinearity: any two shares define a line, and every
+    /// further share must lie on it. Generally, the first `threshold` shares define the
+    /// polynomial and every other share is re-evaluated against it. Integrity hashes are not
+    /// used, so this works for shares split without integrity checking and is the building
+    /// block for tamper detection.
+    ///
+    /// Unlike [`verify_consistency`](Self::verify_consistency), which trivially accepts
+    /// exactly `threshold` shares, this requires at least one share beyond the threshold, so
+    /// `Ok(true)` always means something was checked.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - At most `threshold` shares are provided (`InsufficientShares`)
+    /// - Shares have inconsistent lengths or duplicated indices
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    ///
+    /// let config = Config::new().with_integrity_check(false);
+    /// let mut scheme = ShamirShare::builder(3, 2).with_config(config).build().unwrap();
+    /// let mut shares = scheme.split(b"collinear").unwrap();
+    /// assert!(ShamirShare::shares_consistent(&shares).unwrap());
+    ///
+    /// shares[2].data[1] ^= 0x04;
+    /// assert!(!ShamirShare::shares_consistent(&shares).unwrap());
+    /// ```
+    pub fn shares_consistent(shares: &[Share]) -> Result<bool> {
+        if let Some(first) = shares.first()
+            && shares.len() <= first.threshold as usize
+        {
+            return Err(ShamirError::InsufficientShares {
+                needed: first.threshold.saturating_add(1),
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
+            });
+        }
+        Self::verify_consistency(shares)
+    }
+
+    /// Reconstructs the secret, explaining integrity failures when extra shares are given
+    ///
+    /// Behaves like [`reconstruct`](Self::reconstruct) on success. When reconstruction
+    /// fails its integrity check (or decompression) and more than `threshold` shares are
+    /// available, the shares are checked byte by byte against the polynomial defined by
+    /// the first `threshold` shares to tell the two usual causes apart:
+    ///
+    /// - Shares from a different split disagree at almost every byte position, giving
+    ///   `MixedShareSets`. Shares from before and after
+    ///   [`refresh_shares`](Self::refresh_shares) are already rejected by `reconstruct`
+    ///   as `MixedGenerations`, unless their generation was lost, e.g. in a format that
+    ///   does not record it
+    /// - Localized corruption (bit rot, a bad sector, a typo in a transcribed share)
+    ///   disagrees at only a few positions, giving `DataCorruption`
+    ///
+    /// A share whose data was overwritten entirely cannot be told apart from a share of
+    /// another split and is reported as `MixedShareSets`. With exactly `threshold` shares
+    /// there is nothing to compare against, and the original error is returned.
+    ///
+    /// # Security
+    /// The diagnosis only runs after a failed reconstruction and branches on share data,
+    /// so unlike the success path it is not constant-time.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Reconstruction fails and more than half of the byte positions are inconsistent
+    ///   (`MixedShareSets`)
+    /// - Reconstruction fails and some, but at most half, of the byte positions are
+    ///   inconsistent (`DataCorruption`)
+    /// - Any other error from `reconstruct`, including `IntegrityCheckFailed` when the
+    ///   failure cannot be diagnosed
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirError, ShamirShare};
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"diagnose me").unwrap();
+    ///
+    /// let mut corrupted = shares.clone();
+    /// corrupted[1].data[5] ^= 0x40;
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_diagnosed(&corrupted),
+    ///     Err(ShamirError::DataCorruption { corrupted_bytes: 1 })
+    /// ));
+    ///
+    /// let other = scheme.split(b"diagnose me").unwrap();
+    /// let mixed = vec![
+    ///     shares[0].clone(),
+    ///     other[1].clone(),
+    ///     shares[2].clone(),
+    ///     shares[3].clone(),
+    /// ];
+    /// assert!(matches!(
+    ///     ShamirShare::reconstruct_diagnosed(&mixed),
+    ///     Err(ShamirError::MixedShareSets)
+    /// ));
+    /// ```
+    pub fn reconstruct_diagnosed(shares: &[Share]) -> Result<Vec<u8>> {
+        match Self::reconstruct(shares) {
+            Err(e) if Self::is_chunk_corruption(&e) => {
+                let threshold = shares[0].threshold as usize;
+                if shares.len() <= threshold {
+                    return Err(e);
+                }
+
+                let data_len = shares[0].data.len();
+                let inconsistent = Self::count_inconsistent_positions(shares, threshold)?;
+                if inconsistent == 0 {
+                    Err(e)
+                } else if inconsistent * 2 > data_len {
+                    Err(ShamirError::MixedShareSets)
+                } else {
+                    Err(ShamirError::DataCorruption {
+                        corrupted_bytes: inconsistent,
+                    })
+                }
+            }
+            result => result,
+        }
+    }
+
+    /// Counts the byte positions where some share beyond the first `threshold` does not lie
+    /// on the polynomial defined by the first `threshold` shares
+    fn count_inconsistent_positions(shares: &[Share], threshold: usize) -> Result<usize> {
+        let shares: Vec<&Share> = shares.iter().collect();
+        let weights = Self::consistency_weights(&shares, threshold)?;
+
+        Ok((0..shares[0].data.len())
+            .filter(|&byte_idx| !Self::is_consistent_at(&shares, &weights, byte_idx))
+            .count())
+    }
+
+    /// Lagrange weights evaluating the polynomial through the first `threshold` shares at
+    /// the index of every later share
+    fn consistency_weights(shares: &[&Share], threshold: usize) -> Result<Vec<Vec<FiniteField>>> {
+        let (basis, extra) = shares.split_at(threshold);
+        let xs: Vec<FiniteField> = basis.iter().map(|s| FiniteField::new(s.index)).collect();
+        extra
+            .iter()
+            .map(|s| Self::lagrange_weights_at(&xs, FiniteField::new(s.index)))
+            .collect()
+    }
+
+    /// Returns true if every share checked by `weights` lies at `byte_idx` on the polynomial
+    /// through the leading shares (see [`consistency_weights`](Self::consistency_weights))
+    fn is_consistent_at(shares: &[&Share], weights: &[Vec<FiniteField>], byte_idx: usize) -> bool {
+        let (basis, extra) = shares.split_at(shares.len() - weights.len());
+        extra.iter().zip(weights).all(|(share, share_weights)| {
+            let expected = basis
+                .iter()
+                .zip(share_weights)
+                .fold(FiniteField::new(0), |acc, (s, &w)| {
+                    acc + w * FiniteField::new(s.data[byte_idx])
+                });
+            expected.0 == share.data[byte_idx]
+        })
+    }
+
+    /// Reconstructs the secret, identifying and excluding a single corrupt share
+    ///
+    /// With more than `threshold` shares there is redundancy to spare. If reconstruction
+    /// from all shares fails the integrity check, each leave-one-out subset is tried; when
+    /// exactly one subset verifies, the share it left out is the corrupt one.
+    ///
+    /// # Returns
+    /// The secret and the index of the excluded share, or `None` if all shares were good
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Reconstruction fails and no single share can be blamed: only `threshold` shares are
+    ///   given, more than one share is corrupt, or the shares have no integrity tag to tell
+    ///   good from bad (the error from [`reconstruct`](Self::reconstruct), typically
+    ///   `IntegrityCheckFailed`)
+    /// - Any other error from `reconstruct`
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let mut shares = scheme.split(b"self-healing").unwrap();
+    /// shares[2].data[0] ^= 0xFF;
+    ///
+    /// let (secret, excluded) = ShamirShare::reconstruct_tolerant(&shares[..4]).unwrap();
+    /// assert_eq!(secret, b"self-healing");
+    /// assert_eq!(excluded, Some(3));
+    /// ```
+    pub fn reconstruct_tolerant(shares: &[Share]) -> Result<(Vec<u8>, Option<u8>)> {
+        let error = match Self::reconstruct(shares) {
+            Ok(secret) => return Ok((secret, None)),
+            Err(e) => e,
+        };
+        if !Self::is_chunk_corruption(&error)
+            || !shares[0].integrity_check
+            || shares.len() <= shares[0].threshold as usize
+        {
+            return Err(error);
+        }
+
+        let mut recovered: Option<(Vec<u8>, u8)> = None;
+        for (position, excluded) in shares.iter().enumerate() {
+            let subset: Vec<Share> = shares
+                .iter()
+                .enumerate()
+                .filter(|&(i, _)| i != position)
+                .map(|(_, share)| share.clone())
+                .collect();
+            #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+            let Ok(mut secret) = Self::reconstruct(&subset) else {
+                continue;
+            };
+
+            if recovered.is_some() {
+                // Several subsets verify, so the corrupt share cannot be singled out
+                #[cfg(feature = "zeroize")]
+                {
+                    secret.zeroize();
+                    if let Some((previous, _)) = recovered.as_mut() {
+                        previous.zeroize();
+                    }
+                }
+                return Err(error);
+            }
+            recovered = Some((secret, excluded.index));
+        }
+
+        recovered
+            .map(|(secret, index)| (secret, Some(index)))
+            .ok_or(error)
+    }
+
+    /// Checks a share set from [`split_with_parity`](Self::split_with_parity) for corruption
+    ///
+    /// Every share beyond the first `threshold` is redundancy, whether it is a data or a
+    /// parity share. With `r = shares.len() - threshold`:
+    ///
+    /// - Detection: any corruption touching at most `r` shares at a byte position is
+    ///   detected, so `r >= 1` catches a single corrupted share
+    /// - Location: a single corrupted share per byte position is identified when `r >= 2`.
+    ///   Different positions may blame different shares, so several corrupted shares are
+    ///   located as long as they do not overlap. Excluding the located shares and
+    ///   reconstructing corrects the secret
+    ///
+    /// # Returns
+    /// The indices of corrupted shares in ascending order; empty if all shares are consistent
+    ///
+    /// # Security
+    /// Locating corruption branches on share data, so this is not constant-time. Run it on
+    /// shares at rest, not where timing is observable.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - At most `threshold` shares are provided (`InsufficientShares`)
+    /// - Shares have inconsistent lengths or flags (`InconsistentShareMetadata`)
+    /// - Share indices are duplicated (`InvalidShareFormat`)
+    /// - Corruption is detected but cannot be located, because `r == 1` or several shares
+    ///   are corrupted at the same byte position (`DataCorruption`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    ///
+    /// let config = Config::new().with_integrity_check(false);
+    /// let mut scheme = ShamirShare::builder(3, 3).with_config(config).build().unwrap();
+    /// let mut shares = scheme.split_with_parity(b"parity protected", 2).unwrap();
+    ///
+    /// shares[1].data[4] ^= 0x10;
+    /// assert_eq!(ShamirShare::verify_with_parity(&shares).unwrap(), vec![2]);
+    /// ```
+    pub fn verify_with_parity(shares: &[Share]) -> Result<Vec<u8>> {
+        if shares.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+        let threshold = shares[0].threshold as usize;
+        if shares.len() <= threshold {
+            return Err(ShamirError::InsufficientShares {
+                needed: shares[0].threshold.saturating_add(1),
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
+            });
+        }
+        Self::ensure_consistent_metadata(shares)?;
+
+        // Duplicate indices make the interpolation meaningless
+        Self::compute_lagrange_coefficients(shares)?;
+
+        let all: Vec<&Share> = shares.iter().collect();
+        let weights = Self::consistency_weights(&all, threshold)?;
+        let inconsistent: Vec<usize> = (0..shares[0].data.len())
+            .filter(|&byte_idx| !Self::is_consistent_at(&all, &weights, byte_idx))
+            .collect();
+        if inconsistent.is_empty() {
+            return Ok(Vec::new());
+        }
+
+        let unlocatable = ShamirError::DataCorruption {
+            corrupted_bytes: inconsistent.len(),
+        };
+        if shares.len() < threshold + 2 {
+            return Err(unlocatable);
+        }
+
+        // Leaving out the corrupted share is the only way to make the rest consistent
+        let subsets = (0..all.len())
+            .map(|excluded| {
+                let subset: Vec<&Share> = all
+                    .iter()
+                    .enumerate()
+                    .filter(|&(i, _)| i != excluded)
+                    .map(|(_, &share)| share)
+                    .collect();
+                let weights = Self::consistency_weights(&subset, threshold)?;
+                Ok((subset, weights))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut corrupted = [false; 256];
+        for byte_idx in inconsistent {
+            let mut culprits = subsets
+                .iter()
+                .enumerate()
+                .filter(|(_, (subset, weights))| Self::is_consistent_at(subset, weights, byte_idx))
+                .map(|(position, _)| shares[position].index);
+            match (culprits.next(), culprits.next()) {
+                (Some(index), None) => corrupted[index as usize] = true,
+                _ => return Err(unlocatable),
+            }
+        }
+
+        Ok((0..=u8::MAX).filter(|&i| corrupted[i as usize]).collect())
+    }
+
+    /// Splits data from a stream into multiple share streams using chunk-based processing
+    ///
+    /// This method reads data from the source in chunks of `config.chunk_size`, splits each chunk
+    /// independently, and writes the resulting shares to the destination writers. Each chunk is
+    /// processed with optional integrity checking and written with length prefixes for reconstruction.
+    ///
+    /// # Arguments
+    /// * `source` - Reader to read data from
+    /// * `destinations` - Array of writers, one for each share (must equal `total_shares`)
+    ///
+    /// # Data Format
+    /// Each destination stream contains a header followed by a sequence of chunks:
+    /// ```text
+    /// [magic "SHSS"][1-byte format version][1-byte flags][1-byte share index][4-byte length][share data for chunk 1][4-byte length][share data for chunk 2]...
+    /// ```
+    /// - The magic and version let readers reject streams in a newer format instead of
+    ///   misparsing them. Streams are written as version 1, or version 2 when they end
+    ///   with a digest trailer
+    /// - The flags indicate whether integrity checking (1) and compression (2) were used,
+    ///   whether lengths are big-endian (8), and whether the stream ends with a digest
+    ///   trailer (16)
+    /// - With [`Config::with_stream_digest`], the chunks are followed by a zero length and
+    ///   a final `[4-byte length][share of the SHA-256 of all chunk plaintext]` record
+    /// - The share index indicates which share this stream represents (1-based)
+    /// - The length represents the size of the following share data and is written in
+    ///   little-endian format, or big-endian with [`Config::with_big_endian_lengths`]
+    ///
+    /// # Security
+    /// - Each chunk is processed independently with its own integrity hash (if enabled)
+    /// - Constant-time operations maintain security guarantees
+    /// - Chunk-level integrity checking allows for early detection of corruption
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Number of destinations doesn't match `total_shares`
+    /// - I/O errors occur during reading or writing
+    /// - Memory allocation fails for large chunks
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{ShamirShare, Config};
+    /// use std::io::Cursor;
+    ///
+    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+    /// let data = b"This is a test message for streaming";
+    /// let mut source = Cursor::new(data);
+    /// let mut destinations = vec![Vec::new(); 3];
+    /// let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+    ///     .iter_mut()
+    ///     .map(|d| Cursor::new(std::mem::take(d)))
+    ///     .collect();
+    ///
+    /// shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+    /// ```
+    pub fn split_stream<R: Read, W: Write>(
+        &mut self,
+        source: &mut R,
+        destinations: &mut [W],
+    ) -> Result<()> {
+        self.split_stream_with_stats(source, destinations)
+            .map(|_| ())
+    }
+
+    /// Splits a stream like [`split_stream`](Self::split_stream) and reports what it wrote
+    ///
+    /// The returned [`SplitStats`] give the bytes read from `source`, the bytes written to
+    /// each destination, and the chunk count. Comparing `bytes_written` with the size of
+    /// each share file confirms that no output was truncated, and the figures help plan
+    /// backup capacity.
+    ///
+    /// # Errors
+    /// Same as [`split_stream`](Self::split_stream).
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::io::Cursor;
+    ///
+    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut destinations = vec![Vec::new(); 3];
+    /// let stats = shamir
+    ///     .split_stream_with_stats(&mut Cursor::new(b"capacity"), &mut destinations)
+    ///     .unwrap();
+    ///
+    /// assert_eq!(stats.bytes_read, 8);
+    /// assert_eq!(stats.chunks, 1);
+    /// assert_eq!(stats.bytes_written[0], destinations[0].len() as u64);
+    /// ```
+    pub fn split_stream_with_stats<R: Read, W: Write>(
+        &mut self,
+        source: &mut R,
+        destinations: &mut [W],
+    ) -> Result<SplitStats> {
+        // Validate that we have the correct number of destinations
+        if destinations.len() != self.total_shares as usize {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Expected {} destinations, got {}",
+                self.total_shares,
+                destinations.len()
+            )));
+        }
+
+        // The stream header has no room for an algorithm id
+        if self.config.integrity_check && self.config.verifier.algorithm_id() != SHA256_ALGORITHM_ID
+        {
+            return Err(ShamirError::InvalidConfig(
+                "Stream splitting only supports the SHA-256 integrity verifier".into(),
+            ));
+        }
+
+        // Write header (magic + version + flags + share index) to all destinations
+        let integrity_flag = if self.config.integrity_check { 1 } else { 0 };
+        // Compressed streams mark each chunk as compressed or stored (flag 4)
+        let compression_flag = if self.config.compression { 2 | 4 } else { 0 };
+        let byte_order_flag = if self.config.big_endian_lengths { 8 } else { 0 };
+        let digest_flag = if self.config.stream_digest { 16 } else { 0 };
+        let flags = integrity_flag | compression_flag | byte_order_flag | digest_flag;
+        // Only streams with the digest trailer need version 2
+        let version = if self.config.stream_digest {
+            STREAM_FORMAT_VERSION
+        } else {
+            STREAM_FORMAT_VERSION_NO_DIGEST
+        };
+
+        for (i, dest) in destinations.iter_mut().enumerate() {
+            dest.write_all(STREAM_MAGIC)
+                .and_then(|()| dest.write_all(&[version, flags, (i + 1) as u8]))
+                .map_err(ShamirError::IoError)?;
+        }
+        let mut stats = SplitStats {
+            bytes_read: 0,
+            bytes_written: vec![STREAM_HEADER_LEN as u64; destinations.len()],
+            chunks: 0,
+        };
+
+        let chunk_size = self.config.chunk_size;
+
+        // Reuse buffers to avoid allocations in the hot loop
+        let mut chunk_read_buffer = vec![0u8; chunk_size];
+        let mut chunk_with_hash_buffer = Vec::with_capacity(if self.config.integrity_check {
+            HASH_SIZE + chunk_size
+        } else {
+            chunk_size
+        });
+
+        // Pre-allocate share data buffers to reuse across chunks
+        let max_chunk_size_with_hash = if self.config.integrity_check {
+            HASH_SIZE + chunk_size
+        } else {
+            chunk_size
+        };
+        let mut share_data_buffers: Vec<Vec<u8>> = (0..self.total_shares)
+            .map(|_| Vec::with_capacity(max_chunk_size_with_hash))
+            .collect();
+        // Running digest of the plaintext, updated chunk by chunk
+        let mut stream_hasher = self.config.stream_digest.then(Sha256::new);
+
+        loop {
+            // Read a chunk from the source
+            let bytes_read = source
+                .read(&mut chunk_read_buffer)
+                .map_err(ShamirError::IoError)?;
+            if bytes_read == 0 {
+                break; // EOF reached
+            }
+
+            // Process only the bytes that were actually read
+            let chunk = &chunk_read_buffer[..bytes_read];
+            stats.bytes_read += bytes_read as u64;
+            stats.chunks += 1;
+            if let Some(hasher) = &mut stream_hasher {
+                hasher.update(chunk);
+            }
+
+            // Prepare data for splitting (with or without integrity check)
+            // Reuse buffer to avoid allocations in the hot loop
+            chunk_with_hash_buffer.clear();
+            if self.config.integrity_check {
+                let hash = Sha256::digest(chunk);
+                chunk_with_hash_buffer.extend_from_slice(&hash);
+            }
+
+            if self.config.compression {
+                // Incompressible chunks are stored as-is behind a marker byte
+                let (payload, compressed) = compress_if_smaller(chunk, true)?;
+                chunk_with_hash_buffer.push(compressed as u8);
+                chunk_with_hash_buffer.extend_from_slice(&payload);
+            } else {
+                chunk_with_hash_buffer.extend_from_slice(chunk);
+            }
+
+            // Split the chunk using the unified split_chunk method
+            let chunk_share_data = self.split_chunk(&chunk_with_hash_buffer)?;
+
+            // Copy the results into our reusable buffers for writing
+            for (share_idx, chunk_data) in chunk_share_data.iter().enumerate() {
+                let share_buffer = &mut share_data_buffers[share_idx];
+                share_buffer.clear();
+                share_buffer.extend_from_slice(chunk_data);
+            }
+
+            // Write each share to its corresponding destination with length prefix
+            for (i, share_data) in share_data_buffers.iter().enumerate() {
+                stats.bytes_written[i] += Self::write_stream_record(
+                    &mut destinations[i],
+                    share_data,
+                    self.config.big_endian_lengths,
+                )?;
+            }
+        }
+
+        // End the chunks with an empty record, then append the split digest
+        if let Some(hasher) = stream_hasher {
+            let digest_shares = self.split_chunk(&hasher.finalize())?;
+            for (i, digest_share) in digest_shares.iter().enumerate() {
+                for record in [&[][..], digest_share] {
+                    stats.bytes_written[i] += Self::write_stream_record(
+                        &mut destinations[i],
+                        record,
+                        self.config.big_endian_lengths,
+                    )?;
+                }
+            }
+        }
+
+        // Zeroize sensitive buffers before returning
+        #[cfg(feature = "zeroize")]
+        {
+            chunk_read_buffer.zeroize();
+            chunk_with_hash_buffer.zeroize();
+            for buffer in &mut share_data_buffers {
+                buffer.zeroize();
+            }
+        }
+
+        // Flush all destinations
+        for dest in destinations.iter_mut() {
+            dest.flush().map_err(ShamirError::IoError)?;
+        }
+
+        Ok(stats)
+    }
+
+    /// Writes a length-prefixed stream record, returning the number of bytes written
+    fn write_stream_record<W: Write>(dest: &mut W, data: &[u8], big_endian: bool) -> Result<u64> {
+        // Write length prefix (4 bytes, in the configured byte order)
+        let length = data.len() as u32;
+        let length_bytes = if big_endian {
+            length.to_be_bytes()
+        } else {
+            length.to_le_bytes()
+        };
+        dest.write_all(&length_bytes)
+            .and_then(|()| dest.write_all(data))
+            .map_err(ShamirError::IoError)?;
+        Ok((length_bytes.len() + data.len()) as u64)
+    }
+
+    /// Reconstructs data from multiple share streams using chunk-based processing
+    ///
+    /// This method reads share data from multiple sources in lock-step, reconstructs each chunk
+    /// independently, and writes the original data to the destination. It reads the integrity
+    /// checking flag from the stream header to determine how to process the data.
+    ///
+    /// # Arguments
+    /// * `sources` - Array of readers, one for each share (must have at least `threshold` sources)
+    ///   in any order: each source's share index is taken from its header, not its position
+    /// * `destination` - Writer to write reconstructed data to
+    ///
+    /// # Data Format
+    /// Each source stream must contain chunks in the format written by `split_stream`:
+    /// ```text
+    /// [1-byte integrity flag][1-byte share index][4-byte length][share data for chunk 1][4-byte length][share data for chunk 2]...
+    /// ```
+    ///
+    /// # Security
+    /// - Chunk-level integrity verification (if enabled during splitting)
+    /// - Whole-stream digest verification (if enabled with [`Config::with_stream_digest`]),
+    ///   detecting reordered or missing chunks once the last chunk has been written
+    /// - Constant-time reconstruction operations
+    /// - Early failure on integrity check violations
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Insufficient sources for reconstruction
+    /// - I/O errors occur during reading or writing
+    /// - Integrity check fails for any chunk, or the whole-stream digest does not match
+    /// - Inconsistent chunk sizes across sources, or a stream flagged with a digest lacks
+    ///   its trailer (`InvalidShareFormat`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::io::Cursor;
+    ///
+    /// // First, create some share data using split_stream
+    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+    /// let data = b"test data";
+    /// let mut source = Cursor::new(data);
+    /// let mut destinations = vec![Vec::new(); 3];
+    /// let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+    ///     .iter_mut()
+    ///     .map(|d| Cursor::new(std::mem::take(d)))
+    ///     .collect();
+    /// shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+    /// let share_data: Vec<Vec<u8>> = dest_cursors
+    ///     .into_iter()
+    ///     .map(|cursor| cursor.into_inner())
+    ///     .collect();
+    ///
+    /// // Now reconstruct from the first 2 shares
+    /// let mut sources = vec![
+    ///     Cursor::new(share_data[0].clone()),
+    ///     Cursor::new(share_data[1].clone()),
+    /// ];
+    /// let mut destination = Vec::new();
+    /// let mut dest_cursor = Cursor::new(&mut destination);
+    ///
+    /// ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+    /// assert_eq!(&destination, data);
+    /// ```
+    pub fn reconstruct_stream<R: Read, W: Write>(
+        sources: &mut [R],
+        destination: &mut W,
+    ) -> Result<()> {
+        // Write only the data part (without hash) to destination
+        Self::for_each_stream_chunk(sources, |data| {
+            destination.write_all(data).map_err(ShamirError::IoError)
+        })?;
+
+        // Flush the destination
+        destination.flush().map_err(ShamirError::IoError)?;
+
+        Ok(())
+    }
+
+    /// Reconstructs share streams and encrypts the output chunk by chunk
+    ///
+    /// Fuses [`reconstruct_stream`](Self::reconstruct_stream) with encryption for pipelines
+    /// that re-encrypt the secret right away: each reconstructed chunk is encrypted and
+    /// written to `destination` before the next one is read, so the plaintext is only ever
+    /// held one chunk at a time in zeroized buffers, never whole and never on disk.
+    ///
+    /// Only available with the `encrypt` feature.
+    ///
+    /// # AEAD framing
+    /// Encryption uses ChaCha20-Poly1305 in the STREAM construction (`EncryptorBE32` of the
+    /// RustCrypto `aead` crate): chunk `i` is sealed under the 12-byte nonce
+    /// `nonce || i as 4-byte BE || last`, where `last` is 1 for the final record and 0
+    /// otherwise. `destination` receives a sequence of records:
+    /// ```text
+    /// [4-byte LE length][ciphertext of chunk 0 + 16-byte tag]
+    /// [4-byte LE length][ciphertext of chunk 1 + 16-byte tag]
+    /// ...
+    /// [4-byte LE length = 16][16-byte tag of an empty final chunk, `last` = 1]
+    /// ```
+    /// The final record is written only after reconstruction succeeded, including the
+    /// whole-stream digest check, so a decryptor that requires it (`DecryptorBE32::decrypt_last`)
+    /// rejects output that was truncated or left incomplete by a failure.
+    ///
+    /// # Security
+    /// - Never reuse a `(key, nonce)` pair: the nonce prefix must be unique per key
+    /// - Ciphertext for chunks reconstructed before a failure may already have been
+    ///   written; it cannot be decrypted as a complete stream without the final record
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - Any error from [`reconstruct_stream`](Self::reconstruct_stream)
+    /// - The stream has more than 2^32 - 1 chunks (`EncryptionFailed`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::io::Cursor;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut outputs = vec![Cursor::new(Vec::new()); 3];
+    /// scheme.split_stream(&mut Cursor::new(b"re-encrypted"), &mut outputs).unwrap();
+    ///
+    /// let (key, nonce) = ([7u8; 32], [1u8; 7]);
+    /// let mut sources: Vec<_> = outputs[1..].iter().map(|c| Cursor::new(c.get_ref())).collect();
+    /// let mut encrypted = Vec::new();
+    /// ShamirShare::reconstruct_stream_encrypted(&mut sources, &mut encrypted, &key, &nonce)
+    ///     .unwrap();
+    /// // One data record plus the final record, each with a 16-byte tag
+    /// assert_eq!(encrypted.len(), (4 + 12 + 16) + (4 + 16));
+    /// ```
+    #[cfg(feature = "encrypt")]
+    pub fn reconstruct_stream_encrypted<R: Read, W: Write>(
+        sources: &mut [R],
+        destination: &mut W,
+        key: &[u8; 32],
+        nonce: &[u8; 7],
+    ) -> Result<()> {
+        use chacha20poly1305::ChaCha20Poly1305;
+        use chacha20poly1305::aead::KeyInit;
+        use chacha20poly1305::aead::stream::EncryptorBE32;
+
+        let write_record = |destination: &mut W, ciphertext: &[u8]| -> Result<()> {
+            destination.write_all(&(ciphertext.len() as u32).to_le_bytes())?;
+            destination.write_all(ciphertext)?;
+            Ok(())
+        };
+
+        let cipher = ChaCha20Poly1305::new(key.into());
+        let mut encryptor = EncryptorBE32::from_aead(cipher, nonce.into());
+        Self::for_each_stream_chunk(sources, |data| {
+            let ciphertext = encryptor
+                .encrypt_next(data)
+                .map_err(|_| ShamirError::EncryptionFailed)?;
+            write_record(destination, &ciphertext)
+        })?;
+
+        let tag = encryptor
+            .encrypt_last(&[][..])
+            .map_err(|_| ShamirError::EncryptionFailed)?;
+        write_record(destination, &tag)?;
+        destination.flush()?;
+        Ok(())
+    }
+
+    /// Reconstructs share streams into every verified, decompressed chunk in order
+    ///
+    /// Reads the stream headers, then hands each chunk's secret data to `write_chunk`.
+    /// Stops at the first error. Internal buffers are zeroized before returning.
+    fn for_each_stream_chunk<R: Read>(
+        sources: &mut [R],
+        mut write_chunk: impl FnMut(&[u8]) -> Result<()>,
+    ) -> Result<()> {
+        let (flags, share_indices) = Self::read_stream_headers(sources)?;
+        Self::for_each_stream_chunk_after_headers(
+            sources,
+            flags,
+            &share_indices,
+            false,
+            |data, _| write_chunk(data),
+        )
+    }
+
+    /// Like [`for_each_stream_chunk`](Self::for_each_stream_chunk), for sources whose
+    /// headers were already read
+    ///
+    /// `write_chunk` also receives the number of bytes the chunk's record took up in every
+    /// source. Set `resumed` if earlier chunks were skipped: the whole-stream digest then
+    /// cannot be checked, and reading stops at the trailer.
+    fn for_each_stream_chunk_after_headers<R: Read>(
+        sources: &mut [R],
+        flags: u8,
+        share_indices: &[u8],
+        resumed: bool,
+        mut write_chunk: impl FnMut(&[u8], u64) -> Result<()>,
+    ) -> Result<()> {
+        let integrity_check = (flags & 1) != 0;
+        let compression = PayloadCompression::from_stream_flags(flags);
+        let big_endian = (flags & 8) != 0;
+        let stream_digest = (flags & 16) != 0;
+        let mut stream_hasher = (stream_digest && !resumed).then(Sha256::new);
+
+        // Pre-allocate buffers to reuse across chunks to avoid allocations in hot loop
+        let mut share_chunk_data_buffers: Vec<Vec<u8>> =
+            (0..sources.len()).map(|_| Vec::new()).collect();
+        let mut reconstructed_chunk_buffer = Vec::new();
+
+        let result: Result<()> = (|| {
+            while Self::read_stream_chunk(sources, &mut share_chunk_data_buffers, big_endian)? {
+                if stream_digest && Self::is_stream_end(&share_chunk_data_buffers)? {
+                    let Some(hasher) = &stream_hasher else {
+                        return Ok(());
+                    };
+                    return Self::verify_stream_digest(
+                        sources,
+                        share_indices,
+                        &mut share_chunk_data_buffers,
+                        &mut reconstructed_chunk_buffer,
+                        big_endian,
+                        &hasher.clone().finalize(),
+                    );
+                }
+                let reconstructed_chunk = Self::reconstruct_stream_chunk(
+                    share_indices,
+                    &share_chunk_data_buffers,
+                    &mut reconstructed_chunk_buffer,
+                )?;
+                let data = Self::decode_reconstructed(
+                    reconstructed_chunk,
+                    sha256_verifier_if(integrity_check),
+                    compression,
+                )?;
+                if let Some(hasher) = &mut stream_hasher {
+                    hasher.update(&data);
+                }
+                // Chunk records that reconstructed have the same length in every source
+                let record_len = 4 + share_chunk_data_buffers[0].len() as u64;
+                write_chunk(&data, record_len)?;
+            }
+            // The sources ended without the digest trailer their flags promise
+            if stream_digest {
+                return Err(ShamirError::InvalidShareFormat);
+            }
+            Ok(())
+        })();
+
+        // Zeroize sensitive buffers before returning
+        #[cfg(feature = "zeroize")]
+        {
+            for buffer in &mut share_chunk_data_buffers {
+                buffer.zeroize();
+            }
+            reconstructed_chunk_buffer.zeroize();
+        }
+        result
+    }
+
+    /// Reconstructs share streams directly into a memory-mapped file
+    ///
+    /// For multi-gigabyte secrets, this avoids a second copy of the data through a
+    /// buffered writer: the file at `path` is created (or truncated), pre-sized to
+    /// `expected_len` bytes, memory-mapped, and each reconstructed chunk is copied straight
+    /// into the mapping.
+    ///
+    /// `expected_len` is a sizing hint. If the reconstructed data is longer, the file is
+    /// grown and remapped as needed; if it is shorter, the file is truncated to the actual
+    /// length. The actual length is returned.
+    ///
+    /// Only available with the `memmap` feature.
+    ///
+    /// # Security
+    /// - Internal chunk buffers are zeroized, as for [`reconstruct_stream`](Self::reconstruct_stream)
+    /// - If reconstruction fails, the data written so far is overwritten with zeros and the
+    ///   file is truncated to zero length, so no partial secret is left on disk
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if the file cannot be created, sized, or mapped (`IoError`), or
+    /// for any error from `reconstruct_stream`.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::io::Cursor;
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let data = vec![7u8; 4096];
+    /// let mut outputs = vec![Cursor::new(Vec::new()); 3];
+    /// scheme.split_stream(&mut Cursor::new(&data), &mut outputs).unwrap();
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let path = dir.path().join("secret.bin");
+    /// let mut sources: Vec<_> = outputs[..2].iter().map(|c| Cursor::new(c.get_ref())).collect();
+    /// let len = ShamirShare::reconstruct_stream_to_mmap(&mut sources, &path, 4096).unwrap();
+    ///
+    /// assert_eq!(len, 4096);
+    /// assert_eq!(std::fs::read(&path).unwrap(), data);
+    /// ```
+    #[cfg(feature = "memmap")]
+    pub fn reconstruct_stream_to_mmap<R: Read, P: AsRef<Path>>(
+        sources: &mut [R],
+        path: P,
+        expected_len: u64,
+    ) -> Result<u64> {
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(path)?;
+        let mut output = MmapOutput::new(file, expected_len)?;
+
+        let result = Self::for_each_stream_chunk(sources, |data| output.write(data));
+        match result {
+            Ok(()) => output.finish(),
+            Err(e) => {
+                output.discard();
+                Err(e)
+            }
+        }
+    }
+
+    /// Reconstructs the secret from memory-mapped share files without copying share data
+    ///
+    /// Each mapping, such as a `memmap2::Mmap` of a `share_<index>` file, must hold a share
+    /// in the [`FileShareStore`] format. Any byte buffer works, so callers are free to
+    /// choose their mapping crate. Only the headers are parsed; interpolation reads the
+    /// share data straight from the mappings through [`ShareView`]s, so for large shares
+    /// the only allocation of their size is the reconstructed secret. Headers are
+    /// validated as by [`FileShareStore::load_share`](crate::ShareStore::load_share),
+    /// including the file checksum, and must agree as for
+    /// [`reconstruct`](Self::reconstruct).
+    ///
+    /// The views borrow `mmaps`, so no mapping can be unmapped while it is read.
+    ///
+    /// Only available with the `memmap` feature.
+    ///
+    /// # Security
+    /// Mapping a file is only sound while no other process truncates or modifies it (see
+    /// `memmap2::Mmap::map`). Map share files that are not being written, and keep them
+    /// read-only for the duration of the call.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - A mapping is not a well-formed share (`InvalidShareFormat`) or fails its checksum
+    ///   (`ShareFileCorrupt`)
+    /// - Any error from [`reconstruct`](Self::reconstruct)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let dir = tempfile::tempdir().unwrap();
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// let paths = scheme.backup_to_dir(b"large secret", dir.path()).unwrap();
+    ///
+    /// let mmaps: Vec<_> = paths[1..]
+    ///     .iter()
+    ///     .map(|path| {
+    ///         let file = std::fs::File::open(path).unwrap();
+    ///         // The share files are not modified while mapped
+    ///         unsafe { memmap2::Mmap::map(&file) }.unwrap()
+    ///     })
+    ///     .collect();
+    /// assert_eq!(ShamirShare::reconstruct_from_mmaps(&mmaps).unwrap(), b"large secret");
+    /// ```
+    #[cfg(feature = "memmap")]
+    pub fn reconstruct_from_mmaps(mmaps: &[impl AsRef<[u8]>]) -> Result<Vec<u8>> {
+        let parsed = mmaps
+            .iter()
+            .map(|map| parse_share_slice(map.as_ref()))
+            .collect::<Result<Vec<(Share, &[u8])>>>()?;
+
+        // The parsed shares hold no data, so their data lengths are passed separately
+        let headers: Vec<&Share> = parsed.iter().map(|(share, _)| share).collect();
+        let verifier = Self::check_reconstruction_input(&headers, |i| parsed[i].1.len(), None)?;
+        let first = headers[0];
+
+        let views: Vec<ShareView> = parsed
+            .iter()
+            .map(|(share, data)| ShareView {
+                index: share.index,
+                data,
+            })
+            .collect();
+        #[cfg_attr(not(feature = "zeroize"), allow(unused_mut))]
+        let mut reconstructed_data = Vec::new();
+        let result = Self::reconstruct_chunk_from_views(&views, &mut reconstructed_data)
+            .and_then(|chunk| Self::decode_secret(chunk, first, verifier).map(Cow::into_owned));
+
+        // Zeroize sensitive reconstructed data buffer before returning
+        #[cfg(feature = "zeroize")]
+        reconstructed_data.zeroize();
+
+        result
+    }
+
+    /// Reconstructs share streams for forensic recovery, keeping data from verified chunks
+    ///
+    /// **This is a recovery tool, not a replacement for
+    /// [`reconstruct_stream`](Self::reconstruct_stream).** It writes every chunk that
+    /// reconstructs and verifies successfully, so the destination may end up holding only
+    /// part of the original data. Use it when `reconstruct_stream` has already failed and
+    /// salvaging the intact chunks is worth more than an all-or-nothing result.
+    ///
+    /// A chunk fails if its integrity hash does not match or it cannot be decompressed.
+    /// Without integrity checking, corruption in uncompressed chunks cannot be detected and
+    /// every chunk is written. A whole-stream digest trailer, if present, is not verified.
+    ///
+    /// # Arguments
+    /// * `sources` - Array of readers, one for each share (must have at least `threshold` sources)
+    /// * `destination` - Writer to write recovered data to
+    /// * `stop_on_error` - If true, stop at the first failed chunk after writing all chunks
+    ///   before it. If false, skip failed chunks and keep going
+    ///
+    /// # Returns
+    /// A [`StreamRecoveryReport`] with the number of chunks written and the zero-based
+    /// indices of the chunks that failed (only possible when `stop_on_error` is false)
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - A chunk fails and `stop_on_error` is true: `ChunkIntegrityFailed` with the failing
+    ///   chunk index and the number of chunks written before it
+    /// - Insufficient or inconsistent sources, or I/O errors (as `reconstruct_stream`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirError, ShamirShare};
+    /// use std::io::Cursor;
+    ///
+    /// let config = Config::new().with_chunk_size(32).unwrap();
+    /// let mut shamir = ShamirShare::builder(3, 2).with_config(config).build().unwrap();
+    /// let data = [[b'a'; 32], [b'b'; 32], [b'c'; 32]].concat();
+    /// let mut outputs = vec![Cursor::new(Vec::new()); 3];
+    /// shamir.split_stream(&mut Cursor::new(&data), &mut outputs).unwrap();
+    /// let mut streams: Vec<Vec<u8>> = outputs.into_iter().map(|c| c.into_inner()).collect();
+    ///
+    /// // Corrupt the second chunk (header is 7 bytes, each chunk is 4 + 32 + 32 bytes)
+    /// streams[0][7 + 68 + 4] ^= 0xFF;
+    ///
+    /// let mut sources: Vec<_> = streams[..2].iter().map(Cursor::new).collect();
+    /// let mut recovered = Vec::new();
+    /// let result = ShamirShare::reconstruct_stream_lenient(&mut sources, &mut recovered, true);
+    ///
+    /// assert!(matches!(
+    ///     result,
+    ///     Err(ShamirError::ChunkIntegrityFailed { chunk_index: 1, recovered_chunks: 1 })
+    /// ));
+    /// assert_eq!(recovered, &data[..32]);
+    /// ```
+    pub fn reconstruct_stream_lenient<R: Read, W: Write>(
+        sources: &mut [R],
+        destination: &mut W,
+        stop_on_error: bool,
+    ) -> Result<StreamRecoveryReport> {
+        let (flags, share_indices) = Self::read_stream_headers(sources)?;
+        let integrity_check = (flags & 1) != 0;
+        let compression = PayloadCompression::from_stream_flags(flags);
+        let big_endian = (flags & 8) != 0;
+        let stream_digest = (flags & 16) != 0;
+
+        let mut share_chunk_data_buffers: Vec<Vec<u8>> =
+            (0..sources.len()).map(|_| Vec::new()).collect();
+        let mut reconstructed_chunk_buffer = Vec::new();
+        let mut report = StreamRecoveryReport::default();
+
+        let result: Result<()> = (|| {
+            let mut chunk_index = 0;
+            while Self::read_stream_chunk(sources, &mut share_chunk_data_buffers, big_endian)? {
+                // Skipped chunks would fail the whole-stream digest, so it is not checked
+                if stream_digest && Self::is_stream_end(&share_chunk_data_buffers)? {
+                    break;
+                }
+                let decoded = Self::reconstruct_stream_chunk(
+                    &share_indices,
+                    &share_chunk_data_buffers,
+                    &mut reconstructed_chunk_buffer,
+                )
+                .and_then(|chunk| {
+                    Self::decode_reconstructed(
+                        chunk,
+                        sha256_verifier_if(integrity_check),
+                        compression,
+                    )
+                });
+
+                match decoded {
+                    Ok(data) => {
+                        destination.write_all(&data).map_err(ShamirError::IoError)?;
+                        report.recovered_chunks += 1;
+                    }
+                    Err(e) if Self::is_chunk_corruption(&e) => {
+                        if stop_on_error {
+                            destination.flush().map_err(ShamirError::IoError)?;
+                            return Err(ShamirError::ChunkIntegrityFailed {
+                                chunk_index,
+                                recovered_chunks: report.recovered_chunks,
+                            });
+                        }
+                        report.failed_chunks.push(chunk_index);
+                    }
+                    Err(e) => return Err(e),
+                }
+                chunk_index += 1;
+            }
+            Ok(())
+        })();
+
+        #[cfg(feature = "zeroize")]
+        {
+            for buffer in &mut share_chunk_data_buffers {
+                buffer.zeroize();
+            }
+            reconstructed_chunk_buffer.zeroize();
+        }
+        result?;
+
+        destination.flush().map_err(ShamirError::IoError)?;
+
+        Ok(report)
+    }
+
+    /// Reconstructs share streams, realigning a stream that carries a spurious leading record
+    ///
+    /// [`reconstruct_stream`](Self::reconstruct_stream) reads the sources in lock-step, so a
+    /// share file that picked up an extra record at its start (for example from an
+    /// imperfect copy or a concatenation mistake) pairs every chunk with the wrong one and
+    /// fails. This variant first scans the sequence of chunk length prefixes of every source
+    /// by seeking over the chunk data. If all sequences match, the sources are aligned. If
+    /// exactly one source's sequence matches all others once its first record is dropped,
+    /// that record is skipped. The chunks are then reconstructed as usual.
+    ///
+    /// Sequences are compared in full, so a spurious record is found even when it has the
+    /// same length as the real ones: the stream then has one record more than the others.
+    ///
+    /// # Returns
+    /// The position in `sources` of the stream whose leading record was skipped, or `None`
+    /// if the sources were already aligned
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The length sequences differ in any other way, e.g. several streams are offset or a
+    ///   stream is missing records (`InvalidShareFormat`)
+    /// - Any error reported by `reconstruct_stream`, including seek failures as `IoError`
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ShamirShare};
+    /// use std::io::Cursor;
+    ///
+    /// let config = Config::new().with_chunk_size(32).unwrap();
+    /// let mut shamir = ShamirShare::builder(3, 2).with_config(config).build().unwrap();
+    /// let data = [b'r'; 80];
+    /// let mut outputs = vec![Cursor::new(Vec::new()); 3];
+    /// shamir.split_stream(&mut Cursor::new(&data), &mut outputs).unwrap();
+    /// let mut streams: Vec<Vec<u8>> = outputs.into_iter().map(|c| c.into_inner()).collect();
+    ///
+    /// // Insert a stray 8-byte record after the 7-byte header of the second stream
+    /// let stray = [8u32.to_le_bytes().as_slice(), &[0xEE; 8]].concat();
+    /// streams[1].splice(7..7, stray);
+    ///
+    /// let mut sources: Vec<_> = streams[..2].iter().map(Cursor::new).collect();
+    /// let mut output = Vec::new();
+    /// let skipped = ShamirShare::reconstruct_stream_realign(&mut sources, &mut output).unwrap();
+    /// assert_eq!(skipped, Some(1));
+    /// assert_eq!(output, data);
+    /// ```
+    pub fn reconstruct_stream_realign<R: Read + Seek, W: Write>(
+        sources: &mut [R],
+        destination: &mut W,
+    ) -> Result<Option<usize>> {
+        let (flags, share_indices) = Self::read_stream_headers(sources)?;
+        let big_endian = (flags & 8) != 0;
+
+        let mut starts = Vec::with_capacity(sources.len());
+        let mut sequences = Vec::with_capacity(sources.len());
+        for source in sources.iter_mut() {
+            starts.push(source.stream_position().map_err(ShamirError::IoError)?);
+            sequences.push(Self::scan_stream_lengths(source, big_endian)?);
+        }
+
+        let offset_source = Self::find_offset_stream(&sequences)?;
+        for (position, (source, start)) in sources.iter_mut().zip(starts).enumerate() {
+            let skip = match offset_source {
+                Some(offset) if offset == position => 4 + u64::from(sequences[position][0]),
+                _ => 0,
+            };
+            source
+                .seek(SeekFrom::Start(start + skip))
+                .map_err(ShamirError::IoError)?;
+        }
+
+        Self::for_each_stream_chunk_after_headers(
+            sources,
+            flags,
+            &share_indices,
+            false,
+            |data, _| destination.write_all(data).map_err(ShamirError::IoError),
+        )?;
+        destination.flush().map_err(ShamirError::IoError)?;
+
+        Ok(offset_source)
+    }
+
+    /// Reconstructs share streams, reporting checkpoints from which an interrupted run
+    /// can resume
+    ///
+    /// Behaves like [`reconstruct_stream`](Self::reconstruct_stream), but after every
+    /// `checkpoint_interval` chunks it flushes `destination` and passes a
+    /// [`ReconstructionCheckpoint`] to `on_checkpoint`, which should persist it. An error
+    /// from `on_checkpoint` stops the run with that error.
+    ///
+    /// To resume, reopen the sources at the start of their streams, truncate or position
+    /// the destination at the checkpoint's `bytes_written`, and call again with
+    /// `resume_from`. The headers are read and checked as usual; each source then seeks
+    /// forward over the chunks the checkpoint covers, whose records take the same number
+    /// of bytes in every source, without reconstructing them.
+    ///
+    /// # Arguments
+    /// * `sources` - Readers positioned at the start of their share streams. They must
+    ///   support seeking relative to the current position when resuming
+    /// * `destination` - Writer receiving the chunks after the checkpoint
+    /// * `resume_from` - Checkpoint from an earlier run over the same sources, or `None`
+    /// * `checkpoint_interval` - Number of chunks between checkpoints
+    /// * `on_checkpoint` - Called with each checkpoint once its data has been flushed
+    ///
+    /// # Returns
+    /// A checkpoint covering the whole stream
+    ///
+    /// # Security
+    /// A resumed run cannot check the whole-stream digest of
+    /// [`Config::with_stream_digest`], which covers chunks it does not read; chunk-level
+    /// integrity checks still apply. Reconstruct in one pass where the digest matters.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - `checkpoint_interval` is zero (`InvalidConfig`)
+    /// - Seeking fails (`IoError`)
+    /// - Any error reported by `reconstruct_stream` or `on_checkpoint`
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{Config, ReconstructionCheckpoint, ShamirShare};
+    /// use std::io::Cursor;
+    ///
+    /// let config = Config::new().with_chunk_size(32).unwrap();
+    /// let mut shamir = ShamirShare::builder(3, 2).with_config(config).build().unwrap();
+    /// let data = [b'c'; 100];
+    /// let mut outputs = vec![Cursor::new(Vec::new()); 3];
+    /// shamir.split_stream(&mut Cursor::new(&data), &mut outputs).unwrap();
+    ///
+    /// // The first run stops after saving the checkpoint for two chunks
+    /// let mut saved = None;
+    /// let mut output = Vec::new();
+    /// let mut sources: Vec<_> = outputs[..2].iter().map(|c| Cursor::new(c.get_ref())).collect();
+    /// let interrupted =
+    ///     ShamirShare::reconstruct_stream_checkpointed(&mut sources, &mut output, None, 2, |c| {
+    ///         saved = Some(*c);
+    ///         Err(shamir_share::ShamirError::InvalidConfig("interrupted".into()))
+    ///     });
+    /// assert!(interrupted.is_err());
+    /// let saved: ReconstructionCheckpoint = saved.unwrap();
+    /// assert_eq!(output.len() as u64, saved.bytes_written);
+    ///
+    /// let mut sources: Vec<_> = outputs[..2].iter().map(|c| Cursor::new(c.get_ref())).collect();
+    /// let resume = Some(&saved);
+    /// ShamirShare::reconstruct_stream_checkpointed(&mut sources, &mut output, resume, 2, |_| Ok(()))
+    ///     .unwrap();
+    /// assert_eq!(output, data);
+    /// ```
+    pub fn reconstruct_stream_checkpointed<R: Read + Seek, W: Write>(
+        sources: &mut [R],
+        destination: &mut W,
+        resume_from: Option<&ReconstructionCheckpoint>,
+        checkpoint_interval: u64,
+        mut on_checkpoint: impl FnMut(&ReconstructionCheckpoint) -> Result<()>,
+    ) -> Result<ReconstructionCheckpoint> {
+        if checkpoint_interval == 0 {
+            return Err(ShamirError::InvalidConfig(
+                "Checkpoint interval cannot be zero".into(),
+            ));
+        }
+        let (flags, share_indices) = Self::read_stream_headers(sources)?;
+
+        let mut checkpoint = resume_from.copied().unwrap_or_default();
+        let offset = i64::try_from(checkpoint.source_offset).map_err(|_| {
+            ShamirError::InvalidConfig("Checkpoint offset exceeds the seekable range".into())
+        })?;
+        if offset > 0 {
+            for source in sources.iter_mut() {
+                source
+                    .seek(SeekFrom::Current(offset))
+                    .map_err(ShamirError::IoError)?;
+            }
+        }
+
+        let resumed = checkpoint.chunks > 0;
+        Self::for_each_stream_chunk_after_headers(
+            sources,
+            flags,
+            &share_indices,
+            resumed,
+            |data, record_len| {
+                destination.write_all(data).map_err(ShamirError::IoError)?;
+                checkpoint.chunks += 1;
+                checkpoint.bytes_written += data.len() as u64;
+                checkpoint.source_offset += record_len;
+                if checkpoint.chunks % checkpoint_interval == 0 {
+                    destination.flush().map_err(ShamirError::IoError)?;
+                    on_checkpoint(&checkpoint)?;
+                }
+                Ok(())
+            },
+        )?;
+        destination.flush().map_err(ShamirError::IoError)?;
+
+        Ok(checkpoint)
+    }
+
+    /// Reads the chunk length prefixes of a stream positioned after its header, seeking
+    /// over the chunk data
+    fn scan_stream_lengths<R: Read + Seek>(source: &mut R, big_endian: bool) -> Result<Vec<u32>> {
+        let mut lengths = Vec::new();
+        loop {
+            let mut length_bytes = [0u8; 4];
+            match source.read_exact(&mut length_bytes) {
+                Ok(()) => {}
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(lengths),
+                Err(e) => return Err(ShamirError::IoError(e)),
+            }
+            let length = if big_endian {
+                u32::from_be_bytes(length_bytes)
+            } else {
+                u32::from_le_bytes(length_bytes)
+            };
+            source
+                .seek(SeekFrom::Current(i64::from(length)))
+                .map_err(ShamirError::IoError)?;
+            lengths.push(length);
+        }
+    }
+
+    /// Finds the single stream whose length sequence matches the others after dropping its
+    /// first record, or `None` if all sequences already match
+    fn find_offset_stream(sequences: &[Vec<u32>]) -> Result<Option<usize>> {
+        if sequences.iter().all(|sequence| *sequence == sequences[0]) {
+            return Ok(None);
+        }
+
+        let mut candidates = (0..sequences.len()).filter(|&position| {
+            let Some((_, shifted)) = sequences[position].split_first() else {
+                return false;
+            };
+            sequences
+                .iter()
+                .enumerate()
+                .all(|(other, sequence)| other == position || sequence == shifted)
+        });
+        match (candidates.next(), candidates.next()) {
+            (Some(position), None) => Ok(Some(position)),
+            _ => Err(ShamirError::InvalidShareFormat),
+        }
+    }
+
+    /// Returns true for errors caused by the contents of a single chunk being corrupt
+    fn is_chunk_corruption(error: &ShamirError) -> bool {
+        match error {
+            ShamirError::IntegrityCheckFailed => true,
+            #[cfg(feature = "compress")]
+            ShamirError::DecompressionError(_) => true,
+            _ => false,
+        }
+    }
+
+    /// Reads the stream header from every source stream
+    ///
+    /// # Returns
+    /// The flags byte shared by all sources and the share index of each source
+    fn read_stream_headers<R: Read>(sources: &mut [R]) -> Result<(u8, Vec<u8>)> {
+        if sources.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+
+        // Read integrity check flag and share indices from all sources
+        let mut headers: Vec<[u8; 2]> = Vec::with_capacity(sources.len());
+        for source in sources.iter_mut() {
+            headers.push(Self::read_stream_header(source)?);
+        }
+
+        let first_flags = headers[0][0];
+        for header in headers.iter().skip(1) {
+            if header[0] != first_flags {
+                return Err(ShamirError::InvalidConfig(
+                    "Inconsistent flags across sources".to_string(),
+                ));
+            }
+        }
+
+        Ok((first_flags, headers.iter().map(|h| h[1]).collect()))
+    }
+
+    /// Reads a `[magic][version][flags][share index]` header, returning flags and index
+    ///
+    /// Streams written before the header carried a magic start directly with the flags
+    /// byte, which never matches the first magic byte, and are still accepted.
+    fn read_stream_header<R: Read>(source: &mut R) -> Result<[u8; 2]> {
+        let mut first = [0u8; 1];
+        source
+            .read_exact(&mut first)
+            .map_err(ShamirError::IoError)?;
+        if first[0] != STREAM_MAGIC[0] {
+            let mut index = [0u8; 1];
+            source
+                .read_exact(&mut index)
+                .map_err(ShamirError::IoError)?;
+            return Ok([first[0], index[0]]);
+        }
+
+        let mut header = [0u8; STREAM_HEADER_LEN - 1];
+        source
+            .read_exact(&mut header)
+            .map_err(ShamirError::IoError)?;
+        let (magic_rest, fields) = header.split_at(STREAM_MAGIC.len() - 1);
+        if magic_rest != &STREAM_MAGIC[1..] {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        if fields[0] > STREAM_FORMAT_VERSION {
+            return Err(ShamirError::UnsupportedVersion {
+                found: fields[0],
+                max_supported: STREAM_FORMAT_VERSION,
+            });
+        }
+        Ok([fields[1], fields[2]])
+    }
+
+    /// Reads the next length-prefixed chunk from every source into `buffers`
+    ///
+    /// `big_endian` selects the byte order of the length prefixes, as recorded by flag 8.
+    ///
+    /// # Returns
+    /// `false` once the sources are exhausted, `true` if a chunk was read
+    fn read_stream_chunk<R: Read>(
+        sources: &mut [R],
+        buffers: &mut [Vec<u8>],
+        big_endian: bool,
+    ) -> Result<bool> {
+        for (source, buffer) in sources.iter_mut().zip(buffers.iter_mut()) {
+            let mut length_bytes = [0u8; 4];
+            match source.read_exact(&mut length_bytes) {
+                Ok(()) => {}
+                // All sources should reach EOF simultaneously
+                Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => return Ok(false),
+                Err(e) => return Err(ShamirError::IoError(e)),
+            }
+            let chunk_length = if big_endian {
+                u32::from_be_bytes(length_bytes)
+            } else {
+                u32::from_le_bytes(length_bytes)
+            } as usize;
+
+            // Resize buffer only if needed to avoid unnecessary allocations
+            if buffer.len() != chunk_length {
+                buffer.resize(chunk_length, 0);
+            }
+            source.read_exact(buffer).map_err(ShamirError::IoError)?;
+        }
+        Ok(true)
+    }
+
+    /// Returns true if the records just read are the empty marker ending a stream's chunks
+    ///
+    /// Chunks are never empty, so an empty record can only be the marker, which must then
+    /// be present in every source.
+    fn is_stream_end(records: &[Vec<u8>]) -> Result<bool> {
+        let empty = records.iter().filter(|record| record.is_empty()).count();
+        match empty {
+            0 => Ok(false),
+            n if n == records.len() => Ok(true),
+            _ => Err(ShamirError::InvalidShareFormat),
+        }
+    }
+
+    /// Reads the digest trailer following the end marker and compares it with `digest`
+    ///
+    /// The trailer must be the last record of every source.
+    fn verify_stream_digest<R: Read>(
+        sources: &mut [R],
+        share_indices: &[u8],
+        buffers: &mut [Vec<u8>],
+        output_buffer: &mut Vec<u8>,
+        big_endian: bool,
+        digest: &[u8],
+    ) -> Result<()> {
+        if !Self::read_stream_chunk(sources, buffers, big_endian)? {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        let expected = Self::reconstruct_stream_chunk(share_indices, buffers, output_buffer)?;
+        if !ct_eq(expected, digest) {
+            return Err(ShamirError::IntegrityCheckFailed);
+        }
+        if Self::read_stream_chunk(sources, buffers, big_endian)? {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        Ok(())
+    }
+
+    /// Reconstructs one stream chunk from per-source chunk data without cloning it
+    fn reconstruct_stream_chunk<'a>(
+        share_indices: &[u8],
+        chunk_data: &[Vec<u8>],
+        output_buffer: &'a mut Vec<u8>,
+    ) -> Result<&'a [u8]> {
+        // Create temporary ShareView objects for reconstruction without allocation
+        // This avoids the expensive clone() operation in the hot loop
+        let share_views: Vec<ShareView> = chunk_data
+            .iter()
+            .zip(share_indices)
+            .map(|(data, &index)| ShareView { index, data })
+            .collect();
+
+        Self::reconstruct_chunk_from_views(&share_views, output_buffer)
+    }
+
+    /// Decodes a secret reconstructed from whole shares according to their flags
+    ///
+    /// Strips the length prefix of length-checked shares, verifies and decompresses the
+    /// rest like [`decode_reconstructed`](Self::decode_reconstructed), then checks that the
+    /// secret has the recorded length.
+    fn decode_secret<'a>(
+        reconstructed: &'a [u8],
+        share: &Share,
+        verifier: Option<&dyn IntegrityVerifier>,
+    ) -> Result<Cow<'a, [u8]>> {
+        let compression = PayloadCompression::from_flag(share.compression);
+        if !share.length_check {
+            return Self::decode_reconstructed(reconstructed, verifier, compression);
+        }
+
+        let Some((length, rest)) = reconstructed.split_first_chunk::<LENGTH_PREFIX_SIZE>() else {
+            return Err(ShamirError::InvalidShareFormat);
+        };
+        let expected = u32::from_le_bytes(*length) as usize;
+        let data = Self::decode_reconstructed(rest, verifier, compression)?;
+        if data.len() != expected {
+            let actual = data.len();
+            #[cfg(feature = "zeroize")]
+            if let Cow::Owned(mut decompressed) = data {
+                decompressed.zeroize();
+            }
+            return Err(ShamirError::SecretLengthMismatch { expected, actual });
+        }
+        Ok(data)
+    }
+
+    /// Verifies and decompresses reconstructed data according to the share flags
+    ///
+    /// Shared by whole-secret and per-chunk stream reconstruction: strips and checks the
+    /// integrity prefix if a `verifier` is given, and decompresses the payload as described
+    /// by `compression`.
+    ///
+    /// # Returns
+    /// The original data, borrowed when no decompression is needed
+    fn decode_reconstructed<'a>(
+        reconstructed_chunk: &'a [u8],
+        verifier: Option<&dyn IntegrityVerifier>,
+        compression: PayloadCompression,
+    ) -> Result<Cow<'a, [u8]>> {
+        if let Some(verifier) = verifier {
+            // Integrity checking was used - verify tag and extract data
+            let prefix_len = verifier.prefix_len();
+            if reconstructed_chunk.len() < prefix_len {
+                return Err(ShamirError::IntegrityCheckFailed);
+            }
+            let (reconstructed_tag, payload) = reconstructed_chunk.split_at(prefix_len);
+            let data = compression.decompress(payload)?;
+
+            // Verify the integrity of the data using constant-time comparison
+            if !verifier.verify_ct(&data, reconstructed_tag) {
+                return Err(ShamirError::IntegrityCheckFailed);
+            }
+
+            Ok(data)
+        } else {
+            // No integrity checking - return data directly
+            compression.decompress(reconstructed_chunk)
+        }
+    }
+
+    /// Reconstructs data from share stream files on disk
+    ///
+    /// Convenience wrapper around [`reconstruct_stream`](Self::reconstruct_stream) that opens
+    /// each path as a buffered reader. The files must contain streams written by
+    /// [`split_stream`](Self::split_stream).
+    ///
+    /// # Arguments
+    /// * `paths` - Paths to the share stream files (at least `threshold` of them)
+    /// * `destination` - Writer to write reconstructed data to
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - A file does not exist: `InvalidShareIndex` with the 1-based position of that path
+    ///   in `paths`
+    /// - More than 255 paths are given: `InvalidConfig`
+    /// - A file cannot be opened for any other reason: `IoError`
+    /// - Any error reported by `reconstruct_stream`
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    /// use std::fs::File;
+    /// use std::io::Cursor;
+    ///
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let paths: Vec<_> = (1..=3)
+    ///     .map(|i| temp_dir.path().join(format!("stream_{i}")))
+    ///     .collect();
+    ///
+    /// let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+    /// let mut files: Vec<File> = paths.iter().map(|p| File::create(p).unwrap()).collect();
+    /// shamir.split_stream(&mut Cursor::new(b"data on disk"), &mut files).unwrap();
+    ///
+    /// let mut output = Vec::new();
+    /// ShamirShare::reconstruct_stream_from_paths(&paths[1..], &mut output).unwrap();
+    /// assert_eq!(output, b"data on disk");
+    /// ```
+    pub fn reconstruct_stream_from_paths<P: AsRef<Path>, W: Write>(
+        paths: &[P],
+        destination: &mut W,
+    ) -> Result<()> {
+        if u8::try_from(paths.len()).is_err() {
+            return Err(ShamirError::InvalidConfig(format!(
+                "At most {} share streams can be combined, got {}",
+                u8::MAX,
+                paths.len()
+            )));
+        }
+
+        let mut sources = Vec::with_capacity(paths.len());
+        for (position, path) in (1..=u8::MAX).zip(paths) {
+            let file = File::open(path).map_err(|e| {
+                if e.kind() == std::io::ErrorKind::NotFound {
+                    ShamirError::InvalidShareIndex(position)
+                } else {
+                    ShamirError::IoError(e)
+                }
+            })?;
+            sources.push(BufReader::new(file));
+        }
+
+        Self::reconstruct_stream(&mut sources, destination)
+    }
+
+    /// Helper method to split a single chunk of data into share data
+    ///
+    /// This is the canonical implementation for splitting data using Shamir's Secret Sharing.
+    /// It takes a data chunk and returns the raw share data for each share.
+    /// Used internally by both `split` and `split_stream` methods to ensure consistency.
+    ///
+    /// # Arguments
+    /// * `data` - The data chunk to split
+    ///
+    /// # Returns
+    /// A vector where each element contains the share data for one share.
+    /// The outer vector index corresponds to the share number (0-based).
+    ///
+    /// # Security
+    /// - Uses cryptographically secure random coefficients
+    /// - Constant-time polynomial evaluation
+    /// - Parallel processing for performance while maintaining security
+    #[inline]
+    fn split_chunk(&mut self, data: &[u8]) -> Result<Vec<Vec<u8>>> {
+        let secret_len = data.len();
+        let t = self.threshold as usize;
+
+        // Bulk generate random coefficients for all secret bytes (for coefficients 1..t)
+        let mut random_data = vec![0u8; secret_len * (t - 1)];
+        self.rng.fill_bytes(&mut random_data);
+
+        // Precompute x values for each share
+        let x_values: Vec<FiniteField> = (1..=self.total_shares).map(FiniteField::new).collect();
+
+        // Evaluate the polynomial for each share, in parallel for large chunks
+        // For each secret byte at index idx, the polynomial is:
+        // P(x) = data[idx] + random_coef1 * x + random_coef2 * x^2 + ... + random_coef_{t-1} * x^(t-1)
+        let evaluate = |x: FiniteField| -> Vec<u8> {
+            (0..secret_len)
+                .map(|idx| {
+                    let mut acc = FiniteField::new(0);
+                    // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
+                    for j in (0..t).rev() {
+                        let coeff = if j == 0 {
+                            FiniteField::new(data[idx])
+                        } else {
+                            // Random coefficient for x^j is stored in random_data at position idx*(t-1) + (j-1)
+                            FiniteField::new(random_data[idx * (t - 1) + (j - 1)])
+                        };
+                        acc = acc * x + coeff;
+                    }
+                    acc.0
+                })
+                .collect()
+        };
+        let share_data: Vec<Vec<u8>> = if self.config.parallelizes(secret_len) {
+            x_values.into_par_iter().map(evaluate).collect()
+        } else {
+            x_values.into_iter().map(evaluate).collect()
+        };
+
+        // Zeroize sensitive random coefficients before returning
+        #[cfg(feature = "zeroize")]
+        random_data.zeroize();
+
+        Ok(share_data)
+    }
+
+    /// Helper method to compute Lagrange coefficients for reconstruction
+    ///
+    /// This is the shared implementation for computing Lagrange interpolation coefficients.
+    /// Used by both reconstruction helper methods to ensure consistency and reduce code duplication.
+    ///
+    /// # Arguments
+    /// * `shares` - Slice of shares to compute coefficients for
+    ///
+    /// # Returns
+    /// Vector of Lagrange coefficients for each share
+    ///
+    /// # Security
+    /// - Constant-time coefficient computation
+    /// - Validates share indices for uniqueness
+    #[inline]
+    fn compute_lagrange_coefficients<S: Borrow<Share>>(shares: &[S]) -> Result<Vec<FiniteField>> {
+        let xs: Vec<FiniteField> = shares
+            .iter()
+            .map(|share| FiniteField::new(share.borrow().index))
+            .collect();
+
+        // Check for duplicate share indices
+        for i in 0..xs.len() {
+            for j in (i + 1)..xs.len() {
+                if xs[i] == xs[j] {
+                    return Err(ShamirError::InvalidShareFormat);
+                }
+            }
+        }
+
+        let p = xs.iter().fold(FiniteField::new(1), |acc, &x| acc * x);
+        let lagrange_coefficients: Result<Vec<FiniteField>> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                // Since x_i != 0, division by x_i is safe via multiplication by its inverse
+                let numerator = p * x_i.inverse().unwrap();
+                let mut denominator = FiniteField::new(1);
+                for (j, &x_j) in xs.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator * (x_i + x_j);
+                    }
+                }
+                denominator
+                    .inverse()
+                    .ok_or(ShamirError::InvalidShareFormat)
+                    .map(|inv| numerator * inv)
+            })
+            .collect();
+
+        lagrange_coefficients
+    }
+
+    /// Computes Lagrange coefficients like
+    /// [`compute_lagrange_coefficients`](Self::compute_lagrange_coefficients), without
+    /// early exits
+    ///
+    /// Every index pair is compared and every coefficient computed, whatever the indices.
+    /// Zero and duplicate indices only set bits in an error flag, and inverting a zero
+    /// denominator yields zero instead of failing, so the error is returned once at the end.
+    ///
+    /// # Security
+    /// - Running time depends only on the number of shares, not on where a duplicate
+    ///   or zero index occurs
+    fn compute_lagrange_coefficients_ct<S: Borrow<Share>>(
+        shares: &[S],
+    ) -> Result<Vec<FiniteField>> {
+        // 1 if `value` is zero, else 0, without branching on `value`
+        let is_zero = |value: u8| ((u16::from(value).wrapping_sub(1)) >> 8) as u8 & 1;
+
+        let xs: Vec<FiniteField> = shares
+            .iter()
+            .map(|share| FiniteField::new(share.borrow().index))
+            .collect();
+
+        let mut invalid = 0u8;
+        for (i, &x_i) in xs.iter().enumerate() {
+            invalid |= is_zero(x_i.0);
+            for &x_j in &xs[i + 1..] {
+                invalid |= is_zero((x_i + x_j).0);
+            }
+        }
+
+        let p = xs.iter().fold(FiniteField::new(1), |acc, &x| acc * x);
+        let lagrange_coefficients = xs
+            .iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                let numerator = p * x_i.inverse_or_zero();
+                let mut denominator = FiniteField::new(1);
+                for (j, &x_j) in xs.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator * (x_i + x_j);
+                    }
+                }
+                numerator * denominator.inverse_or_zero()
+            })
+            .collect();
+
+        if invalid != 0 {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        Ok(lagrange_coefficients)
+    }
+
+    /// Computes the Lagrange basis weights for evaluating the interpolating polynomial at `x`
+    ///
+    /// For nodes `xs`, returns `w_i = prod_{j != i} (x - x_j) / (x_i - x_j)`, so that
+    /// `P(x) = sum_i w_i * y_i`. Evaluating at `x = 0` yields the reconstruction coefficients.
+    ///
+    /// # Security
+    /// - Constant-time GF(2^8) arithmetic
+    fn lagrange_weights_at(xs: &[FiniteField], x: FiniteField) -> Result<Vec<FiniteField>> {
+        xs.iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                let mut numerator = FiniteField::new(1);
+                let mut denominator = FiniteField::new(1);
+                for (j, &x_j) in xs.iter().enumerate() {
+                    if i != j {
+                        numerator = numerator * (x - x_j);
+                        denominator = denominator * (x_i - x_j);
+                    }
+                }
+                denominator
+                    .inverse()
+                    .ok_or(ShamirError::InvalidShareFormat)
+                    .map(|inv| numerator * inv)
+            })
+            .collect()
+    }
+
+    /// Helper method to compute Lagrange coefficients for reconstruction using ShareView
+    ///
+    /// This version works with borrowed share data to avoid allocations in hot paths.
+    /// Used internally by `reconstruct_stream` for performance optimization.
+    ///
+    /// # Arguments
+    /// * `share_views` - Slice of share views to compute coefficients for
+    ///
+    /// # Returns
+    /// Vector of Lagrange coefficients for each share
+    ///
+    /// # Security
+    /// - Constant-time coefficient computation
+    /// - Validates share indices for uniqueness
+    #[inline]
+    fn compute_lagrange_coefficients_from_views(
+        share_views: &[ShareView],
+    ) -> Result<Vec<FiniteField>> {
+        let xs: Vec<FiniteField> = share_views
+            .iter()
+            .map(|view| FiniteField::new(view.index))
+            .collect();
+
+        // Check for duplicate share indices
+        for i in 0..xs.len() {
+            for j in (i + 1)..xs.len() {
+                if xs[i] == xs[j] {
+                    return Err(ShamirError::InvalidShareFormat);
+                }
+            }
+        }
+
+        let p = xs.iter().fold(FiniteField::new(1), |acc, &x| acc * x);
+        let lagrange_coefficients: Result<Vec<FiniteField>> = xs
+            .iter()
+            .enumerate()
+            .map(|(i, &x_i)| {
+                // Since x_i != 0, division by x_i is safe via multiplication by its inverse
+                let numerator = p * x_i.inverse().unwrap();
+                let mut denominator = FiniteField::new(1);
+                for (j, &x_j) in xs.iter().enumerate() {
+                    if i != j {
+                        denominator = denominator * (x_i + x_j);
+                    }
+                }
+                denominator
+                    .inverse()
+                    .ok_or(ShamirError::InvalidShareFormat)
+                    .map(|inv| numerator * inv)
+            })
+            .collect();
+
+        lagrange_coefficients
+    }
+
+    /// Helper method to reconstruct data from shares using Lagrange interpolation
+    ///
+    /// This is the canonical implementation for reconstructing data using Shamir's Secret Sharing.
+    /// It takes a slice of shares and returns the reconstructed data.
+    /// Used internally by both `reconstruct` and `reconstruct_stream` methods to ensure consistency.
+    ///
+    /// # Arguments
+    /// * `shares` - Slice of shares to use for reconstruction
+    ///
+    /// # Returns
+    /// The reconstructed data (may include integrity hash if shares were created with integrity checking)
+    ///
+    /// # Security
+    /// - Constant-time Lagrange interpolation
+    /// - Parallel processing for performance while maintaining security
+    /// - Validates share consistency before processing
+    #[inline]
+    fn reconstruct_chunk<S: Borrow<Share> + Sync>(
+        shares: &[S],
+        parallel_threshold: usize,
+    ) -> Result<Vec<u8>> {
+        if shares.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+
+        let secret_len = shares[0].borrow().data.len();
+
+        // Ensure all shares have consistent length
+        if !shares.iter().all(|s| s.borrow().data.len() == secret_len) {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        if let [first, second] = shares {
+            return Self::reconstruct_two_point(
+                first.borrow(),
+                second.borrow(),
+                parallel_threshold,
+            );
+        }
+
+        // Use shared Lagrange coefficient computation
+        let lagrange_coefficients = Self::compute_lagrange_coefficients(shares)?;
+        Ok(Self::interpolate_at_zero(
+            shares,
+            &lagrange_coefficients,
+            parallel_threshold,
+        ))
+    }
+
+    /// Variant of [`reconstruct_chunk`](Self::reconstruct_chunk) for
+    /// [`reconstruct_ct`](Self::reconstruct_ct), always using
+    /// [`compute_lagrange_coefficients_ct`](Self::compute_lagrange_coefficients_ct)
+    fn reconstruct_chunk_ct<S: Borrow<Share> + Sync>(
+        shares: &[S],
+        parallel_threshold: usize,
+    ) -> Result<Vec<u8>> {
+        let Some(first) = shares.first().map(Borrow::borrow) else {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        };
+        if !shares
+            .iter()
+            .all(|s| s.borrow().data.len() == first.data.len())
+        {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        let lagrange_coefficients = Self::compute_lagrange_coefficients_ct(shares)?;
+        Ok(Self::interpolate_at_zero(
+            shares,
+            &lagrange_coefficients,
+            parallel_threshold,
+        ))
+    }
+
+    /// Combines the shares' bytes with precomputed Lagrange coefficients
+    ///
+    /// Parallelized across bytes for large secrets.
+    fn interpolate_at_zero<S: Borrow<Share> + Sync>(
+        shares: &[S],
+        lagrange_coefficients: &[FiniteField],
+        parallel_threshold: usize,
+    ) -> Vec<u8> {
+        let secret_len = shares[0].borrow().data.len();
+        let interpolate = |byte_idx: usize| {
+            shares
+                .iter()
+                .zip(lagrange_coefficients)
+                .fold(FiniteField::new(0), |acc, (share, &coeff)| {
+                    acc + coeff * FiniteField::new(share.borrow().data[byte_idx])
+                })
+                .0
+        };
+        if secret_len < parallel_threshold {
+            (0..secret_len).map(interpolate).collect()
+        } else {
+            (0..secret_len).into_par_iter().map(interpolate).collect()
+        }
+    }
+
+    /// Reconstructs the secret from exactly two shares, the common 2-of-n case
+    ///
+    /// The line through `(x1, y1)` and `(x2, y2)` meets x = 0 at `w * y1 + (1 + w) * y2`
+    /// with `w = x2 / (x1 + x2)`, since the two Lagrange weights sum to one. In GF(2^8) this
+    /// is `w * (y1 + y2) + y2`: one multiplication per byte instead of two, with output
+    /// identical to general interpolation.
+    ///
+    /// # Security
+    /// - Constant-time GF(2^8) arithmetic, without data-dependent branches
+    fn reconstruct_two_point(
+        first: &Share,
+        second: &Share,
+        parallel_threshold: usize,
+    ) -> Result<Vec<u8>> {
+        if first.index == 0 || second.index == 0 {
+            return Err(ShamirError::InvalidShareIndex(0));
+        }
+        let (x1, x2) = (
+            FiniteField::new(first.index),
+            FiniteField::new(second.index),
+        );
+        // Equal indices make the denominator zero
+        let inverse = (x1 + x2).inverse().ok_or(ShamirError::InvalidShareFormat)?;
+        let w = x2 * inverse;
+
+        let interpolate = |(&y1, &y2): (&u8, &u8)| {
+            (w * (FiniteField::new(y1) + FiniteField::new(y2)) + FiniteField::new(y2)).0
+        };
+        Ok(if first.data.len() < parallel_threshold {
+            first
+                .data
+                .iter()
+                .zip(&second.data)
+                .map(interpolate)
+                .collect()
+        } else {
+            first
+                .data
+                .par_iter()
+                .zip(&second.data)
+                .map(interpolate)
+                .collect()
+        })
+    }
+
+    /// Optimized helper method to reconstruct a single chunk from share views with buffer reuse
+    ///
+    /// This version uses borrowed share data to eliminate allocations in hot paths.
+    /// Used internally by `reconstruct_stream` for maximum performance optimization.
+    ///
+    /// # Arguments
+    /// * `share_views` - Slice of share views to use for reconstruction
+    /// * `output_buffer` - Reusable buffer for the reconstructed data
+    ///
+    /// # Returns
+    /// Slice reference to the reconstructed data in the output buffer
+    ///
+    /// # Security
+    /// - Constant-time Lagrange interpolation
+    /// - Uses borrowed data to avoid allocations
+    /// - Validates share consistency before processing
+    #[inline]
+    fn reconstruct_chunk_from_views<'a>(
+        share_views: &[ShareView],
+        output_buffer: &'a mut Vec<u8>,
+    ) -> Result<&'a [u8]> {
+        if share_views.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+
+        let secret_len = share_views[0].data.len();
+
+        // Ensure all share views have consistent length
+        if !share_views.iter().all(|v| v.data.len() == secret_len) {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        // Use shared Lagrange coefficient computation for views
+        let lagrange_coefficients = Self::compute_lagrange_coefficients_from_views(share_views)?;
+
+        // Reuse output buffer to avoid allocations in the hot loop
+        output_buffer.clear();
+        output_buffer.reserve(secret_len);
+
+        // Reconstruct each byte directly into the output buffer
+        for byte_idx in 0..secret_len {
+            let reconstructed_byte = share_views
+                .iter()
+                .zip(&lagrange_coefficients)
+                .fold(FiniteField::new(0), |acc, (view, &coeff)| {
+                    acc + coeff * FiniteField::new(view.data[byte_idx])
+                })
+                .0;
+            output_buffer.push(reconstructed_byte);
+        }
+
+        Ok(output_buffer)
+    }
+
+    /// Generates share deltas by creating and evaluating a random polynomial whose secret is zero
+    ///
+    /// This private helper method creates a polynomial of degree `k-1` where the constant term
+    /// (the "secret") is zero, and evaluates it at the given share indices. The resulting
+    /// delta values can be added to existing shares for share refreshing.
+    ///
+    /// # Arguments
+    /// * `share_indices` - Slice of x-coordinates (share indices) to evaluate the polynomial at
+    /// * `data_length` - Length of the zero secret data to generate deltas for
+    ///
+    /// # Returns
+    /// Vector where each element contains the delta data for the corresponding share index
+    ///
+    /// # Security
+    /// - Uses cryptographically secure random coefficients
+    /// - Constant-time polynomial evaluation using Horner's method
+    /// - Zero constant term ensures deltas maintain the secret sharing property
+    fn generate_zero_polynomial_shares(
+        &mut self,
+        share_indices: &[u8],
+        data_length: usize,
+    ) -> Result<Vec<Vec<u8>>> {
+        let t = self.threshold as usize;
+
+        // Generate random coefficients for all data bytes (for coefficients 1..t)
+        // The constant term (coefficient 0) is always zero for all bytes
+        let mut random_data = vec![0u8; data_length * (t - 1)];
+        self.rng.fill_bytes(&mut random_data);
+
+        // Evaluate the polynomial for each share index
+        let delta_shares: Vec<Vec<u8>> = share_indices
+            .par_iter()
+            .map(|&index| {
+                let x = FiniteField::new(index);
+
+                // For each byte position, evaluate the polynomial at x
+                (0..data_length)
+                    .map(|byte_idx| {
+                        let mut acc = FiniteField::new(0);
+
+                        // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
+                        // P(x) = 0 + random_coef1 * x + random_coef2 * x^2 + ... + random_coef_{t-1} * x^(t-1)
+                        for j in (1..t).rev() {
+                            // Random coefficient for x^j is stored in random_data at position byte_idx*(t-1) + (j-1)
+                            let coeff = FiniteField::new(random_data[byte_idx * (t - 1) + (j - 1)]);
+                            acc = acc * x + coeff;
+                        }
+
+                        // Note: We skip j=0 because the constant term is always FiniteField(0)
+                        // The final multiplication by x handles the last coefficient
+                        acc = acc * x;
+
+                        acc.0
+                    })
+                    .collect()
+            })
+            .collect();
+
+        // Zeroize sensitive random coefficients before returning
+        #[cfg(feature = "zeroize")]
+        random_data.zeroize();
+
+        Ok(delta_shares)
+    }
+
+    /// Refreshes existing shares by adding zero-polynomial deltas to invalidate old shares
+    ///
+    /// This method generates new shares that maintain the same secret but have different share data,
+    /// effectively invalidating the old shares for security purposes. The refreshing process uses
+    /// additive sharing of a zero-secret polynomial, ensuring that the underlying secret remains
+    /// unchanged while the share values are completely refreshed.
+    ///
+    /// # Arguments
+    /// * `shares` - Slice of existing shares to refresh (must have at least `threshold` shares)
+    ///
+    /// # Returns
+    /// Vector of refreshed shares with the same indices and metadata but new share data,
+    /// and a `refresh_generation` one higher than the input shares'
+    ///
+    /// # Security Purpose
+    /// Share refreshing is a critical security operation that:
+    /// - **Invalidates old shares**: Previous share values become useless after refreshing
+    /// - **Maintains secret integrity**: The underlying secret remains exactly the same
+    /// - **Prevents share accumulation**: Attackers cannot combine old and new shares
+    /// - **Enables proactive security**: Regular refreshing limits exposure windows
+    ///
+    /// # Mechanism
+    /// The refreshing process works by:
+    /// 1. Generating a random polynomial with zero constant term (zero-secret)
+    /// 2. Evaluating this polynomial at the same x-coordinates as the input shares
+    /// 3. Adding (XOR) the resulting deltas to the original share data
+    /// 4. Since the polynomial has zero secret, the refreshed shares reconstruct to the same value
+    ///
+    /// # Input Validation
+    /// This method performs comprehensive validation:
+    /// - Ensures the shares slice is not empty
+    /// - Verifies sufficient shares (at least `threshold` shares required)
+    /// - Checks that all shares have consistent data length
+    /// - Validates that all shares have the same integrity check setting
+    /// - Checks that all shares are of the same refresh generation
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - No shares provided (empty slice)
+    /// - Insufficient shares for the threshold requirement
+    /// - Shares have inconsistent data lengths
+    /// - Shares have different integrity check settings
+    /// - Shares have different refresh generations (`MixedGenerations`)
+    /// - The shares have already been refreshed `u32::MAX` times (`InvalidConfig`)
+    /// - Internal polynomial generation fails
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let secret = b"sensitive data";
+    ///
+    /// // Create initial shares
+    /// let original_shares = scheme.split(secret).unwrap();
+    ///
+    /// // Refresh the shares to invalidate old ones
+    /// let refreshed_shares = scheme.refresh_shares(&original_shares[0..3]).unwrap();
+    ///
+    /// // Both sets reconstruct to the same secret
+    /// let original_secret = ShamirShare::reconstruct(&original_shares[0..3]).unwrap();
+    /// let refreshed_secret = ShamirShare::reconstruct(&refreshed_shares).unwrap();
+    /// assert_eq!(original_secret, refreshed_secret);
+    ///
+    /// // But the share data is completely different
+    /// assert_ne!(original_shares[0].data, refreshed_shares[0].data);
+    /// ```
+    ///
+    /// # Performance
+    /// - Time complexity: O(n * m * k) where n = number of shares, m = data length, k = threshold
+    /// - Space complexity: O(n * m) for the output shares
+    /// - Uses constant-time operations to prevent side-channel attacks
+    pub fn refresh_shares(&mut self, shares: &[Share]) -> Result<Vec<Share>> {
+        // Input validation: Check if shares slice is empty
+        if shares.is_empty() {
+            return Err(ShamirError::InsufficientShares { needed: 1, got: 0 });
+        }
+
+        // Input validation: Check if we have sufficient shares for the threshold
+        if shares.len() < self.threshold as usize {
+            return Err(ShamirError::InsufficientShares {
+                needed: self.threshold,
+                got: u8::try_from(shares.len()).unwrap_or(u8::MAX),
+            });
+        }
+
+        // Extract reference values from the first share for consistency checking
+        let data_length = shares[0].data.len();
+        let integrity_check = shares[0].integrity_check;
+
+        // Input validation: Check that all shares have consistent data length and integrity check setting
+        if !shares
+            .iter()
+            .all(|s| s.data.len() == data_length && s.integrity_check == integrity_check)
+        {
+            return Err(ShamirError::InconsistentShareLength);
+        }
+
+        let generation = shares[0].refresh_generation;
+        if shares.iter().any(|s| s.refresh_generation != generation) {
+            return Err(ShamirError::MixedGenerations);
+        }
+        let next_generation = generation.checked_add(1).ok_or_else(|| {
+            ShamirError::InvalidConfig("Refresh generation counter exhausted".to_string())
+        })?;
+
+        // Extract the indices from the input shares
+        let indices: Vec<u8> = shares.iter().map(|s| s.index).collect();
+
+        // Generate zero-polynomial deltas using the private helper
+        let deltas = self.generate_zero_polynomial_shares(&indices, data_length)?;
+
+        // Create refreshed shares by XORing original data with deltas
+        let refreshed_shares: Vec<Share> = shares
+            .iter()
+            .zip(deltas.iter())
+            .map(|(old_share, delta_data)| {
+                // XOR the original share data with the delta to create new share data
+                let new_data: Vec<u8> = old_share
+                    .data
+                    .iter()
+                    .zip(delta_data.iter())
+                    .map(|(&old_byte, &delta_byte)| old_byte ^ delta_byte)
+                    .collect();
+
+                // Create new share with refreshed data but same metadata
+                Share {
+                    index: old_share.index,
+                    data: new_data,
+                    threshold: old_share.threshold,
+                    total_shares: old_share.total_shares,
+                    integrity_check: old_share.integrity_check,
+                    integrity_algorithm: old_share.integrity_algorithm,
+                    compression: old_share.compression,
+                    length_check: old_share.length_check,
+                    refresh_generation: next_generation,
+                    metadata: old_share.metadata.clone(),
+                    not_before: old_share.not_before,
+                }
+            })
+            .collect();
+
+        Ok(refreshed_shares)
+    }
+}
+
+impl Iterator for Dealer {
+    type Item = Share;
+
+    /// Generates the next share by evaluating the polynomial at the current x-coordinate
+    ///
+    /// This method uses constant-time polynomial evaluation with Horner's method to compute
+    /// the share data. It automatically stops after 255 shares (GF(256) field limitation).
+    ///
+    /// # Returns
+    /// - `Some(Share)` - The next share in the sequence
+    /// - `None` - When all possible shares have been generated (x > 255)
+    ///
+    /// # Security
+    /// - Constant-time polynomial evaluation using Horner's method
+    /// - No data-dependent branching or memory access patterns
+    fn next(&mut self) -> Option<Self::Item> {
+        // Stop after 255 shares (GF(256) field limitation - x=0 is not used)
+        if self.current_x == 0 {
+            return None;
+        }
+
+        let x = FiniteField::new(self.current_x);
+        let secret_len = self.data.len();
+        let t = self.threshold as usize;
+
+        // Evaluate polynomial for each byte at the current x-coordinate
+        let share_data: Vec<u8> = (0..secret_len)
+            .map(|byte_idx| {
+                let mut acc = FiniteField::new(0);
+                // Evaluate polynomial using Horner's method (iterating coefficients in reverse order)
+                for j in (0..t).rev() {
+                    let coeff = if j == 0 {
+                        FiniteField::new(self.data[byte_idx])
+                    } else {
+                        // Random coefficient for x^j is stored in coefficients at position byte_idx*(t-1) + (j-1)
+                        FiniteField::new(self.coefficients[byte_idx * (t - 1) + (j - 1)])
+                    };
+                    acc = acc * x + coeff;
+                }
+                acc.0
+            })
+            .collect();
+
+        let share = Share {
+            index: self.current_x,
+            data: share_data,
+            threshold: self.threshold,
+            total_shares: self.total_shares,
+            integrity_check: self.integrity_check,
+            integrity_algorithm: self.integrity_algorithm,
+            compression: self.compression,
+            length_check: self.length_check,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
+        };
+
+        // Increment x for next share, wrapping to 0 when we reach 256 (which stops iteration)
+        self.current_x = self.current_x.wrapping_add(1);
+
+        Some(share)
+    }
+
+    /// Returns the number of remaining shares that can be generated
+    ///
+    /// This provides a size hint for the iterator, which can be useful for
+    /// pre-allocating collections or progress tracking.
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = if self.current_x == 0 {
+            0
+        } else {
+            256 - self.current_x as usize
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl ExactSizeIterator for Dealer {
+    /// Returns the exact number of remaining shares
+    fn len(&self) -> usize {
+        self.size_hint().0
+    }
+}
+
+/// Reads the bytes interpolated from borrowed share data, as many as each read asks for
+struct InterpolatingReader<'a> {
+    views: &'a [ShareView<'a>],
+    /// Lagrange coefficients of `views` at x = 0
+    coefficients: &'a [FiniteField],
+    /// Offset into the share data of the next byte to interpolate
+    position: usize,
+}
+
+impl Read for InterpolatingReader<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let end = self.views[0].data.len().min(self.position + buf.len());
+        for (byte, byte_idx) in buf.iter_mut().zip(self.position..end) {
+            *byte = self
+                .views
+                .iter()
+                .zip(self.coefficients)
+                .fold(FiniteField::new(0), |acc, (view, &coeff)| {
+                    acc + coeff * FiniteField::new(view.data[byte_idx])
+                })
+                .0;
+        }
+        let read = end - self.position;
+        self.position = end;
+        Ok(read)
+    }
+}
+
+/// A file written through a growable writable memory mapping
+#[cfg(feature = "memmap")]
+struct MmapOutput {
+    file: File,
+    /// Mapping of the file's current length; `None` while the file is empty
+    map: Option<memmap2::MmapMut>,
+    /// Bytes written so far
+    len: u64,
+}
+
+#[cfg(feature = "memmap")]
+impl MmapOutput {
+    /// Sizes `file` to `capacity` bytes and maps it
+    fn new(file: File, capacity: u64) -> Result<Self> {
+        let mut output = Self {
+            file,
+            map: None,
+            len: 0,
+        };
+        output.resize(capacity)?;
+        Ok(output)
+    }
+
+    /// Current size of the file and mapping
+    fn capacity(&self) -> u64 {
+        self.map.as_ref().map_or(0, |map| map.len() as u64)
+    }
+
+    /// Resizes the file to `capacity` bytes and remaps it
+    fn resize(&mut self, capacity: u64) -> Result<()> {
+        if let Some(map) = self.map.take() {
+            map.flush()?;
+        }
+        self.file.set_len(capacity)?;
+        if capacity > 0 {
+            // SAFETY: the file was opened by us for reading and writing and is only
+            // modified through this mapping until it is dropped
+            self.map = Some(unsafe { memmap2::MmapMut::map_mut(&self.file)? });
+        }
+        Ok(())
+    }
+
+    /// Appends `data`, growing the file when it exceeds the expected length
+    fn write(&mut self, data: &[u8]) -> Result<()> {
+        let end = self.len + data.len() as u64;
+        if end > self.capacity() {
+            self.resize(end.max(self.capacity() * 2))?;
+        }
+        if let Some(map) = self.map.as_mut() {
+            map[self.len as usize..end as usize].copy_from_slice(data);
+        }
+        self.len = end;
+        Ok(())
+    }
+
+    /// Flushes the mapping and trims the file to the bytes written
+    fn finish(mut self) -> Result<u64> {
+        if let Some(map) = self.map.take() {
+            map.flush()?;
+        }
+        self.file.set_len(self.len)?;
+        Ok(self.len)
+    }
+
+    /// Wipes the bytes written so far and truncates the file to zero length
+    fn discard(mut self) {
+        if let Some(mut map) = self.map.take() {
+            map[..self.len as usize].fill(0);
+            // Best effort: the original reconstruction error is reported instead
+            let _ = map.flush();
+        }
+        let _ = self.file.set_len(0);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_and_reconstruct() {
+        let secret = b"Hello, World!";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+
+        // Split the secret
+        let shares = shamir.split(secret).unwrap();
+        assert_eq!(shares.len(), 5);
+
+        // Reconstruct with exactly threshold shares
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
+
+        // Reconstruct with more than threshold shares
+        let reconstructed = ShamirShare::reconstruct(&shares[1..5]).unwrap();
+        assert_eq!(&reconstructed, secret);
+    }
+
+    #[test]
+    fn test_invalid_parameters() {
+        assert!(ShamirShare::builder(0, 1).build().is_err());
+        assert!(ShamirShare::builder(1, 0).build().is_err());
+        assert!(ShamirShare::builder(3, 4).build().is_err());
+    }
+
+    #[test]
+    fn test_insufficient_shares() {
+        let secret = b"Test";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        assert!(ShamirShare::reconstruct(&shares[0..2]).is_err());
+    }
+
+    #[test]
+    fn test_different_share_combinations() {
+        let secret = b"Different combinations test";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        // Try different combinations of 3 shares
+        let combinations = vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4], vec![0, 2, 4]];
+
+        for combo in combinations {
+            let selected_shares: Vec<Share> = combo.iter().map(|&i| shares[i].clone()).collect();
+
+            let reconstructed = ShamirShare::reconstruct(&selected_shares).unwrap();
+            assert_eq!(&reconstructed, secret);
+        }
+    }
+
+    #[test]
+    fn test_empty_secret() {
+        let secret = b"";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_single_byte_secret() {
+        let secret = b"x";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_max_shares() {
+        let secret = b"Maximum shares test";
+        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+        assert_eq!(shares.len(), 255);
+
+        let reconstructed = ShamirShare::reconstruct(&shares[0..128]).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    #[test]
+    fn test_duplicate_share_indices() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(b"test").unwrap();
+
+        let mut corrupted_shares = shares[0..3].to_vec();
+        corrupted_shares[1].index = corrupted_shares[0].index; // Duplicate index
+
+        assert!(matches!(
+            ShamirShare::reconstruct(&corrupted_shares),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_corrupted_share_data() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let mut shares = shamir.split(b"test").unwrap();
+
+        // Corrupt one byte in a share
+        if shares[0].data[0] == 0 {
+            shares[0].data[0] = 1;
+        } else {
+            shares[0].data[0] = 0;
+        }
+
+        assert!(matches!(
+            ShamirShare::reconstruct(&shares[0..3]),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+    }
+
+    #[test]
+    fn test_builder_pattern() {
+        // Test basic builder usage
+        let shamir = ShamirShare::builder(5, 3).build().unwrap();
+        assert_eq!(shamir.total_shares, 5);
+        assert_eq!(shamir.threshold, 3);
+        assert!(shamir.config.integrity_check); // Default should be true
+
+        // Test builder with custom config
+        let config = Config::new().with_integrity_check(false);
+        let shamir = ShamirShare::builder(7, 4)
+            .with_config(config)
+            .build()
+            .unwrap();
+        assert_eq!(shamir.total_shares, 7);
+        assert_eq!(shamir.threshold, 4);
+        assert!(!shamir.config.integrity_check);
+    }
+
+    #[test]
+    fn test_builder_validation() {
+        // Test invalid parameters through builder
+        assert!(ShamirShare::builder(0, 1).build().is_err());
+        assert!(ShamirShare::builder(1, 0).build().is_err());
+        assert!(ShamirShare::builder(3, 5).build().is_err());
+
+        // Test invalid config
+        let invalid_config = Config::new().with_chunk_size(0).unwrap_err();
+        assert!(matches!(invalid_config, ShamirError::InvalidConfig(_)));
+    }
+
+    #[test]
+    fn test_fault_tolerance() {
+        assert_eq!(
+            ShamirShare::builder(5, 3)
+                .build()
+                .unwrap()
+                .fault_tolerance(),
+            2
+        );
+        assert_eq!(
+            ShamirShare::builder(5, 1)
+                .build()
+                .unwrap()
+                .fault_tolerance(),
+            4
+        );
+
+        // Zero tolerance is allowed by default but detectable
+        let fragile = ShamirShare::builder(4, 4).build().unwrap();
+        assert_eq!(fragile.fault_tolerance(), 0);
+    }
+
+    #[test]
+    fn test_no_fault_tolerance_opt_out() {
+        let strict = Config::new().with_allow_no_fault_tolerance(false);
+
+        assert!(matches!(
+            ShamirShare::builder(4, 4)
+                .with_config(strict.clone())
+                .build(),
+            Err(ShamirError::NoFaultTolerance { threshold: 4 })
+        ));
+
+        // Schemes with spare shares are unaffected
+        let scheme = ShamirShare::builder(4, 3)
+            .with_config(strict)
+            .build()
+            .unwrap();
+        assert_eq!(scheme.fault_tolerance(), 1);
+
+        // Explicitly allowing it restores the default behavior
+        let permissive = Config::new().with_allow_no_fault_tolerance(true);
+        assert!(
+            ShamirShare::builder(4, 4)
+                .with_config(permissive)
+                .build()
+                .is_ok()
+        );
+    }
+
+    /// RNG source that fails a fixed number of times before producing bytes
+    struct FlakyRng {
+        failures_left: u8,
+        attempts: u8,
+    }
+
+    impl TryRngCore for FlakyRng {
+        type Error = std::io::Error;
+
+        fn try_next_u32(&mut self) -> std::result::Result<u32, Self::Error> {
+            let mut bytes = [0u8; 4];
+            self.try_fill_bytes(&mut bytes)?;
+            Ok(u32::from_le_bytes(bytes))
+        }
+
+        fn try_next_u64(&mut self) -> std::result::Result<u64, Self::Error> {
+            let mut bytes = [0u8; 8];
+            self.try_fill_bytes(&mut bytes)?;
+            Ok(u64::from_le_bytes(bytes))
+        }
+
+        fn try_fill_bytes(&mut self, dst: &mut [u8]) -> std::result::Result<(), Self::Error> {
+            self.attempts += 1;
+            if self.failures_left > 0 {
+                self.failures_left -= 1;
+                return Err(std::io::Error::other("entropy starved"));
+            }
+            dst.fill(0x5A);
+            Ok(())
+        }
+    }
+
+    #[test]
+    fn test_rng_seeding_retries_until_success() {
+        let mut source = FlakyRng {
+            failures_left: 1,
+            attempts: 0,
+        };
+        assert!(seed_rng_with_retry(&mut source, 3).is_ok());
+        assert_eq!(source.attempts, 2);
+    }
+
+    #[test]
+    fn test_rng_seeding_gives_up_after_retries() {
+        let mut source = FlakyRng {
+            failures_left: 5,
+            attempts: 0,
+        };
+        assert!(matches!(
+            seed_rng_with_retry(&mut source, 2),
+            Err(ShamirError::RngUnavailable(_))
+        ));
+        assert_eq!(source.attempts, 3);
+
+        let mut source = FlakyRng {
+            failures_left: 1,
+            attempts: 0,
+        };
+        assert!(seed_rng_with_retry(&mut source, 0).is_err());
+        assert_eq!(source.attempts, 1);
+    }
+
+    #[test]
+    fn test_integrity_check_disabled() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let secret = b"test secret without integrity check";
+        let shares = shamir.split(secret).unwrap();
+
+        // Verify shares have integrity_check = false
+        assert!(!shares[0].integrity_check);
+
+        // Reconstruct should work
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
+
+        // Data should be smaller since no hash is prepended
+        let mut shamir_with_integrity = ShamirShare::builder(5, 3).build().unwrap();
+        let shares_with_integrity = shamir_with_integrity.split(secret).unwrap();
+
+        // Shares without integrity check should be smaller
+        assert!(shares[0].data.len() < shares_with_integrity[0].data.len());
+        assert_eq!(
+            shares_with_integrity[0].data.len() - shares[0].data.len(),
+            HASH_SIZE
+        );
+    }
+
+    #[test]
+    fn test_integrity_check_enabled() {
+        let config = Config::new().with_integrity_check(true);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let secret = b"test secret with integrity check";
+        let shares = shamir.split(secret).unwrap();
+
+        // Verify shares have integrity_check = true
+        assert!(shares[0].integrity_check);
+
+        // Reconstruct should work
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
+
+        // Corruption should be detected
+        let mut corrupted_shares = shares[0..3].to_vec();
+        if corrupted_shares[0].data[0] == 0 {
+            corrupted_shares[0].data[0] = 1;
+        } else {
+            corrupted_shares[0].data[0] = 0;
+        }
+
+        assert!(matches!(
+            ShamirShare::reconstruct(&corrupted_shares),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+    }
+
+    #[test]
+    fn test_mixed_integrity_check_shares() {
+        // Create shares with integrity check enabled
+        let config_with_integrity = Config::new().with_integrity_check(true);
+        let mut shamir_with_integrity = ShamirShare::builder(5, 3)
+            .with_config(config_with_integrity)
+            .build()
+            .unwrap();
+
+        // Create shares with integrity check disabled
+        let config_without_integrity = Config::new().with_integrity_check(false);
+        let mut shamir_without_integrity = ShamirShare::builder(5, 3)
+            .with_config(config_without_integrity)
+            .build()
+            .unwrap();
+
+        let secret = b"test secret";
+        let shares_with_integrity = shamir_with_integrity.split(secret).unwrap();
+        let shares_without_integrity = shamir_without_integrity.split(secret).unwrap();
+
+        // Mixing shares with different integrity check settings should fail
+        let mixed_shares = vec![
+            shares_with_integrity[0].clone(),
+            shares_without_integrity[1].clone(),
+            shares_with_integrity[2].clone(),
+        ];
+
+        assert!(matches!(
+            ShamirShare::reconstruct(&mixed_shares),
+            Err(ShamirError::InconsistentShareMetadata {
+                field: "integrity_check"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_inconsistent_metadata_names_field() {
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = shamir.split(b"metadata").unwrap();
+        let mismatch = |modify: fn(&mut Share)| {
+            let mut altered = shares[1].clone();
+            modify(&mut altered);
+            match ShamirShare::reconstruct(&[shares[0].clone(), altered]) {
+                Err(ShamirError::InconsistentShareMetadata { field }) => field,
+                other => panic!("expected InconsistentShareMetadata, got {other:?}"),
+            }
+        };
+
+        assert_eq!(mismatch(|s| s.integrity_check = false), "integrity_check");
+        assert_eq!(
+            mismatch(|s| s.integrity_algorithm = 7),
+            "integrity_algorithm"
+        );
+        assert_eq!(mismatch(|s| s.compression = !s.compression), "compression");
+        assert_eq!(mismatch(|s| s.length_check = true), "length_check");
+        assert_eq!(mismatch(|s| s.data.push(0)), "data length");
+
+        // The algorithm id is not an effective parameter without integrity checking
+        let config = Config::new().with_integrity_check(false);
+        let mut unchecked = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut shares = unchecked.split(b"metadata").unwrap();
+        shares[1].integrity_algorithm = 7;
+        assert_eq!(ShamirShare::reconstruct(&shares[..2]).unwrap(), b"metadata");
+    }
+
+    #[test]
+    fn test_reconstruct_trimmed_padded_shares() {
+        let secret = b"storage padded this";
+        let mut shamir = ShamirShare::builder(4, 3).build().unwrap();
+        let mut shares = shamir.split(secret).unwrap();
+
+        // Each store padded to a different block size with trailing zeros
+        for (share, padding) in shares.iter_mut().zip([0, 5, 13, 64]) {
+            share.data.resize(share.data.len() + padding, 0);
+        }
+        assert!(ShamirShare::reconstruct(&shares[..3]).is_err());
+        assert_eq!(
+            ShamirShare::reconstruct_trimmed(&shares[..3]).unwrap(),
+            secret
+        );
+
+        // Trimming away real data is caught by the integrity check
+        shares[2].data.truncate(HASH_SIZE + secret.len() - 1);
+        assert!(matches!(
+            ShamirShare::reconstruct_trimmed(&shares[..3]),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_trimmed(&[]),
+            Err(ShamirError::InsufficientShares { .. })
+        ));
+    }
+
+    #[test]
+    fn test_numeric_secret_round_trip() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        for value in [0, 1, 0x0123_4567_89AB_CDEF, u64::MAX] {
+            let shares = shamir.split_u64(value).unwrap();
+            assert_eq!(shares[0].data.len(), HASH_SIZE + 8);
+            assert_eq!(ShamirShare::reconstruct_u64(&shares[2..]).unwrap(), value);
+        }
+        for value in [0, u128::from(u64::MAX) + 1, u128::MAX] {
+            let shares = shamir.split_u128(value).unwrap();
+            assert_eq!(ShamirShare::reconstruct_u128(&shares[..3]).unwrap(), value);
+        }
+
+        // Secrets of another width are rejected rather than truncated or padded
+        let wide = shamir.split_u128(7).unwrap();
+        assert!(matches!(
+            ShamirShare::reconstruct_u64(&wide[..3]),
+            Err(ShamirError::InvalidReconstructedLength {
+                expected: 8,
+                actual: 16
+            })
+        ));
+        let text = shamir.split(b"1234").unwrap();
+        assert!(matches!(
+            ShamirShare::reconstruct_u128(&text[..3]),
+            Err(ShamirError::InvalidReconstructedLength {
+                expected: 16,
+                actual: 4
+            })
+        ));
+    }
+
+    #[test]
+    fn test_is_minimal_set() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(b"just enough").unwrap();
+
+        assert!(ShamirShare::is_minimal_set(&shares[..3]));
+        assert!(ShamirShare::is_minimal_set(&shares[2..]));
+        assert!(!ShamirShare::is_minimal_set(&shares[..4]));
+        assert!(!ShamirShare::is_minimal_set(&shares));
+        assert!(!ShamirShare::is_minimal_set(&shares[..2]));
+        assert!(!ShamirShare::is_minimal_set(&[]));
+
+        // Inconsistent sets of the right size are not minimal sets either
+        let duplicated = [shares[0].clone(), shares[1].clone(), shares[0].clone()];
+        assert!(!ShamirShare::is_minimal_set(&duplicated));
+        let other = ShamirShare::builder(4, 3)
+            .build()
+            .unwrap()
+            .split(b"just enough")
+            .unwrap();
+        let mixed = [shares[0].clone(), shares[1].clone(), other[2].clone()];
+        assert!(!ShamirShare::is_minimal_set(&mixed));
+    }
+
+    #[test]
+    fn test_reconstruct_cow_mixed_ownership() {
+        let secret = b"owned and borrowed";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        let mixed = [
+            Cow::Borrowed(&shares[0]),
+            Cow::Owned(shares[2].clone()),
+            Cow::Borrowed(&shares[4]),
+        ];
+        assert_eq!(ShamirShare::reconstruct_cow(&mixed).unwrap(), secret);
+
+        let two_of_three = [Cow::Owned(shares[1].clone()), Cow::Borrowed(&shares[3])];
+        assert!(matches!(
+            ShamirShare::reconstruct_cow(&two_of_three),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
+
+        // Metadata checks see through both variants
+        let mut tampered = shares[3].clone();
+        tampered.compression = !tampered.compression;
+        let inconsistent = [
+            Cow::Borrowed(&shares[0]),
+            Cow::Borrowed(&shares[1]),
+            Cow::Owned(tampered),
+        ];
+        assert!(matches!(
+            ShamirShare::reconstruct_cow(&inconsistent),
+            Err(ShamirError::InconsistentShareMetadata {
+                field: "compression"
+            })
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_ct_detects_duplicates_at_any_position() {
+        let secret = b"hardened interpolation";
+        let mut shamir = ShamirShare::builder(6, 4).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        assert_eq!(ShamirShare::reconstruct_ct(&shares[2..]).unwrap(), secret);
+        for set in [&shares[..2], &shares[1..4], &shares[..]] {
+            assert_eq!(
+                ShamirShare::compute_lagrange_coefficients_ct(set).unwrap(),
+                ShamirShare::compute_lagrange_coefficients(set).unwrap()
+            );
+        }
+
+        // A duplicate at the front, in the middle, at the end and spread apart
+        for (copy_from, copy_to) in [(0, 1), (1, 2), (3, 4), (0, 4)] {
+            let mut set = shares[..5].to_vec();
+            set[copy_to] = set[copy_from].clone();
+            assert!(matches!(
+                ShamirShare::compute_lagrange_coefficients_ct(&set),
+                Err(ShamirError::InvalidShareFormat)
+            ));
+            assert!(matches!(
+                ShamirShare::reconstruct_ct(&set),
+                Err(ShamirError::InvalidShareFormat)
+            ));
+        }
+
+        // Index 0 never comes from a split and is rejected rather than panicking
+        let mut zero_index = shares[..4].to_vec();
+        zero_index[2].index = 0;
+        assert!(matches!(
+            ShamirShare::reconstruct_ct(&zero_index),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_nonzero() {
+        let mut shamir = ShamirShare::builder(4, 3).build().unwrap();
+
+        let key = [0x5Au8; 32];
+        let shares = shamir.split(&key).unwrap();
+        assert_eq!(ShamirShare::reconstruct_nonzero(&shares[1..]).unwrap(), key);
+
+        // A single nonzero byte anywhere is enough
+        let mut last_byte_set = [0u8; 32];
+        last_byte_set[31] = 1;
+        let shares = shamir.split(&last_byte_set).unwrap();
+        assert_eq!(
+            ShamirShare::reconstruct_nonzero(&shares[..3]).unwrap(),
+            last_byte_set
+        );
+
+        for zero in [&[0u8; 32][..], &[]] {
+            let shares = shamir.split(zero).unwrap();
+            assert_eq!(ShamirShare::reconstruct(&shares[..3]).unwrap(), zero);
+            assert!(matches!(
+                ShamirShare::reconstruct_nonzero(&shares[..3]),
+                Err(ShamirError::TrivialSecret)
+            ));
+        }
+
+        // Errors from reconstruction itself take precedence
+        assert!(matches!(
+            ShamirShare::reconstruct_nonzero(&shares[..2]),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_min_generation_rejects_stale_shares() {
+        let secret = b"after key rotation";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let gen0 = shamir.split(secret).unwrap();
+        let gen1 = shamir.refresh_shares(&gen0).unwrap();
+        let gen2 = shamir.refresh_shares(&gen1).unwrap();
+
+        assert_eq!(
+            ShamirShare::reconstruct_min_generation(&gen0[..3], 0).unwrap(),
+            secret
+        );
+        assert_eq!(
+            ShamirShare::reconstruct_min_generation(&gen2[2..], 1).unwrap(),
+            secret
+        );
+        assert_eq!(
+            ShamirShare::reconstruct_min_generation(&gen2[..3], 2).unwrap(),
+            secret
+        );
+
+        // A complete stale set still reconstructs with plain `reconstruct`
+        assert_eq!(ShamirShare::reconstruct(&gen1[1..4]).unwrap(), secret);
+        assert!(matches!(
+            ShamirShare::reconstruct_min_generation(&gen1[1..4], 2),
+            Err(ShamirError::StaleShare {
+                index: 2,
+                generation: 1
+            })
+        ));
+
+        // The first stale share is named, even among current ones
+        let mixed = [gen2[0].clone(), gen0[3].clone(), gen2[4].clone()];
+        assert!(matches!(
+            ShamirShare::reconstruct_min_generation(&mixed, 2),
+            Err(ShamirError::StaleShare {
+                index: 4,
+                generation: 0
+            })
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_min_generation(&mixed, 0),
+            Err(ShamirError::MixedGenerations)
+        ));
+    }
+
+    #[test]
+    fn test_padding_recommendation_buckets() {
+        let advice = |len| {
+            let advice = ShamirShare::padding_recommendation(len).unwrap();
+            (
+                advice.recommended,
+                advice.pad_to_multiple,
+                advice.padded_len,
+            )
+        };
+
+        // Small secrets share the smallest bucket, including the empty secret
+        assert_eq!(advice(0), (true, 16, 16));
+        assert_eq!(advice(11), (true, 16, 16));
+        assert_eq!(advice(16), (false, 16, 16));
+        // Key-sized secrets already on a boundary need no padding
+        assert_eq!(advice(32), (false, 32, 32));
+        assert_eq!(advice(33), (true, 64, 64));
+        assert_eq!(advice(1000), (true, 1024, 1024));
+        assert_eq!(advice(4096), (false, 4096, 4096));
+        // Past the largest bucket, padding grows in 4 KiB steps
+        assert_eq!(advice(4097), (true, 4096, 8192));
+        assert_eq!(advice(1_000_000), (true, 4096, 1_003_520));
+
+        // The last bucket boundary below usize::MAX is still reachable, anything past it is not
+        let last_boundary = usize::MAX / 4096 * 4096;
+        assert_eq!(advice(last_boundary), (false, 4096, last_boundary));
+        assert!(matches!(
+            ShamirShare::padding_recommendation(last_boundary + 1),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_dedupe_keeps_first_of_each_index() {
+        let secret = b"delivered twice";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        let resent = [
+            shares[2].clone(),
+            shares[0].clone(),
+            shares[2].clone(),
+            shares[4].clone(),
+            shares[0].clone(),
+        ];
+        assert_eq!(ShamirShare::reconstruct_dedupe(&resent).unwrap(), secret);
+        assert!(matches!(
+            ShamirShare::reconstruct(&resent),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        // Duplicates do not count towards the threshold
+        let too_few = [shares[1].clone(), shares[1].clone(), shares[3].clone()];
+        assert!(matches!(
+            ShamirShare::reconstruct_dedupe(&too_few),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
+
+        // A conflicting later copy is dropped unexamined, an earlier one is used
+        let mut forged = shares[0].clone();
+        forged.data[0] ^= 0xFF;
+        let genuine_first = [
+            shares[0].clone(),
+            forged.clone(),
+            shares[1].clone(),
+            shares[2].clone(),
+        ];
+        assert_eq!(
+            ShamirShare::reconstruct_dedupe(&genuine_first).unwrap(),
+            secret
+        );
+        let forged_first = [
+            forged,
+            shares[0].clone(),
+            shares[1].clone(),
+            shares[2].clone(),
+        ];
+        assert!(matches!(
+            ShamirShare::reconstruct_dedupe(&forged_first),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_verified_set_rejects_other_split() {
+        let secret = b"published set";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+        let other = shamir.split(secret).unwrap();
+        let fingerprint = ShamirShare::set_fingerprint(&shares[1..4]);
+
+        let mut reordered = shares[1..4].to_vec();
+        reordered.reverse();
+        assert_eq!(
+            ShamirShare::reconstruct_verified_set(&reordered, &fingerprint).unwrap(),
+            secret
+        );
+
+        // The same indices from another split of the same secret
+        assert!(matches!(
+            ShamirShare::reconstruct_verified_set(&other[1..4], &fingerprint),
+            Err(ShamirError::ShareSetMismatch)
+        ));
+
+        // A mixed set that would otherwise interpolate to garbage
+        let mixed = [shares[1].clone(), shares[2].clone(), other[3].clone()];
+        assert!(matches!(
+            ShamirShare::reconstruct_verified_set(&mixed, &fingerprint),
+            Err(ShamirError::ShareSetMismatch)
+        ));
+
+        // A different subset of the right split is a different set
+        assert!(matches!(
+            ShamirShare::reconstruct_verified_set(&shares[..3], &fingerprint),
+            Err(ShamirError::ShareSetMismatch)
+        ));
+    }
+
+    #[test]
+    fn test_config_builder_methods() {
+        use crate::config::SplitMode;
+
+        let config = Config::new()
+            .with_chunk_size(2048)
+            .unwrap()
+            .with_mode(SplitMode::Parallel)
+            .with_compression(true)
+            .with_integrity_check(false);
+
+        let shamir = ShamirShare::builder(5, 3)
+            .with_config(config.clone())
+            .build()
+            .unwrap();
+
+        assert_eq!(shamir.config.chunk_size, 2048);
+        assert_eq!(shamir.config.mode, SplitMode::Parallel);
+        assert!(shamir.config.compression);
+        assert!(!shamir.config.integrity_check);
+    }
+
+    #[test]
+    fn test_split_stream_basic() {
+        use std::io::Cursor;
+
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let data = b"This is a test message for streaming functionality";
+        let mut source = Cursor::new(data);
+
+        // Create destination buffers
+        let mut destinations = vec![Vec::new(); 3];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        // Split the stream
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        // Extract the written data
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Verify that all shares have data
+        for share in &share_data {
+            assert!(!share.is_empty());
+        }
+
+        // Reconstruct using the first 2 shares (threshold = 2)
+        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(&destination, data);
+    }
+
+    #[test]
+    fn test_split_stream_with_custom_chunk_size() {
+        use std::io::Cursor;
+
+        let config = Config::new().with_chunk_size(32).unwrap(); // Small chunks for testing
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let data = b"This is a longer test message that will be split into multiple chunks";
+        let mut source = Cursor::new(data);
+
+        let mut destinations = vec![Vec::new(); 3];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Reconstruct
+        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(&destination, data);
+    }
+
+    #[test]
+    fn test_split_stream_without_integrity_check() {
+        use std::io::Cursor;
+
+        let config = Config::new()
+            .with_integrity_check(false)
+            .with_chunk_size(20)
+            .unwrap();
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let data = b"Test message without integrity checking";
+        let mut source = Cursor::new(data);
+
+        let mut destinations = vec![Vec::new(); 3];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Reconstruct
+        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(&destination, data);
+    }
+
+    #[test]
+    fn test_split_stream_empty_data() {
+        use std::io::Cursor;
+
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let data = b"";
+        let mut source = Cursor::new(data);
+
+        let mut destinations = vec![Vec::new(); 3];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // All shares should contain only the header for empty input
+        for share in &share_data {
+            assert_eq!(share.len(), STREAM_HEADER_LEN); // Only header, no chunk data
+        }
+
+        // Reconstruct should also produce empty data
+        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(&destination, data);
+    }
+
+    #[test]
+    fn test_split_stream_wrong_destination_count() {
+        use std::io::Cursor;
+
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let data = b"test";
+        let mut source = Cursor::new(data);
+
+        // Wrong number of destinations (2 instead of 3)
+        let mut destinations = vec![Vec::new(); 2];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        let result = shamir.split_stream(&mut source, &mut dest_cursors);
+        assert!(matches!(result, Err(ShamirError::InvalidConfig(_))));
+    }
+
+    #[test]
+    fn test_reconstruct_stream_insufficient_sources() {
+        use std::io::Cursor;
+
+        let mut sources: Vec<Cursor<Vec<u8>>> = vec![];
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        let result = ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor);
+        assert!(matches!(
+            result,
+            Err(ShamirError::InsufficientShares { .. })
+        ));
+    }
+
+    #[test]
+    fn test_stream_data_format() {
+        use std::io::Cursor;
+
+        let config = Config::new().with_chunk_size(32).unwrap(); // Smallest chunks with hashes
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        let data = &b"Hello World! ".repeat(6); // 78 bytes, will create 3 chunks (32, 32, 14)
+        let mut source = Cursor::new(data);
+
+        let mut destinations = vec![Vec::new(); 3];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Verify the data format: each share should have length prefixes after the header
+        for share in &share_data {
+            let mut cursor = Cursor::new(share);
+            let mut total_chunks = 0;
+
+            // Skip header (magic + version + flags + share index)
+            let mut header = [0u8; STREAM_HEADER_LEN];
+            cursor.read_exact(&mut header).unwrap();
+            assert_eq!(&header[..4], STREAM_MAGIC);
+            assert_eq!(header[4], STREAM_FORMAT_VERSION_NO_DIGEST);
+
+            // Read chunks until EOF
+            loop {
+                let mut length_bytes = [0u8; 4];
+                match cursor.read_exact(&mut length_bytes) {
+                    Ok(()) => {
+                        let length = u32::from_le_bytes(length_bytes) as usize;
+                        let mut chunk_data = vec![0u8; length];
+                        cursor.read_exact(&mut chunk_data).unwrap();
+                        total_chunks += 1;
+                    }
+                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
+                    Err(e) => panic!("Unexpected error: {}", e),
+                }
+            }
+
+            // Should have 3 chunks (32 + 32 + 14 bytes)
+            assert_eq!(total_chunks, 3);
+        }
+
+        // Reconstruct and verify
+        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(destination, *data);
+    }
+
+    #[test]
+    fn test_stream_integrity_check_detection() {
+        use std::io::Cursor;
+
+        // Test with integrity check enabled
+        let config_with_integrity = Config::new()
+            .with_integrity_check(true)
+            .with_chunk_size(32)
+            .unwrap();
+        let mut shamir_with_integrity = ShamirShare::builder(3, 2)
+            .with_config(config_with_integrity)
+            .build()
+            .unwrap();
+
+        // Test with integrity check disabled
+        let config_without_integrity = Config::new()
+            .with_integrity_check(false)
+            .with_chunk_size(32)
+            .unwrap();
+        let mut shamir_without_integrity = ShamirShare::builder(3, 2)
+            .with_config(config_without_integrity)
+            .build()
+            .unwrap();
+
+        let data = b"Test data for integrity checking";
+
+        // Split with integrity check
+        let mut source1 = Cursor::new(data);
+        let mut destinations1 = vec![Vec::new(); 3];
+        let mut dest_cursors1: Vec<Cursor<Vec<u8>>> = destinations1
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+        shamir_with_integrity
+            .split_stream(&mut source1, &mut dest_cursors1)
+            .unwrap();
+        let share_data_with_integrity: Vec<Vec<u8>> = dest_cursors1
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Split without integrity check
+        let mut source2 = Cursor::new(data);
+        let mut destinations2 = vec![Vec::new(); 3];
+        let mut dest_cursors2: Vec<Cursor<Vec<u8>>> = destinations2
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+        shamir_without_integrity
+            .split_stream(&mut source2, &mut dest_cursors2)
+            .unwrap();
+        let share_data_without_integrity: Vec<Vec<u8>> = dest_cursors2
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Shares with integrity check should be larger
+        assert!(share_data_with_integrity[0].len() > share_data_without_integrity[0].len());
+
+        // Both should reconstruct correctly
+        let mut sources1: Vec<Cursor<Vec<u8>>> = share_data_with_integrity[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination1 = Vec::new();
+        let mut dest_cursor1 = Cursor::new(&mut destination1);
+        ShamirShare::reconstruct_stream(&mut sources1, &mut dest_cursor1).unwrap();
+
+        let mut sources2: Vec<Cursor<Vec<u8>>> = share_data_without_integrity[0..2]
+            .iter()
+            .map(|data| Cursor::new(data.clone()))
+            .collect();
+        let mut destination2 = Vec::new();
+        let mut dest_cursor2 = Cursor::new(&mut destination2);
+        ShamirShare::reconstruct_stream(&mut sources2, &mut dest_cursor2).unwrap();
+
+        assert_eq!(&destination1, data);
+        assert_eq!(&destination2, data);
+    }
+
+    #[test]
+    fn test_stream_large_data() {
+        use std::io::Cursor;
+
+        let config = Config::new().with_chunk_size(1024).unwrap();
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        // Create a large test dataset
+        let data: Vec<u8> = (0..10000).map(|i| (i % 256) as u8).collect();
+        let mut source = Cursor::new(&data);
+
+        let mut destinations = vec![Vec::new(); 5];
+        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
+            .iter_mut()
+            .map(|d| Cursor::new(std::mem::take(d)))
+            .collect();
+
+        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+
+        let share_data: Vec<Vec<u8>> = dest_cursors
+            .into_iter()
+            .map(|cursor| cursor.into_inner())
+            .collect();
+
+        // Reconstruct using shares 0, 2, 4 (threshold = 3)
+        let mut sources: Vec<Cursor<Vec<u8>>> = vec![
+            Cursor::new(share_data[0].clone()),
+            Cursor::new(share_data[2].clone()),
+            Cursor::new(share_data[4].clone()),
+        ];
+        let mut destination = Vec::new();
+        let mut dest_cursor = Cursor::new(&mut destination);
+
+        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+
+        assert_eq!(&destination, &data);
+    }
+
+    #[test]
+    fn test_reconstruct_stream_from_paths() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths: Vec<std::path::PathBuf> = (1..=5)
+            .map(|i| temp_dir.path().join(format!("share_stream_{i}")))
+            .collect();
+
+        let config = Config::new().with_chunk_size(64).unwrap();
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let data: Vec<u8> = (0..1000).map(|i| (i % 256) as u8).collect();
+
+        let mut files: Vec<File> = paths.iter().map(|p| File::create(p).unwrap()).collect();
+        shamir
+            .split_stream(&mut std::io::Cursor::new(&data), &mut files)
+            .unwrap();
+        drop(files);
+
+        let mut output = Vec::new();
+        ShamirShare::reconstruct_stream_from_paths(&paths[2..5], &mut output).unwrap();
+        assert_eq!(output, data);
+
+        // A missing file is reported by its position in the path list
+        let missing = vec![
+            paths[0].clone(),
+            temp_dir.path().join("does_not_exist"),
+            paths[1].clone(),
+        ];
+        assert!(matches!(
+            ShamirShare::reconstruct_stream_from_paths(&missing, &mut Vec::new()),
+            Err(ShamirError::InvalidShareIndex(2))
+        ));
+
+        // Positions past 255 cannot be reported, so such lists are rejected up front
+        let too_many = vec![paths[0].clone(); 256];
+        assert!(matches!(
+            ShamirShare::reconstruct_stream_from_paths(&too_many, &mut Vec::new()),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_split_stream_with_stats_matches_file_sizes() {
+        let temp_dir = tempfile::tempdir().unwrap();
+        let paths: Vec<std::path::PathBuf> = (1..=3)
+            .map(|i| temp_dir.path().join(format!("share_stream_{i}")))
+            .collect();
+
+        let config = Config::new().with_chunk_size(64).unwrap();
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let data: Vec<u8> = (0..1000).map(|i| (i % 256) as u8).collect();
+
+        let mut files: Vec<File> = paths.iter().map(|p| File::create(p).unwrap()).collect();
+        let stats = shamir
+            .split_stream_with_stats(&mut std::io::Cursor::new(&data), &mut files)
+            .unwrap();
+        drop(files);
+
+        assert_eq!(stats.bytes_read, 1000);
+        assert_eq!(stats.chunks, 16); // 15 full chunks of 64 bytes and one of 40
+        let expected = STREAM_HEADER_LEN + 16 * (4 + HASH_SIZE) + 1000;
+        for (path, &written) in paths.iter().zip(&stats.bytes_written) {
+            assert_eq!(written, std::fs::metadata(path).unwrap().len());
+            assert_eq!(written, expected as u64);
+        }
+
+        let mut output = Vec::new();
+        ShamirShare::reconstruct_stream_from_paths(&paths[..2], &mut output).unwrap();
+        assert_eq!(output, data);
+    }
+
+    #[test]
+    fn test_deterministic_coefficients_reproducible() {
+        let config = Config::new().with_deterministic_coefficients(true);
+        let secret = b"idempotent backup";
+
+        // Separate instances, so no RNG state is shared between the two splits
+        let mut first = ShamirShare::builder(5, 3)
+            .with_config(config.clone())
+            .build()
+            .unwrap();
+        let mut second = ShamirShare::builder(5, 3)
+            .with_config(config.clone())
+            .build()
+            .unwrap();
+
+        let shares_a = first.split(secret).unwrap();
+        let shares_b = second.split(secret).unwrap();
+        assert_eq!(shares_a, shares_b);
+        assert_eq!(first.split(secret).unwrap(), shares_a);
+        assert_eq!(ShamirShare::reconstruct(&shares_a[1..4]).unwrap(), secret);
+
+        // Different secrets and thresholds give different polynomials
+        assert_ne!(first.split(b"idempotent backuP").unwrap()[0], shares_a[0]);
+        let mut other_threshold = ShamirShare::builder(5, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        assert_ne!(
+            other_threshold.split(secret).unwrap()[0].data,
+            shares_a[0].data
+        );
+
+        // The default mode stays randomized
+        let mut random = ShamirShare::builder(5, 3).build().unwrap();
+        assert_ne!(random.split(secret).unwrap(), random.split(secret).unwrap());
+    }
+
+    #[test]
+    fn test_reconstruct_strict_index_range() {
+        let secret = b"strict index range";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        assert_eq!(
+            ShamirShare::reconstruct_strict(&shares[2..5]).unwrap(),
+            secret
+        );
+
+        // A share whose index exceeds total_shares is rejected by the strict path only
+        let mut forged = shares[0..3].to_vec();
+        forged[1].index = 9;
+        assert!(matches!(
+            ShamirShare::reconstruct_strict(&forged),
+            Err(ShamirError::InvalidShareIndex(9))
+        ));
+        assert!(
+            ShamirShare::reconstruct(&forged)
+                .is_err_and(|e| { matches!(e, ShamirError::IntegrityCheckFailed) })
+        );
+
+        let mut zero = shares[0..3].to_vec();
+        zero[2].index = 0;
+        assert!(matches!(
+            ShamirShare::reconstruct_strict(&zero),
+            Err(ShamirError::InvalidShareIndex(0))
+        ));
+    }
+
+    #[test]
+    fn test_split_str_multibyte_unicode() {
+        let secrets = [
+            "",
+            "ascii only",
+            "Grüße aus Köln",
+            "日本語のパスワード",
+            "emoji 🔐🗝️ and combining e\u{301}",
+        ];
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+
+        for secret in secrets {
+            let shares = shamir.split_str(secret).unwrap();
+            assert_eq!(shares[0].data.len(), HASH_SIZE + secret.len());
+            assert_eq!(ShamirShare::reconstruct_str(&shares[2..5]).unwrap(), secret);
+        }
+    }
+
+    #[test]
+    fn test_reconstruct_str_invalid_utf8() {
+        // Without integrity checking, corruption reaches the UTF-8 decoding step
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut shares = shamir.split_str("ü").unwrap();
+
+        // Tamper with one share so the reconstructed first byte becomes 0x80, a lone
+        // continuation byte: scaling the change by 1/L_0 cancels the Lagrange weight
+        let original = ShamirShare::reconstruct(&shares[0..2]).unwrap();
+        let delta = original[0] ^ 0x80;
+        let coefficients = ShamirShare::compute_lagrange_coefficients(&shares[0..2]).unwrap();
+        let inverse = coefficients[0].inverse().unwrap();
+        shares[0].data[0] ^= (FiniteField::new(delta) * inverse).0;
+
+        assert!(matches!(
+            ShamirShare::reconstruct_str(&shares[0..2]),
+            Err(ShamirError::InvalidUtf8)
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_columns() {
+        let secret = b"secret stored column-wise";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(secret).unwrap();
+
+        // Columns in arbitrary order, as a database might return them
+        let indices: Vec<u8> = vec![shares[4].index, shares[0].index, shares[2].index];
+        let data: Vec<&[u8]> = vec![&shares[4].data, &shares[0].data, &shares[2].data];
+
+        let reconstructed =
+            ShamirShare::reconstruct_columns(&indices, &data, 3, true, false).unwrap();
+        assert_eq!(reconstructed, secret);
+
+        // Matches the row-based API
+        assert_eq!(
+            reconstructed,
+            ShamirShare::reconstruct(&[shares[4].clone(), shares[0].clone(), shares[2].clone()])
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn test_reconstruct_columns_validation() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(b"columns").unwrap();
+        let indices: Vec<u8> = shares.iter().map(|s| s.index).collect();
+        let data: Vec<&[u8]> = shares.iter().map(|s| s.data.as_slice()).collect();
+
+        assert!(matches!(
+            ShamirShare::reconstruct_columns(&indices[..3], &data[..4], 3, true, false),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_columns(&indices[..2], &data[..2], 3, true, false),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
+
+        let truncated: Vec<&[u8]> = vec![data[0], data[1], &data[2][1..]];
+        assert!(matches!(
+            ShamirShare::reconstruct_columns(&indices[..3], &truncated, 3, true, false),
+            Err(ShamirError::InconsistentShareLength)
+        ));
+
+        // With the integrity flag unset, the hash prefix is returned as part of the data
+        assert!(
+            ShamirShare::reconstruct_columns(&indices[..3], &data[..3], 3, false, false)
+                .is_ok_and(|data| data.len() == 32 + b"columns".len())
+        );
+    }
+
+    #[test]
+    fn test_verify_consistency() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(6, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = shamir.split(b"consistency without hashes").unwrap();
 
-        // Increment x for next share, wrapping to 0 when we reach 256 (which stops iteration)
-        self.current_x = self.current_x.wrapping_add(1);
+        // All six shares lie on the same polynomial, in any order
+        assert!(ShamirShare::verify_consistency(&shares).unwrap());
+        let mut reordered = shares.clone();
+        reordered.reverse();
+        assert!(ShamirShare::verify_consistency(&reordered).unwrap());
 
-        Some(share)
+        // Exactly threshold shares cannot be contradicted
+        assert!(ShamirShare::verify_consistency(&shares[0..3]).unwrap());
     }
 
-    /// Returns the number of remaining shares that can be generated
-    ///
-    /// This provides a size hint for the iterator, which can be useful for
-    /// pre-allocating collections or progress tracking.
-    fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = if self.current_x == 0 {
-            0
-        } else {
-            256 - self.current_x as usize
-        };
-        (remaining, Some(remaining))
-    }
-}
+    #[test]
+    fn test_verify_consistency_detects_off_curve_share() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut shares = shamir.split(b"tamper evident").unwrap();
+
+        // Tamper with a share that is not part of the defining subset
+        shares[3].data[5] ^= 0x80;
+        assert!(!ShamirShare::verify_consistency(&shares).unwrap());
+
+        // A share from a different split is also off-curve
+        let other = shamir.split(b"tamper evident").unwrap();
+        let mixed = vec![
+            other[0].clone(),
+            other[1].clone(),
+            other[2].clone(),
+            shares[4].clone(),
+        ];
+        assert!(!ShamirShare::verify_consistency(&mixed).unwrap());
 
-impl ExactSizeIterator for Dealer {
-    /// Returns the exact number of remaining shares
-    fn len(&self) -> usize {
-        self.size_hint().0
+        // Shares that disagree on their parameters are rejected before any comparison
+        let mut refreshed = shares.clone();
+        refreshed[4].refresh_generation += 1;
+        assert!(matches!(
+            ShamirShare::verify_consistency(&refreshed),
+            Err(ShamirError::MixedGenerations)
+        ));
+        let mut truncated = shares.clone();
+        truncated[4].data.pop();
+        assert!(matches!(
+            ShamirShare::verify_consistency(&truncated),
+            Err(ShamirError::InconsistentShareMetadata {
+                field: "data length"
+            })
+        ));
+
+        assert!(matches!(
+            ShamirShare::verify_consistency(&shares[0..2]),
+            Err(ShamirError::InsufficientShares { .. })
+        ));
     }
-}
 
-#[cfg(test)]
-mod tests {
-    use super::*;
+    /// Splits `data` into stream shares using a small chunk size
+    fn split_stream_for_test(data: &[u8], chunk_size: usize) -> Vec<Vec<u8>> {
+        let config = Config::new().with_chunk_size(chunk_size).unwrap();
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut outputs = vec![std::io::Cursor::new(Vec::new()); 3];
+        shamir
+            .split_stream(&mut std::io::Cursor::new(data), &mut outputs)
+            .unwrap();
+        outputs.into_iter().map(|c| c.into_inner()).collect()
+    }
 
     #[test]
-    fn test_split_and_reconstruct() {
-        let secret = b"Hello, World!";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+    fn test_reconstruct_stream_lenient_stops_at_corrupt_chunk() {
+        let data = [[b'a'; 32], [b'b'; 32], [b'c'; 32]].concat();
+        let mut streams = split_stream_for_test(&data, 32);
 
-        // Split the secret
-        let shares = shamir.split(secret).unwrap();
-        assert_eq!(shares.len(), 5);
+        // Each chunk is a 4-byte length + 32-byte hash + 32 data bytes
+        let chunk_len = 4 + HASH_SIZE + 32;
+        streams[1][STREAM_HEADER_LEN + chunk_len + 4 + HASH_SIZE + 3] ^= 0x55;
 
-        // Reconstruct with exactly threshold shares
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // Strict reconstruction fails outright
+        let mut sources: Vec<_> = streams[..2].iter().map(std::io::Cursor::new).collect();
+        assert!(ShamirShare::reconstruct_stream(&mut sources, &mut Vec::new()).is_err());
 
-        // Reconstruct with more than threshold shares
-        let reconstructed = ShamirShare::reconstruct(&shares[1..5]).unwrap();
-        assert_eq!(&reconstructed, secret);
-    }
+        let mut sources: Vec<_> = streams[..2].iter().map(std::io::Cursor::new).collect();
+        let mut recovered = Vec::new();
+        let result = ShamirShare::reconstruct_stream_lenient(&mut sources, &mut recovered, true);
 
-    #[test]
-    fn test_invalid_parameters() {
-        assert!(ShamirShare::builder(0, 1).build().is_err());
-        assert!(ShamirShare::builder(1, 0).build().is_err());
-        assert!(ShamirShare::builder(3, 4).build().is_err());
+        assert!(matches!(
+            result,
+            Err(ShamirError::ChunkIntegrityFailed {
+                chunk_index: 1,
+                recovered_chunks: 1
+            })
+        ));
+        assert_eq!(recovered, &data[..32]);
     }
 
     #[test]
-    fn test_insufficient_shares() {
-        let secret = b"Test";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let shares = shamir.split(secret).unwrap();
+    fn test_stream_header_version() {
+        let data = [[b'v'; 32], [b'w'; 32]].concat();
+        let streams = split_stream_for_test(&data, 32);
+        assert_eq!(&streams[0][..4], STREAM_MAGIC);
+        assert_eq!(streams[0][4], STREAM_FORMAT_VERSION_NO_DIGEST);
+
+        // A stream from a future format version is rejected, not misparsed
+        let mut future = streams.clone();
+        future[1][4] = STREAM_FORMAT_VERSION + 1;
+        let mut sources: Vec<_> = future[..2].iter().map(std::io::Cursor::new).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_stream(&mut sources, &mut Vec::new()),
+            Err(ShamirError::UnsupportedVersion {
+                found,
+                max_supported: STREAM_FORMAT_VERSION,
+            }) if found == STREAM_FORMAT_VERSION + 1
+        ));
 
-        assert!(ShamirShare::reconstruct(&shares[0..2]).is_err());
+        // A damaged magic is a format error
+        let mut damaged = streams.clone();
+        damaged[0][2] = b'X';
+        let mut sources: Vec<_> = damaged[..2].iter().map(std::io::Cursor::new).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_stream(&mut sources, &mut Vec::new()),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        // Streams written before the magic was added start with the flags byte
+        let legacy: Vec<Vec<u8>> = streams
+            .iter()
+            .map(|stream| stream[STREAM_MAGIC.len() + 1..].to_vec())
+            .collect();
+        let mut sources: Vec<_> = legacy[1..].iter().map(std::io::Cursor::new).collect();
+        let mut reconstructed = Vec::new();
+        ShamirShare::reconstruct_stream(&mut sources, &mut reconstructed).unwrap();
+        assert_eq!(reconstructed, data);
     }
 
     #[test]
-    fn test_different_share_combinations() {
-        let secret = b"Different combinations test";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let shares = shamir.split(secret).unwrap();
+    fn test_two_point_reconstruction_matches_lagrange() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(255, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let secret: Vec<u8> = (0..=255).collect();
+        let shares = shamir.split(&secret).unwrap();
+
+        let lagrange = |pair: &[Share]| {
+            let coefficients = ShamirShare::compute_lagrange_coefficients(pair).unwrap();
+            (0..secret.len())
+                .map(|byte_idx| {
+                    pair.iter()
+                        .zip(&coefficients)
+                        .fold(FiniteField::new(0), |acc, (share, &coeff)| {
+                            acc + coeff * FiniteField::new(share.data[byte_idx])
+                        })
+                        .0
+                })
+                .collect::<Vec<u8>>()
+        };
 
-        // Try different combinations of 3 shares
-        let combinations = vec![vec![0, 1, 2], vec![1, 2, 3], vec![2, 3, 4], vec![0, 2, 4]];
+        for (i, j) in [(0, 1), (1, 0), (0, 254), (6, 200), (127, 128), (253, 254)] {
+            let pair = [shares[i].clone(), shares[j].clone()];
+            // Both the serial and the parallel branch
+            for parallel_threshold in [0, usize::MAX] {
+                let fast =
+                    ShamirShare::reconstruct_two_point(&pair[0], &pair[1], parallel_threshold)
+                        .unwrap();
+                assert_eq!(fast, lagrange(&pair));
+                assert_eq!(fast, secret);
+            }
+        }
 
-        for combo in combinations {
-            let selected_shares: Vec<Share> = combo.iter().map(|&i| shares[i].clone()).collect();
+        // Large secrets take the parallel branch unless configured otherwise
+        let large: Vec<u8> = (0..DEFAULT_PARALLEL_THRESHOLD * 2)
+            .map(|i| i as u8)
+            .collect();
+        let shares = shamir.split_bounded(&large).take(2).collect::<Vec<_>>();
+        assert_eq!(ShamirShare::reconstruct(&shares).unwrap(), large);
+        let serial = Config::new().with_parallel_threshold(usize::MAX);
+        assert_eq!(
+            ShamirShare::reconstruct_with_config(&shares, &serial).unwrap(),
+            large
+        );
 
-            let reconstructed = ShamirShare::reconstruct(&selected_shares).unwrap();
-            assert_eq!(&reconstructed, secret);
-        }
+        assert!(matches!(
+            ShamirShare::reconstruct_two_point(&shares[0], &shares[0], 0),
+            Err(ShamirError::InvalidShareFormat)
+        ));
     }
 
     #[test]
-    fn test_empty_secret() {
-        let secret = b"";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let shares = shamir.split(secret).unwrap();
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(reconstructed, secret);
+    fn test_shares_consistent_triples() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = shamir.split(b"three on a line").unwrap();
+
+        let triple = shares[1..4].to_vec();
+        assert!(ShamirShare::shares_consistent(&triple).unwrap());
+
+        // Tampering with any member of the triple takes it off the line
+        for position in 0..3 {
+            let mut tampered = triple.clone();
+            tampered[position].data[2] ^= 0x20;
+            assert!(!ShamirShare::shares_consistent(&tampered).unwrap());
+        }
+
+        // A share from another split of the same secret is not on the line either
+        let other = shamir.split(b"three on a line").unwrap();
+        let mixed = vec![shares[0].clone(), shares[1].clone(), other[2].clone()];
+        assert!(!ShamirShare::shares_consistent(&mixed).unwrap());
+
+        // Two shares define the line but leave nothing to check
+        assert!(matches!(
+            ShamirShare::shares_consistent(&shares[..2]),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
+        assert!(matches!(
+            ShamirShare::shares_consistent(&[]),
+            Err(ShamirError::InsufficientShares { needed: 1, got: 0 })
+        ));
     }
 
     #[test]
-    fn test_single_byte_secret() {
-        let secret = b"x";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let shares = shamir.split(secret).unwrap();
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(reconstructed, secret);
+    fn test_verify_with_parity_detects_single_corrupted_share() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(4, 4)
+            .with_config(config)
+            .build()
+            .unwrap();
+
+        // One parity share detects corruption but cannot say where it is
+        let mut shares = shamir.split_with_parity(b"parity secret", 1).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(ShamirShare::verify_with_parity(&shares).unwrap().is_empty());
+        shares[2].data[3] ^= 0x01;
+        assert!(matches!(
+            ShamirShare::verify_with_parity(&shares),
+            Err(ShamirError::DataCorruption { corrupted_bytes: 1 })
+        ));
+
+        // Two parity shares locate it, whether in a data or a parity share
+        let shares = shamir.split_with_parity(b"parity secret", 2).unwrap();
+        assert!(shares.iter().all(|s| s.total_shares == 6));
+        assert_eq!(
+            ShamirShare::reconstruct_strict(&shares[2..]).unwrap(),
+            b"parity secret"
+        );
+        for position in 0..shares.len() {
+            let mut corrupted = shares.clone();
+            corrupted[position].data[7] ^= 0xA5;
+            corrupted[position].data[0] ^= 0x01;
+            assert_eq!(
+                ShamirShare::verify_with_parity(&corrupted).unwrap(),
+                vec![shares[position].index]
+            );
+        }
+
+        // Non-overlapping corruption in two shares is located per byte position
+        let mut corrupted = shares.clone();
+        corrupted[0].data[1] ^= 0x01;
+        corrupted[5].data[2] ^= 0x01;
+        assert_eq!(
+            ShamirShare::verify_with_parity(&corrupted).unwrap(),
+            vec![1, 6]
+        );
+
+        // Two corrupted shares at one byte position exceed what two parity shares locate
+        let mut corrupted = shares.clone();
+        corrupted[0].data[1] ^= 0x01;
+        corrupted[1].data[1] ^= 0x02;
+        assert!(matches!(
+            ShamirShare::verify_with_parity(&corrupted),
+            Err(ShamirError::DataCorruption { .. })
+        ));
+
+        assert!(matches!(
+            ShamirShare::verify_with_parity(&shares[..4]),
+            Err(ShamirError::InsufficientShares { needed: 5, got: 4 })
+        ));
+        assert!(shamir.split_with_parity(b"x", 252).is_err());
     }
 
     #[test]
-    fn test_max_shares() {
-        let secret = b"Maximum shares test";
-        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
-        let shares = shamir.split(secret).unwrap();
-        assert_eq!(shares.len(), 255);
+    fn test_truncated_threshold_one_share() {
+        let mut shamir = ShamirShare::builder(3, 1).build().unwrap();
+        let shares = shamir.split(b"single share secret").unwrap();
+        assert_eq!(
+            ShamirShare::reconstruct(&shares[1..2]).unwrap(),
+            b"single share secret"
+        );
 
-        let reconstructed = ShamirShare::reconstruct(&shares[0..128]).unwrap();
-        assert_eq!(reconstructed, secret);
+        // Cut into the integrity tag
+        let mut truncated = shares[1].clone();
+        truncated.data.truncate(HASH_SIZE - 1);
+        assert!(matches!(
+            ShamirShare::reconstruct(&[truncated]),
+            Err(ShamirError::ShareTooShort {
+                length,
+                minimum: HASH_SIZE
+            }) if length == HASH_SIZE - 1
+        ));
+
+        // Cut into the secret only: the tag is intact but no longer matches
+        let mut truncated = shares[1].clone();
+        truncated.data.truncate(HASH_SIZE + 4);
+        assert!(matches!(
+            ShamirShare::reconstruct(&[truncated]),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
     }
 
     #[test]
-    fn test_duplicate_share_indices() {
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let shares = shamir.split(b"test").unwrap();
+    fn test_length_check_detects_truncation() {
+        let config = Config::new()
+            .with_integrity_check(false)
+            .with_length_check(true);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let secret = b"exactly this long";
+        let shares = shamir.split(secret).unwrap();
+        assert!(shares.iter().all(|s| s.length_check));
+        assert_eq!(shares[0].data.len(), LENGTH_PREFIX_SIZE + secret.len());
+        assert_eq!(ShamirShare::reconstruct(&shares[..3]).unwrap(), secret);
 
-        let mut corrupted_shares = shares[0..3].to_vec();
-        corrupted_shares[1].index = corrupted_shares[0].index; // Duplicate index
+        // The flag survives the binary format
+        let decoded = Share::from_bytes(&shares[0].to_bytes()).unwrap();
+        assert!(decoded.length_check);
 
+        // Shares cut short by the same amount reconstruct to a shorter secret
+        let truncated: Vec<Share> = shares[..3]
+            .iter()
+            .map(|share| {
+                let mut share = share.clone();
+                share.data.truncate(share.data.len() - 3);
+                share
+            })
+            .collect();
         assert!(matches!(
-            ShamirShare::reconstruct(&corrupted_shares),
-            Err(ShamirError::InvalidShareFormat)
+            ShamirShare::reconstruct(&truncated),
+            Err(ShamirError::SecretLengthMismatch {
+                expected: 17,
+                actual: 14
+            })
         ));
+
+        // Without the length check the same truncation goes unnoticed
+        let mut unchecked = ShamirShare::builder(5, 3)
+            .with_config(Config::new().with_integrity_check(false))
+            .build()
+            .unwrap();
+        let mut shares = unchecked.split(secret).unwrap();
+        for share in &mut shares {
+            share.data.truncate(share.data.len() - 3);
+        }
+        assert_eq!(
+            ShamirShare::reconstruct(&shares[..3]).unwrap(),
+            &secret[..14]
+        );
     }
 
     #[test]
-    fn test_corrupted_share_data() {
+    fn test_reconstruct_map() {
         let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-        let mut shares = shamir.split(b"test").unwrap();
+        let secret = b"keyed by participant";
+        let mut shares: HashMap<u8, Share> = shamir
+            .split(secret)
+            .unwrap()
+            .into_iter()
+            .map(|share| (share.index, share))
+            .collect();
 
-        // Corrupt one byte in a share
-        if shares[0].data[0] == 0 {
-            shares[0].data[0] = 1;
-        } else {
-            shares[0].data[0] = 0;
-        }
+        // All five entries are used, two more than the threshold
+        assert_eq!(ShamirShare::reconstruct_map(&shares).unwrap(), secret);
+        assert_eq!(
+            ShamirShare::reconstruct_map_subset(&shares, &[2, 3, 5]).unwrap(),
+            secret
+        );
+        assert!(matches!(
+            ShamirShare::reconstruct_map_subset(&shares, &[2, 3, 7]),
+            Err(ShamirError::InvalidShareIndex(7))
+        ));
 
+        // A share filed under the wrong key is rejected
+        let misfiled = shares.remove(&4).unwrap();
+        shares.insert(6, misfiled);
         assert!(matches!(
-            ShamirShare::reconstruct(&shares[0..3]),
-            Err(ShamirError::IntegrityCheckFailed)
+            ShamirShare::reconstruct_map(&shares),
+            Err(ShamirError::InvalidShareIndex(6))
         ));
+        assert_eq!(
+            ShamirShare::reconstruct_map_subset(&shares, &[1, 2, 3]).unwrap(),
+            secret
+        );
     }
 
     #[test]
-    fn test_builder_pattern() {
-        // Test basic builder usage
-        let shamir = ShamirShare::builder(5, 3).build().unwrap();
-        assert_eq!(shamir.total_shares, 5);
-        assert_eq!(shamir.threshold, 3);
-        assert!(shamir.config.integrity_check); // Default should be true
+    fn test_audit_shares_reports_missing_and_duplicated() {
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = shamir.split(b"audited secret").unwrap();
+
+        let complete = ShamirShare::audit_shares(&shares);
+        assert!(complete.is_complete());
+        assert_eq!(complete.total_shares, 5);
+        assert_eq!(complete.present, vec![1, 2, 3, 4, 5]);
+
+        // Share 4 was lost and share 2 handed out twice
+        let distributed = vec![
+            shares[0].clone(),
+            shares[1].clone(),
+            shares[2].clone(),
+            shares[1].clone(),
+            shares[4].clone(),
+        ];
+        let audit = ShamirShare::audit_shares(&distributed);
+        assert!(!audit.is_complete());
+        assert_eq!(audit.present, vec![1, 2, 3, 5]);
+        assert_eq!(audit.missing, vec![4]);
+        assert_eq!(audit.duplicated, vec![2]);
+        assert!(audit.unexpected.is_empty());
+
+        let mut stray = shares[0].clone();
+        stray.index = 9;
+        let audit = ShamirShare::audit_shares(&[shares[0].clone(), stray]);
+        assert_eq!(audit.unexpected, vec![9]);
+        assert_eq!(audit.missing, vec![2, 3, 4, 5]);
+
+        assert_eq!(ShamirShare::audit_shares(&[]), ShareAudit::default());
+    }
 
-        // Test builder with custom config
-        let config = Config::new().with_integrity_check(false);
-        let shamir = ShamirShare::builder(7, 4)
+    #[test]
+    fn test_stream_big_endian_lengths() {
+        let data = [[b'n'; 40], [b'e'; 40]].concat();
+        let config = Config::new()
+            .with_chunk_size(40)
+            .unwrap()
+            .with_big_endian_lengths(true);
+        let mut shamir = ShamirShare::builder(3, 2)
             .with_config(config)
             .build()
             .unwrap();
-        assert_eq!(shamir.total_shares, 7);
-        assert_eq!(shamir.threshold, 4);
-        assert!(!shamir.config.integrity_check);
+        let mut outputs = vec![std::io::Cursor::new(Vec::new()); 3];
+        shamir
+            .split_stream(&mut std::io::Cursor::new(&data), &mut outputs)
+            .unwrap();
+        let streams: Vec<Vec<u8>> = outputs.into_iter().map(|c| c.into_inner()).collect();
+
+        // The header records the byte order and the first length prefix uses it
+        assert_eq!(streams[0][5] & 8, 8);
+        let first_length = &streams[0][STREAM_HEADER_LEN..STREAM_HEADER_LEN + 4];
+        assert_eq!(first_length, ((HASH_SIZE + 40) as u32).to_be_bytes());
+
+        let mut sources: Vec<_> = streams[1..].iter().map(std::io::Cursor::new).collect();
+        let mut reconstructed = Vec::new();
+        ShamirShare::reconstruct_stream(&mut sources, &mut reconstructed).unwrap();
+        assert_eq!(reconstructed, data);
+
+        // Sources written in different byte orders are not combined
+        let little_endian = split_stream_for_test(&data, 40);
+        assert_eq!(little_endian[0][5] & 8, 0);
+        let mut sources = vec![
+            std::io::Cursor::new(&streams[0]),
+            std::io::Cursor::new(&little_endian[1]),
+        ];
+        assert!(matches!(
+            ShamirShare::reconstruct_stream(&mut sources, &mut Vec::new()),
+            Err(ShamirError::InvalidConfig(_))
+        ));
     }
 
+    #[cfg(feature = "encrypt")]
     #[test]
-    fn test_builder_validation() {
-        // Test invalid parameters through builder
-        assert!(ShamirShare::builder(0, 1).build().is_err());
-        assert!(ShamirShare::builder(1, 0).build().is_err());
-        assert!(ShamirShare::builder(3, 5).build().is_err());
-
-        // Test invalid config
-        let invalid_config = Config::new().with_chunk_size(0).unwrap_err();
-        assert!(matches!(invalid_config, ShamirError::InvalidConfig(_)));
-    }
+    fn test_reconstruct_stream_encrypted_round_trip() {
+        use chacha20poly1305::ChaCha20Poly1305;
+        use chacha20poly1305::aead::KeyInit;
+        use chacha20poly1305::aead::stream::DecryptorBE32;
+        use std::io::Cursor;
 
-    #[test]
-    fn test_integrity_check_disabled() {
-        let config = Config::new().with_integrity_check(false);
-        let mut shamir = ShamirShare::builder(5, 3)
+        let decrypt = |mut encrypted: &[u8], key: &[u8; 32], nonce: &[u8; 7]| {
+            let mut records = Vec::new();
+            while let Some((len, rest)) = encrypted.split_first_chunk::<4>() {
+                let (record, rest) = rest.split_at(u32::from_le_bytes(*len) as usize);
+                records.push(record);
+                encrypted = rest;
+            }
+            let (last, chunks) = records.split_last()?;
+            let cipher = ChaCha20Poly1305::new(key.into());
+            let mut decryptor = DecryptorBE32::from_aead(cipher, nonce.into());
+            let mut plaintext = Vec::new();
+            for chunk in chunks {
+                plaintext.extend(decryptor.decrypt_next(*chunk).ok()?);
+            }
+            plaintext.extend(decryptor.decrypt_last(*last).ok()?);
+            Some(plaintext)
+        };
+
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 251) as u8).collect();
+        let config = Config::new().with_chunk_size(1024).unwrap();
+        let mut shamir = ShamirShare::builder(4, 3)
             .with_config(config)
             .build()
             .unwrap();
+        let mut outputs = vec![Cursor::new(Vec::new()); 4];
+        shamir
+            .split_stream(&mut Cursor::new(&data), &mut outputs)
+            .unwrap();
 
-        let secret = b"test secret without integrity check";
-        let shares = shamir.split(secret).unwrap();
+        let (key, nonce) = ([0x42u8; 32], *b"unique!");
+        let mut sources: Vec<_> = outputs[1..]
+            .iter()
+            .map(|c| Cursor::new(c.get_ref()))
+            .collect();
+        let mut encrypted = Vec::new();
+        ShamirShare::reconstruct_stream_encrypted(&mut sources, &mut encrypted, &key, &nonce)
+            .unwrap();
 
-        // Verify shares have integrity_check = false
-        assert!(!shares[0].integrity_check);
+        // Ten data records and the final record; the plaintext never appears in the output
+        assert!(!encrypted.windows(64).any(|w| w == &data[..64]));
+        assert_eq!(decrypt(&encrypted, &key, &nonce).unwrap(), data);
 
-        // Reconstruct should work
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // Wrong keys, nonces and dropped final records are all rejected
+        assert!(decrypt(&encrypted, &[0x43; 32], &nonce).is_none());
+        assert!(decrypt(&encrypted, &key, b"other!!").is_none());
+        assert!(decrypt(&encrypted[..encrypted.len() - 20], &key, &nonce).is_none());
+    }
 
-        // Data should be smaller since no hash is prepended
-        let mut shamir_with_integrity = ShamirShare::builder(5, 3).build().unwrap();
-        let shares_with_integrity = shamir_with_integrity.split(secret).unwrap();
+    #[test]
+    fn test_stream_digest_trailer() {
+        let data = [[b'd'; 40], [b'g'; 40]].concat();
+        let split = |data: &[u8], compression: bool| {
+            let config = Config {
+                compression,
+                ..Config::new()
+                    .with_chunk_size(40)
+                    .unwrap()
+                    .with_stream_digest(true)
+            };
+            let mut shamir = ShamirShare::builder(3, 2)
+                .with_config(config)
+                .build()
+                .unwrap();
+            let mut outputs = vec![std::io::Cursor::new(Vec::new()); 3];
+            shamir
+                .split_stream(&mut std::io::Cursor::new(data), &mut outputs)
+                .unwrap();
+            outputs
+                .into_iter()
+                .map(|c| c.into_inner())
+                .collect::<Vec<_>>()
+        };
+        let reconstruct = |streams: &[Vec<u8>]| {
+            let mut sources: Vec<_> = streams.iter().map(std::io::Cursor::new).collect();
+            let mut reconstructed = Vec::new();
+            ShamirShare::reconstruct_stream(&mut sources, &mut reconstructed)
+                .map(|()| reconstructed)
+        };
+        // The trailer is the final record: an empty marker, then the digest share
+        let trailer_digest = |streams: &[Vec<u8>]| {
+            let trailers: Vec<Vec<u8>> = streams
+                .iter()
+                .map(|stream| {
+                    let (rest, trailer) = stream.split_at(stream.len() - HASH_SIZE);
+                    assert!(rest.ends_with(&[0, 0, 0, 0, HASH_SIZE as u8, 0, 0, 0]));
+                    trailer.to_vec()
+                })
+                .collect();
+            let mut buffer = Vec::new();
+            ShamirShare::reconstruct_stream_chunk(&[1, 2], &trailers, &mut buffer)
+                .unwrap()
+                .to_vec()
+        };
 
-        // Shares without integrity check should be smaller
-        assert!(shares[0].data.len() < shares_with_integrity[0].data.len());
+        let streams = split(&data, false);
+        assert_eq!(streams[0][5] & 16, 16);
+        assert_eq!(streams[0][4], STREAM_FORMAT_VERSION);
         assert_eq!(
-            shares_with_integrity[0].data.len() - shares[0].data.len(),
-            HASH_SIZE
+            trailer_digest(&streams[..2]),
+            Sha256::digest(&data).to_vec()
         );
-    }
+        assert_eq!(reconstruct(&streams[1..]).unwrap(), data);
 
-    #[test]
-    fn test_integrity_check_enabled() {
-        let config = Config::new().with_integrity_check(true);
-        let mut shamir = ShamirShare::builder(5, 3)
-            .with_config(config)
-            .build()
-            .unwrap();
+        // The digest covers the plaintext, not the compressed chunks
+        #[cfg(feature = "compress")]
+        {
+            let compressed = split(&data, true);
+            assert_eq!(
+                trailer_digest(&compressed[..2]),
+                Sha256::digest(&data).to_vec()
+            );
+            assert_eq!(reconstruct(&compressed[..2]).unwrap(), data);
+        }
 
-        let secret = b"test secret with integrity check";
-        let shares = shamir.split(secret).unwrap();
+        // Swapping the two chunks in every stream passes each chunk hash but not the digest
+        let record_len = 4 + HASH_SIZE + 40;
+        let reordered: Vec<Vec<u8>> = streams
+            .iter()
+            .map(|stream| {
+                let (header, rest) = stream.split_at(STREAM_HEADER_LEN);
+                let (first, rest) = rest.split_at(record_len);
+                let (second, trailer) = rest.split_at(record_len);
+                [header, second, first, trailer].concat()
+            })
+            .collect();
+        assert!(matches!(
+            reconstruct(&reordered[..2]),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
 
-        // Verify shares have integrity_check = true
-        assert!(shares[0].integrity_check);
+        // Streams cut off before the trailer are rejected
+        let truncated: Vec<Vec<u8>> = streams
+            .iter()
+            .map(|stream| stream[..STREAM_HEADER_LEN + 2 * record_len].to_vec())
+            .collect();
+        assert!(matches!(
+            reconstruct(&truncated[..2]),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
 
-        // Reconstruct should work
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+    #[test]
+    fn test_reconstruct_stream_checkpointed_resumes() {
+        let data: Vec<u8> = (0..170u32).map(|i| (i * 7) as u8).collect();
+        for stream_digest in [false, true] {
+            let config = Config::new()
+                .with_chunk_size(32)
+                .unwrap()
+                .with_stream_digest(stream_digest);
+            let mut shamir = ShamirShare::builder(3, 2)
+                .with_config(config)
+                .build()
+                .unwrap();
+            let mut outputs = vec![std::io::Cursor::new(Vec::new()); 3];
+            shamir
+                .split_stream(&mut std::io::Cursor::new(&data), &mut outputs)
+                .unwrap();
+            let sources = || -> Vec<_> {
+                outputs[1..]
+                    .iter()
+                    .map(|c| std::io::Cursor::new(c.get_ref()))
+                    .collect()
+            };
 
-        // Corruption should be detected
-        let mut corrupted_shares = shares[0..3].to_vec();
-        if corrupted_shares[0].data[0] == 0 {
-            corrupted_shares[0].data[0] = 1;
-        } else {
-            corrupted_shares[0].data[0] = 0;
+            // One pass, recording every checkpoint
+            let mut checkpoints = Vec::new();
+            let mut single = Vec::new();
+            let finished = ShamirShare::reconstruct_stream_checkpointed(
+                &mut sources(),
+                &mut single,
+                None,
+                2,
+                |c| {
+                    checkpoints.push(*c);
+                    Ok(())
+                },
+            )
+            .unwrap();
+            assert_eq!(single, data);
+            assert_eq!(finished.chunks, 6);
+            assert_eq!(finished.bytes_written, data.len() as u64);
+            assert_eq!(
+                checkpoints.iter().map(|c| c.chunks).collect::<Vec<_>>(),
+                vec![2, 4, 6]
+            );
+
+            // Interrupted after the second checkpoint, then resumed
+            let mut saved = ReconstructionCheckpoint::default();
+            let mut resumed = Vec::new();
+            let interrupted = ShamirShare::reconstruct_stream_checkpointed(
+                &mut sources(),
+                &mut resumed,
+                None,
+                2,
+                |c| {
+                    saved = *c;
+                    if c.chunks == 4 {
+                        return Err(ShamirError::InvalidConfig("interrupted".into()));
+                    }
+                    Ok(())
+                },
+            );
+            assert!(interrupted.is_err());
+            assert_eq!(saved, checkpoints[1]);
+            assert_eq!(saved.source_offset, 4 * (4 + HASH_SIZE as u64 + 32));
+            resumed.truncate(saved.bytes_written as usize);
+
+            let finished_again = ShamirShare::reconstruct_stream_checkpointed(
+                &mut sources(),
+                &mut resumed,
+                Some(&saved),
+                2,
+                |_| Ok(()),
+            )
+            .unwrap();
+            assert_eq!(resumed, single);
+            assert_eq!(finished_again, finished);
         }
 
         assert!(matches!(
-            ShamirShare::reconstruct(&corrupted_shares),
-            Err(ShamirError::IntegrityCheckFailed)
+            ShamirShare::reconstruct_stream_checkpointed(
+                &mut [std::io::Cursor::new(Vec::new())],
+                &mut Vec::new(),
+                None,
+                0,
+                |_| Ok(())
+            ),
+            Err(ShamirError::InvalidConfig(_))
         ));
     }
 
     #[test]
-    fn test_mixed_integrity_check_shares() {
-        // Create shares with integrity check enabled
-        let config_with_integrity = Config::new().with_integrity_check(true);
-        let mut shamir_with_integrity = ShamirShare::builder(5, 3)
-            .with_config(config_with_integrity)
-            .build()
-            .unwrap();
+    fn test_reconstruct_stream_realign_skips_leading_record() {
+        let data = [[b'x'; 32], [b'y'; 32], [b'z'; 32]].concat();
+        let streams = split_stream_for_test(&data, 32);
+
+        // Aligned streams are reconstructed as they are
+        let mut sources: Vec<_> = streams.iter().map(std::io::Cursor::new).collect();
+        let mut output = Vec::new();
+        let skipped = ShamirShare::reconstruct_stream_realign(&mut sources, &mut output).unwrap();
+        assert_eq!(skipped, None);
+        assert_eq!(output, data);
+
+        // A copy of a real chunk record has the same length as the others
+        let record_len = 4 + HASH_SIZE + 32;
+        let mut shifted = streams.clone();
+        let stray = streams[2][STREAM_HEADER_LEN + record_len..][..record_len].to_vec();
+        shifted[2].splice(STREAM_HEADER_LEN..STREAM_HEADER_LEN, stray);
+
+        let mut sources: Vec<_> = shifted[1..].iter().map(std::io::Cursor::new).collect();
+        assert!(ShamirShare::reconstruct_stream(&mut sources, &mut Vec::new()).is_err());
+
+        let mut sources: Vec<_> = shifted.iter().map(std::io::Cursor::new).collect();
+        let mut output = Vec::new();
+        let skipped = ShamirShare::reconstruct_stream_realign(&mut sources, &mut output).unwrap();
+        assert_eq!(skipped, Some(2));
+        assert_eq!(output, data);
+
+        // Two offset streams cannot be told apart from one missing a record
+        let mut doubly = shifted.clone();
+        let stray = streams[0][STREAM_HEADER_LEN..][..record_len].to_vec();
+        doubly[0].splice(STREAM_HEADER_LEN..STREAM_HEADER_LEN, stray);
+        let mut sources: Vec<_> = doubly.iter().map(std::io::Cursor::new).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_stream_realign(&mut sources, &mut Vec::new()),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
 
-        // Create shares with integrity check disabled
-        let config_without_integrity = Config::new().with_integrity_check(false);
-        let mut shamir_without_integrity = ShamirShare::builder(5, 3)
-            .with_config(config_without_integrity)
-            .build()
-            .unwrap();
+    #[test]
+    fn test_reconstruct_stream_lenient_skips_corrupt_chunks() {
+        let data = [[b'a'; 32], [b'b'; 32], [b'c'; 32]].concat();
+        let mut streams = split_stream_for_test(&data, 32);
+
+        let chunk_len = 4 + HASH_SIZE + 32;
+        streams[0][STREAM_HEADER_LEN + chunk_len + 4] ^= 0x01;
+
+        let mut sources: Vec<_> = streams[..2].iter().map(std::io::Cursor::new).collect();
+        let mut recovered = Vec::new();
+        let report =
+            ShamirShare::reconstruct_stream_lenient(&mut sources, &mut recovered, false).unwrap();
+
+        assert_eq!(report.recovered_chunks, 2);
+        assert_eq!(report.failed_chunks, vec![1]);
+        assert_eq!(recovered, [&data[..32], &data[64..]].concat());
+
+        // Undamaged streams recover completely
+        let clean = split_stream_for_test(&data, 32);
+        let mut sources: Vec<_> = clean[1..].iter().map(std::io::Cursor::new).collect();
+        let mut recovered = Vec::new();
+        let report =
+            ShamirShare::reconstruct_stream_lenient(&mut sources, &mut recovered, true).unwrap();
+        assert_eq!(report.recovered_chunks, 3);
+        assert!(report.failed_chunks.is_empty());
+        assert_eq!(recovered, data);
+    }
 
-        let secret = b"test secret";
-        let shares_with_integrity = shamir_with_integrity.split(secret).unwrap();
-        let shares_without_integrity = shamir_without_integrity.split(secret).unwrap();
+    #[test]
+    fn test_dealer_basic_functionality() {
+        let secret = b"Hello, Dealer!";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
 
-        // Mixing shares with different integrity check settings should fail
-        let mixed_shares = vec![
-            shares_with_integrity[0].clone(),
-            shares_without_integrity[1].clone(),
-            shares_with_integrity[2].clone(),
-        ];
+        // Generate shares using dealer
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
+        assert_eq!(dealer_shares.len(), 5);
 
-        assert!(matches!(
-            ShamirShare::reconstruct(&mixed_shares),
-            Err(ShamirError::InconsistentShareLength)
-        ));
+        // Verify share properties
+        for (i, share) in dealer_shares.iter().enumerate() {
+            assert_eq!(share.index, (i + 1) as u8);
+            assert_eq!(share.threshold, 3);
+            assert_eq!(share.total_shares, 5);
+            assert!(share.integrity_check); // Default is true
+        }
+
+        // Reconstruct with threshold shares
+        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
+
+        // Reconstruct with more than threshold shares
+        let reconstructed = ShamirShare::reconstruct(&dealer_shares[1..5]).unwrap();
+        assert_eq!(&reconstructed, secret);
     }
 
     #[test]
-    fn test_config_builder_methods() {
-        use crate::config::SplitMode;
+    fn test_dealer_vs_split_equivalence() {
+        let secret = b"Test equivalence between dealer and split";
+        let mut shamir = ShamirShare::builder(7, 4).build().unwrap();
 
-        let config = Config::new()
-            .with_chunk_size(2048)
-            .unwrap()
-            .with_mode(SplitMode::Parallel)
-            .with_compression(true)
-            .with_integrity_check(false);
+        // Generate shares using split
+        let split_shares = shamir.split(secret).unwrap();
 
-        let shamir = ShamirShare::builder(5, 3)
-            .with_config(config.clone())
-            .build()
-            .unwrap();
+        // Generate shares using dealer
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(7).collect();
 
-        assert_eq!(shamir.config.chunk_size, 2048);
-        assert_eq!(shamir.config.mode, SplitMode::Parallel);
-        assert!(shamir.config.compression);
-        assert!(!shamir.config.integrity_check);
+        // Both should produce the same number of shares
+        assert_eq!(split_shares.len(), dealer_shares.len());
+
+        // Both should be reconstructable
+        let reconstructed_split = ShamirShare::reconstruct(&split_shares[0..4]).unwrap();
+        let reconstructed_dealer = ShamirShare::reconstruct(&dealer_shares[0..4]).unwrap();
+
+        assert_eq!(&reconstructed_split, secret);
+        assert_eq!(&reconstructed_dealer, secret);
+        assert_eq!(reconstructed_split, reconstructed_dealer);
     }
 
     #[test]
-    fn test_split_stream_basic() {
-        use std::io::Cursor;
+    fn test_dealer_lazy_evaluation() {
+        let secret = b"Lazy evaluation test";
+        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
 
-        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-        let data = b"This is a test message for streaming functionality";
-        let mut source = Cursor::new(data);
+        // Create dealer but don't consume all shares
+        let mut dealer = shamir.dealer(secret);
 
-        // Create destination buffers
-        let mut destinations = vec![Vec::new(); 3];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
+        // Take only first 3 shares
+        let first_three: Vec<Share> = dealer.by_ref().take(3).collect();
+        assert_eq!(first_three.len(), 3);
+        assert_eq!(first_three[0].index, 1);
+        assert_eq!(first_three[1].index, 2);
+        assert_eq!(first_three[2].index, 3);
 
-        // Split the stream
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+        // Take next 2 shares from the same dealer
+        let next_two: Vec<Share> = dealer.by_ref().take(2).collect();
+        assert_eq!(next_two.len(), 2);
+        assert_eq!(next_two[0].index, 4);
+        assert_eq!(next_two[1].index, 5);
 
-        // Extract the written data
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        // Combine shares and reconstruct
+        let mut combined_shares = first_three;
+        combined_shares.extend(next_two);
 
-        // Verify that all shares have data
-        for share in &share_data {
-            assert!(!share.is_empty());
-        }
+        let reconstructed = ShamirShare::reconstruct(&combined_shares).unwrap();
+        assert_eq!(&reconstructed, secret);
+    }
 
-        // Reconstruct using the first 2 shares (threshold = 2)
-        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+    #[test]
+    fn test_dealer_max_shares_limit() {
+        let secret = b"Max shares test";
+        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        let dealer = shamir.dealer(secret);
 
-        assert_eq!(&destination, data);
+        // Count all shares generated
+        let all_shares: Vec<Share> = dealer.collect();
+        assert_eq!(all_shares.len(), 255);
+
+        // Verify indices are correct (1 to 255)
+        for (i, share) in all_shares.iter().enumerate() {
+            assert_eq!(share.index, (i + 1) as u8);
+        }
+
+        // Verify reconstruction works with threshold shares
+        let reconstructed = ShamirShare::reconstruct(&all_shares[0..128]).unwrap();
+        assert_eq!(&reconstructed, secret);
     }
 
     #[test]
-    fn test_split_stream_with_custom_chunk_size() {
-        use std::io::Cursor;
+    fn test_dealer_stops_at_255() {
+        let secret = b"Stop at 255 test";
+        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
 
-        let config = Config::new().with_chunk_size(10).unwrap(); // Small chunks for testing
-        let mut shamir = ShamirShare::builder(3, 2)
-            .with_config(config)
-            .build()
-            .unwrap();
+        let mut dealer = shamir.dealer(secret);
 
-        let data = b"This is a longer test message that will be split into multiple chunks";
-        let mut source = Cursor::new(data);
+        // Consume all 255 shares
+        let shares: Vec<Share> = dealer.by_ref().collect();
+        assert_eq!(shares.len(), 255);
 
-        let mut destinations = vec![Vec::new(); 3];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
+        // Dealer should be exhausted
+        assert_eq!(dealer.next(), None);
+        assert_eq!(dealer.next(), None); // Should remain None
+    }
 
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+    #[test]
+    fn test_dealer_size_hint() {
+        let secret = b"Size hint test";
+        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
 
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        let mut dealer = shamir.dealer(secret);
 
-        // Reconstruct
-        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+        // Initial size hint should be 255 (max possible shares)
+        assert_eq!(dealer.size_hint(), (255, Some(255)));
+        assert_eq!(dealer.len(), 255);
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        // Take one share
+        let _share = dealer.next().unwrap();
+        assert_eq!(dealer.size_hint(), (254, Some(254)));
+        assert_eq!(dealer.len(), 254);
 
-        assert_eq!(&destination, data);
+        // Take several more
+        let _shares: Vec<_> = dealer.by_ref().take(10).collect();
+        assert_eq!(dealer.size_hint(), (244, Some(244)));
+        assert_eq!(dealer.len(), 244);
     }
 
     #[test]
-    fn test_split_stream_without_integrity_check() {
-        use std::io::Cursor;
-
-        let config = Config::new()
-            .with_integrity_check(false)
-            .with_chunk_size(20)
-            .unwrap();
-        let mut shamir = ShamirShare::builder(3, 2)
+    fn test_dealer_with_integrity_check_disabled() {
+        let config = Config::new().with_integrity_check(false);
+        let mut shamir = ShamirShare::builder(5, 3)
             .with_config(config)
             .build()
             .unwrap();
 
-        let data = b"Test message without integrity checking";
-        let mut source = Cursor::new(data);
-
-        let mut destinations = vec![Vec::new(); 3];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
-
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+        let secret = b"No integrity check";
 
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        // Generate shares using dealer
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
 
-        // Reconstruct
-        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+        // Verify integrity_check is false
+        for share in &dealer_shares {
+            assert!(!share.integrity_check);
+        }
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        // Should still reconstruct correctly
+        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
 
-        assert_eq!(&destination, data);
+        // Compare with split method
+        let split_shares = shamir.split(secret).unwrap();
+        let reconstructed_split = ShamirShare::reconstruct(&split_shares[0..3]).unwrap();
+        assert_eq!(reconstructed, reconstructed_split);
     }
 
     #[test]
-    fn test_split_stream_empty_data() {
-        use std::io::Cursor;
-
+    fn test_dealer_empty_secret() {
+        let secret = b"";
         let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-        let data = b"";
-        let mut source = Cursor::new(data);
-
-        let mut destinations = vec![Vec::new(); 3];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
-
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
 
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(3).collect();
+        assert_eq!(dealer_shares.len(), 3);
 
-        // All shares should contain only the header (2 bytes: flags + share index) for empty input
-        for share in &share_data {
-            assert_eq!(share.len(), 2); // Only header, no chunk data
-        }
+        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..2]).unwrap();
+        assert_eq!(&reconstructed, secret);
+    }
 
-        // Reconstruct should also produce empty data
-        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+    #[test]
+    fn test_dealer_single_byte_secret() {
+        let secret = b"x";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
+        assert_eq!(dealer_shares.len(), 5);
 
-        assert_eq!(&destination, data);
+        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
     }
 
     #[test]
-    fn test_split_stream_wrong_destination_count() {
-        use std::io::Cursor;
+    fn test_dealer_different_share_combinations() {
+        let secret = b"Different dealer combinations test";
+        let mut shamir = ShamirShare::builder(7, 4).build().unwrap();
 
-        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-        let data = b"test";
-        let mut source = Cursor::new(data);
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(7).collect();
 
-        // Wrong number of destinations (2 instead of 3)
-        let mut destinations = vec![Vec::new(); 2];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
+        // Try different combinations of 4 shares
+        let combinations = vec![
+            vec![0, 1, 2, 3],
+            vec![1, 2, 3, 4],
+            vec![2, 3, 4, 5],
+            vec![0, 2, 4, 6],
+            vec![1, 3, 5, 6],
+        ];
 
-        let result = shamir.split_stream(&mut source, &mut dest_cursors);
-        assert!(matches!(result, Err(ShamirError::InvalidConfig(_))));
+        for combo in combinations {
+            let selected_shares: Vec<Share> =
+                combo.iter().map(|&i| dealer_shares[i].clone()).collect();
+            let reconstructed = ShamirShare::reconstruct(&selected_shares).unwrap();
+            assert_eq!(&reconstructed, secret);
+        }
     }
 
     #[test]
-    fn test_reconstruct_stream_insufficient_sources() {
-        use std::io::Cursor;
+    fn test_dealer_iterator_chain() {
+        let secret = b"Iterator chain test";
+        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
 
-        let mut sources: Vec<Cursor<Vec<u8>>> = vec![];
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+        // Use iterator methods to filter and collect shares
+        let even_indexed_shares: Vec<Share> = shamir
+            .dealer(secret)
+            .filter(|share| share.index % 2 == 0)
+            .take(5)
+            .collect();
 
-        let result = ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor);
-        assert!(matches!(
-            result,
-            Err(ShamirError::InsufficientShares { .. })
-        ));
+        assert_eq!(even_indexed_shares.len(), 5);
+        for share in &even_indexed_shares {
+            assert_eq!(share.index % 2, 0);
+        }
+
+        // Should still be able to reconstruct
+        let reconstructed = ShamirShare::reconstruct(&even_indexed_shares).unwrap();
+        assert_eq!(&reconstructed, secret);
     }
 
     #[test]
-    fn test_stream_data_format() {
-        use std::io::Cursor;
-
-        let config = Config::new().with_chunk_size(5).unwrap(); // Very small chunks
-        let mut shamir = ShamirShare::builder(3, 2)
-            .with_config(config)
-            .build()
-            .unwrap();
+    #[cfg(feature = "zeroize")]
+    fn test_zeroize_feature_compilation() {
+        // This test ensures that the zeroize feature compiles correctly
+        // and that the derives are applied properly
 
-        let data = b"Hello World!"; // 12 bytes, will create 3 chunks (5, 5, 2)
-        let mut source = Cursor::new(data);
+        let secret = b"test secret for zeroize";
+        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
 
-        let mut destinations = vec![Vec::new(); 3];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
+        // Test that Share struct has Zeroize and ZeroizeOnDrop derives
+        let shares = shamir.split(secret).unwrap();
+        assert_eq!(shares.len(), 5);
 
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+        // Test that Dealer struct has Zeroize and ZeroizeOnDrop derives
+        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(3).collect();
+        assert_eq!(dealer_shares.len(), 3);
 
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        // Test reconstruction still works
+        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
+        assert_eq!(&reconstructed, secret);
 
-        // Verify the data format: each share should have length prefixes after the header
-        for share in &share_data {
-            let mut cursor = Cursor::new(share);
-            let mut total_chunks = 0;
+        // Test that FiniteField has Zeroize derive
+        let mut field = crate::FiniteField::new(42);
+        field.zeroize();
+        assert_eq!(field.0, 0);
+    }
 
-            // Skip header (flags + share index)
-            let mut header = [0u8; 2];
-            cursor.read_exact(&mut header).unwrap();
+    #[test]
+    #[cfg(feature = "zeroize")]
+    fn test_share_zeroize_on_drop() {
+        use zeroize::Zeroize;
 
-            // Read chunks until EOF
-            loop {
-                let mut length_bytes = [0u8; 4];
-                match cursor.read_exact(&mut length_bytes) {
-                    Ok(()) => {
-                        let length = u32::from_le_bytes(length_bytes) as usize;
-                        let mut chunk_data = vec![0u8; length];
-                        cursor.read_exact(&mut chunk_data).unwrap();
-                        total_chunks += 1;
-                    }
-                    Err(e) if e.kind() == std::io::ErrorKind::UnexpectedEof => break,
-                    Err(e) => panic!("Unexpected error: {}", e),
-                }
-            }
+        let secret = b"test secret for drop";
+        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
 
-            // Should have 3 chunks (5 + 5 + 2 bytes)
-            assert_eq!(total_chunks, 3);
-        }
+        // Create a share in a limited scope
+        let share_data = {
+            let shares = shamir.split(secret).unwrap();
+            shares[0].data.clone()
+        }; // Share is dropped here, should be zeroized automatically
 
-        // Reconstruct and verify
-        let mut sources: Vec<Cursor<Vec<u8>>> = share_data[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+        // Verify we can still use the cloned data
+        assert!(!share_data.is_empty());
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        // Test manual zeroization
+        let mut shares = shamir.split(secret).unwrap();
+        let original_data = shares[0].data.clone();
+        shares[0].zeroize();
 
-        assert_eq!(&destination, data);
+        // After zeroization, the share data should be zeroed
+        assert!(shares[0].data.iter().all(|&b| b == 0));
+        assert_ne!(original_data, shares[0].data);
     }
 
+    #[cfg(feature = "compress")]
     #[test]
-    fn test_stream_integrity_check_detection() {
-        use std::io::Cursor;
-
-        // Test with integrity check enabled
-        let config_with_integrity = Config::new()
-            .with_integrity_check(true)
-            .with_chunk_size(10)
-            .unwrap();
-        let mut shamir_with_integrity = ShamirShare::builder(3, 2)
-            .with_config(config_with_integrity)
-            .build()
-            .unwrap();
-
-        // Test with integrity check disabled
-        let config_without_integrity = Config::new()
+    fn test_decompression_error_source() {
+        use std::error::Error;
+
+        let err = PayloadCompression::Zstd
+            .decompress(b"not a zstd frame")
+            .unwrap_err();
+        assert!(matches!(err, ShamirError::DecompressionError(_)));
+        let source = err
+            .source()
+            .and_then(|source| source.downcast_ref::<std::io::Error>())
+            .expect("zstd error as source");
+        assert_eq!(err.to_string(), format!("Decompression error: {source}"));
+
+        // The same cause surfaces through reconstruction of corrupted compressed shares
+        let config = Config::new()
             .with_integrity_check(false)
-            .with_chunk_size(10)
-            .unwrap();
-        let mut shamir_without_integrity = ShamirShare::builder(3, 2)
-            .with_config(config_without_integrity)
+            .with_compression(true);
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
             .build()
             .unwrap();
+        let mut shares = shamir.split(&[b'z'; 200]).unwrap();
+        assert!(shares[0].compression);
+        for share in &mut shares[..2] {
+            share.data[..4].fill(0);
+        }
+        let err = ShamirShare::reconstruct(&shares[..2]).unwrap_err();
+        assert!(err.source().unwrap().is::<std::io::Error>());
 
-        let data = b"Test data for integrity checking";
+        let marker = PayloadCompression::Marked.decompress(&[2, 7]).unwrap_err();
+        let source = marker.source().unwrap().downcast_ref::<std::io::Error>();
+        assert_eq!(source.unwrap().kind(), std::io::ErrorKind::InvalidData);
+    }
 
-        // Split with integrity check
-        let mut source1 = Cursor::new(data);
-        let mut destinations1 = vec![Vec::new(); 3];
-        let mut dest_cursors1: Vec<Cursor<Vec<u8>>> = destinations1
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
-        shamir_with_integrity
-            .split_stream(&mut source1, &mut dest_cursors1)
-            .unwrap();
-        let share_data_with_integrity: Vec<Vec<u8>> = dest_cursors1
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+    #[test]
+    fn test_payload_compression_from_stream_flags() {
+        assert_eq!(
+            PayloadCompression::from_stream_flags(0b001),
+            PayloadCompression::None
+        );
+        // Streams written before per-chunk markers
+        assert_eq!(
+            PayloadCompression::from_stream_flags(0b011),
+            PayloadCompression::Zstd
+        );
+        assert_eq!(
+            PayloadCompression::from_stream_flags(0b111),
+            PayloadCompression::Marked
+        );
 
-        // Split without integrity check
-        let mut source2 = Cursor::new(data);
-        let mut destinations2 = vec![Vec::new(); 3];
-        let mut dest_cursors2: Vec<Cursor<Vec<u8>>> = destinations2
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
-        shamir_without_integrity
-            .split_stream(&mut source2, &mut dest_cursors2)
-            .unwrap();
-        let share_data_without_integrity: Vec<Vec<u8>> = dest_cursors2
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+        let marked = PayloadCompression::Marked;
+        assert_eq!(&*marked.decompress(&[0, 7, 8]).unwrap(), &[7, 8]);
+        assert!(marked.decompress(&[]).is_err());
+        assert!(marked.decompress(&[2, 7, 8]).is_err());
 
-        // Shares with integrity check should be larger
-        assert!(share_data_with_integrity[0].len() > share_data_without_integrity[0].len());
+        #[cfg(feature = "compress")]
+        {
+            let frame = zstd::encode_all(&b"legacy"[..], 0).unwrap();
+            let mut marked_frame = vec![1];
+            marked_frame.extend_from_slice(&frame);
+            assert_eq!(&*marked.decompress(&marked_frame).unwrap(), b"legacy");
+            assert_eq!(
+                &*PayloadCompression::Zstd.decompress(&frame).unwrap(),
+                b"legacy"
+            );
+        }
+    }
 
-        // Both should reconstruct correctly
-        let mut sources1: Vec<Cursor<Vec<u8>>> = share_data_with_integrity[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination1 = Vec::new();
-        let mut dest_cursor1 = Cursor::new(&mut destination1);
-        ShamirShare::reconstruct_stream(&mut sources1, &mut dest_cursor1).unwrap();
+    #[test]
+    fn test_reconstruct_diagnosed_data_corruption() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let secret = b"a secret long enough to have many byte positions";
+        let mut shares = scheme.split(secret).unwrap();
+        assert_eq!(ShamirShare::reconstruct_diagnosed(&shares).unwrap(), secret);
+
+        // A few flipped bytes in one of the basis shares
+        shares[0].data[3] ^= 0x01;
+        shares[0].data[40] ^= 0x80;
+        assert!(matches!(
+            ShamirShare::reconstruct(&shares),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_diagnosed(&shares),
+            Err(ShamirError::DataCorruption { corrupted_bytes: 2 })
+        ));
 
-        let mut sources2: Vec<Cursor<Vec<u8>>> = share_data_without_integrity[0..2]
-            .iter()
-            .map(|data| Cursor::new(data.clone()))
-            .collect();
-        let mut destination2 = Vec::new();
-        let mut dest_cursor2 = Cursor::new(&mut destination2);
-        ShamirShare::reconstruct_stream(&mut sources2, &mut dest_cursor2).unwrap();
+        // Without extra shares the failure cannot be diagnosed
+        assert!(matches!(
+            ShamirShare::reconstruct_diagnosed(&shares[..3]),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+    }
 
-        assert_eq!(&destination1, data);
-        assert_eq!(&destination2, data);
+    #[test]
+    fn test_reconstruct_diagnosed_mixed_share_sets() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let secret = b"a secret long enough to have many byte positions";
+        let original = scheme.split(secret).unwrap();
+        let refreshed = scheme.refresh_shares(&original).unwrap();
+
+        // One refreshed share among originals, in the basis and as an extra share
+        for position in [1, 4] {
+            let mut mixed = original.clone();
+            mixed[position] = refreshed[position].clone();
+            assert!(matches!(
+                ShamirShare::reconstruct_diagnosed(&mixed),
+                Err(ShamirError::MixedGenerations)
+            ));
+
+            // Without its generation, the refreshed share is diagnosed from its data
+            mixed[position].refresh_generation = 0;
+            assert!(matches!(
+                ShamirShare::reconstruct_diagnosed(&mixed),
+                Err(ShamirError::MixedShareSets)
+            ));
+        }
+
+        // Shares from two independent splits of the same secret
+        let other = scheme.split(secret).unwrap();
+        let mixed = vec![
+            original[0].clone(),
+            original[1].clone(),
+            other[2].clone(),
+            other[3].clone(),
+        ];
+        assert!(matches!(
+            ShamirShare::reconstruct_diagnosed(&mixed),
+            Err(ShamirError::MixedShareSets)
+        ));
     }
 
     #[test]
-    fn test_stream_large_data() {
-        use std::io::Cursor;
+    fn test_reconstruct_with_metadata() {
+        let mut scheme = ShamirShare::builder(6, 3).build().unwrap();
+        let secret = b"secret for the audit log";
+        let shares = scheme.split(secret).unwrap();
 
-        let config = Config::new().with_chunk_size(1024).unwrap();
-        let mut shamir = ShamirShare::builder(5, 3)
+        let subset = vec![shares[4].clone(), shares[1].clone(), shares[2].clone()];
+        let (reconstructed, metadata) = ShamirShare::reconstruct_with_metadata(&subset).unwrap();
+        assert_eq!(reconstructed, secret);
+        assert_eq!(
+            metadata,
+            ReconstructionMetadata {
+                used_indices: vec![5, 2, 3],
+                threshold: 3,
+                integrity_verified: true,
+                compressed: false,
+            }
+        );
+
+        // All given shares take part in interpolation
+        let (_, metadata) = ShamirShare::reconstruct_with_metadata(&shares).unwrap();
+        assert_eq!(metadata.used_indices, vec![1, 2, 3, 4, 5, 6]);
+
+        let config = Config::new().with_integrity_check(false);
+        let mut unverified = ShamirShare::builder(4, 2)
             .with_config(config)
             .build()
             .unwrap();
+        let shares = unverified.split(secret).unwrap();
+        let (_, metadata) = ShamirShare::reconstruct_with_metadata(&shares[2..]).unwrap();
+        assert_eq!(metadata.threshold, 2);
+        assert!(!metadata.integrity_verified);
+        assert!(!metadata.compressed);
 
-        // Create a large test dataset
-        let data: Vec<u8> = (0..10000).map(|i| (i % 256) as u8).collect();
-        let mut source = Cursor::new(&data);
+        assert!(ShamirShare::reconstruct_with_metadata(&shares[..1]).is_err());
+    }
 
-        let mut destinations = vec![Vec::new(); 5];
-        let mut dest_cursors: Vec<Cursor<Vec<u8>>> = destinations
-            .iter_mut()
-            .map(|d| Cursor::new(std::mem::take(d)))
-            .collect();
+    #[cfg(feature = "compress")]
+    #[test]
+    fn test_reconstruct_with_metadata_compressed() {
+        let config = Config::new().with_compression(true);
+        let mut scheme = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let secret = vec![b'z'; 512];
+        let shares = scheme.split(&secret).unwrap();
 
-        shamir.split_stream(&mut source, &mut dest_cursors).unwrap();
+        let (reconstructed, metadata) =
+            ShamirShare::reconstruct_with_metadata(&shares[..2]).unwrap();
+        assert_eq!(reconstructed, secret);
+        assert!(metadata.compressed);
+        assert!(metadata.integrity_verified);
+    }
 
-        let share_data: Vec<Vec<u8>> = dest_cursors
-            .into_iter()
-            .map(|cursor| cursor.into_inner())
-            .collect();
+    #[test]
+    fn test_reconstruct_with_hash() {
+        let secret = vec![b'h'; 300];
+        let config = Config {
+            compression: cfg!(feature = "compress"),
+            ..Config::new().with_length_check(true)
+        };
+        let mut scheme = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let shares = scheme.split(&secret).unwrap();
 
-        // Reconstruct using shares 0, 2, 4 (threshold = 3)
-        let mut sources: Vec<Cursor<Vec<u8>>> = vec![
-            Cursor::new(share_data[0].clone()),
-            Cursor::new(share_data[2].clone()),
-            Cursor::new(share_data[4].clone()),
-        ];
-        let mut destination = Vec::new();
-        let mut dest_cursor = Cursor::new(&mut destination);
+        let (reconstructed, hash) = ShamirShare::reconstruct_with_hash(&shares[1..]).unwrap();
+        assert_eq!(reconstructed, secret);
+        let expected: [u8; 32] = Sha256::digest(&secret).into();
+        assert_eq!(hash, Some(expected));
 
-        ShamirShare::reconstruct_stream(&mut sources, &mut dest_cursor).unwrap();
+        // The hash is still verified internally
+        let mut tampered = shares[..2].to_vec();
+        tampered[0].data[6] ^= 1;
+        assert!(matches!(
+            ShamirShare::reconstruct_with_hash(&tampered),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
 
-        assert_eq!(&destination, &data);
+        let mut unchecked = ShamirShare::builder(3, 2)
+            .with_config(Config::new().with_integrity_check(false))
+            .build()
+            .unwrap();
+        let shares = unchecked.split(&secret).unwrap();
+        assert_eq!(
+            ShamirShare::reconstruct_with_hash(&shares[..2]).unwrap(),
+            (secret, None)
+        );
     }
 
     #[test]
-    fn test_dealer_basic_functionality() {
-        let secret = b"Hello, Dealer!";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+    fn test_reconstruct_at_not_before() {
+        let unlock = 1_700_000_000;
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme
+            .split_with_not_before(b"dead man's switch", unlock)
+            .unwrap();
+        assert!(shares.iter().all(|share| share.not_before == Some(unlock)));
 
-        // Generate shares using dealer
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
-        assert_eq!(dealer_shares.len(), 5);
+        assert!(matches!(
+            ShamirShare::reconstruct_at(&shares[..3], unlock - 1),
+            Err(ShamirError::SharesNotYetValid { not_before, now })
+                if not_before == unlock && now == unlock - 1
+        ));
+        for now in [unlock, unlock + 86_400] {
+            let secret = ShamirShare::reconstruct_at(&shares[2..], now).unwrap();
+            assert_eq!(secret, b"dead man's switch");
+        }
 
-        // Verify share properties
-        for (i, share) in dealer_shares.iter().enumerate() {
-            assert_eq!(share.index, (i + 1) as u8);
-            assert_eq!(share.threshold, 3);
-            assert_eq!(share.total_shares, 5);
-            assert!(share.integrity_check); // Default is true
+        // Advisory only: plain reconstruction ignores the time
+        assert!(ShamirShare::reconstruct(&shares[..3]).is_ok());
+
+        // The latest time of a mixed set applies, and survives encoding and refreshing
+        let mut mixed = shares[..3].to_vec();
+        mixed[1].not_before = Some(unlock + 10);
+        mixed[2].not_before = None;
+        assert!(ShamirShare::reconstruct_at(&mixed, unlock + 5).is_err());
+        assert!(ShamirShare::reconstruct_at(&mixed, unlock + 10).is_ok());
+        assert_eq!(Share::from_bytes(&mixed[1].to_bytes()).unwrap(), mixed[1]);
+        assert_eq!(Share::from_bytes(&mixed[2].to_bytes()).unwrap(), mixed[2]);
+        let refreshed = scheme.refresh_shares(&shares).unwrap();
+        assert_eq!(refreshed[0].not_before, Some(unlock));
+    }
+
+    #[test]
+    fn test_scalar_roundtrip() {
+        // A 32-byte scalar with leading and trailing zeros, as a little-endian encoding has
+        let mut scalar = [0u8; 32];
+        scalar[1..31].copy_from_slice(&Sha256::digest(b"scalar")[..30]);
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let shares = scheme.split_scalar(&scalar).unwrap();
+
+        let restored: [u8; 32] = ShamirShare::reconstruct_scalar(&shares[2..]).unwrap();
+        assert_eq!(restored, scalar);
+
+        assert!(matches!(
+            ShamirShare::reconstruct_scalar::<64>(&shares[..3]),
+            Err(ShamirError::InvalidReconstructedLength {
+                expected: 64,
+                actual: 32
+            })
+        ));
+        let bytes = scheme.split(b"not a scalar").unwrap();
+        assert!(ShamirShare::reconstruct_scalar::<32>(&bytes[..3]).is_err());
+        assert!(matches!(
+            scheme.split_scalar(&[]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_reconstruct_chunked_to_writer() {
+        let secret: Vec<u8> = (0..5000u32).map(|i| (i % 97) as u8).collect();
+        for compression in [false, cfg!(feature = "compress")] {
+            let config = Config {
+                compression,
+                ..Config::new().with_length_check(true)
+            };
+            let mut scheme = ShamirShare::builder(5, 3)
+                .with_config(config)
+                .build()
+                .unwrap();
+            let shares = scheme.split(&secret).unwrap();
+
+            let mut file = tempfile::tempfile().unwrap();
+            let written =
+                ShamirShare::reconstruct_chunked_to_writer(&shares[1..4], &mut file, 7).unwrap();
+            assert_eq!(written, secret.len() as u64);
+            file.seek(SeekFrom::Start(0)).unwrap();
+            let mut contents = Vec::new();
+            file.read_to_end(&mut contents).unwrap();
+            assert_eq!(contents, secret);
+            assert_eq!(contents, ShamirShare::reconstruct(&shares[1..4]).unwrap());
+
+            // Corrupted data is caught once the whole secret has been hashed
+            let mut tampered = shares[..3].to_vec();
+            let last = tampered[0].data.len() - 1;
+            tampered[0].data[last] ^= 1;
+            let result = ShamirShare::reconstruct_chunked_to_writer(&tampered, &mut Vec::new(), 64);
+            if compression {
+                assert!(result.is_err());
+            } else {
+                assert!(matches!(result, Err(ShamirError::IntegrityCheckFailed)));
+            }
         }
 
-        // Reconstruct with threshold shares
-        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = scheme.split(b"").unwrap();
+        let mut out = Vec::new();
+        assert_eq!(
+            ShamirShare::reconstruct_chunked_to_writer(&shares[..2], &mut out, 1).unwrap(),
+            0
+        );
+        assert!(matches!(
+            ShamirShare::reconstruct_chunked_to_writer(&shares[..2], &mut out, 0),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_chunked_to_writer(&shares[..1], &mut out, 16),
+            Err(ShamirError::InsufficientShares { needed: 2, got: 1 })
+        ));
 
-        // Reconstruct with more than threshold shares
-        let reconstructed = ShamirShare::reconstruct(&dealer_shares[1..5]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // Truncated shares are reported as for `reconstruct`
+        let mut truncated = shares[..2].to_vec();
+        for share in &mut truncated {
+            share.data.truncate(10);
+        }
+        assert!(matches!(
+            ShamirShare::reconstruct_chunked_to_writer(&truncated, &mut out, 16),
+            Err(ShamirError::ShareTooShort { length: 10, .. })
+        ));
     }
 
     #[test]
-    fn test_dealer_vs_split_equivalence() {
-        let secret = b"Test equivalence between dealer and split";
-        let mut shamir = ShamirShare::builder(7, 4).build().unwrap();
+    fn test_split_with_mandatory() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let secret = b"master key material";
+        let shares = scheme.split_with_mandatory(secret, 2).unwrap();
+        assert_eq!(shares.len(), 5);
+        assert!(shares.iter().all(|s| s.threshold == 3));
+
+        // The mandatory share with any two others
+        for pair in [[0, 2], [2, 4], [3, 4]] {
+            let subset = vec![
+                shares[1].clone(),
+                shares[pair[0]].clone(),
+                shares[pair[1]].clone(),
+            ];
+            assert_eq!(
+                ShamirShare::reconstruct_requiring(&subset, 2).unwrap(),
+                secret
+            );
+        }
 
-        // Generate shares using split
-        let split_shares = shamir.split(secret).unwrap();
+        // All other shares together are not enough
+        let others: Vec<Share> = shares.iter().filter(|s| s.index != 2).cloned().collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_requiring(&others, 2),
+            Err(ShamirError::MandatoryShareMissing(2))
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct(&others),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
 
-        // Generate shares using dealer
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(7).collect();
+        // Too few shares alongside the mandatory one
+        assert!(matches!(
+            ShamirShare::reconstruct_requiring(&shares[..2], 2),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
 
-        // Both should produce the same number of shares
-        assert_eq!(split_shares.len(), dealer_shares.len());
+        // A repeated mandatory share is rejected like any other duplicate index
+        let repeated = [shares[1].clone(), shares[1].clone(), shares[0].clone()];
+        assert!(matches!(
+            ShamirShare::reconstruct_requiring(&repeated, 2),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+    }
 
-        // Both should be reconstructable
-        let reconstructed_split = ShamirShare::reconstruct(&split_shares[0..4]).unwrap();
-        let reconstructed_dealer = ShamirShare::reconstruct(&dealer_shares[0..4]).unwrap();
+    #[test]
+    fn test_fixed_record_size_checked_by_every_split() {
+        let secret = b"fixed record";
+        let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+        let needed = scheme.split(secret).unwrap()[0].to_bytes().len();
 
-        assert_eq!(&reconstructed_split, secret);
-        assert_eq!(&reconstructed_dealer, secret);
-        assert_eq!(reconstructed_split, reconstructed_dealer);
+        let config = Config::new().with_fixed_record_size(needed);
+        let mut exact = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut rng = ChaCha20Rng::seed_from_u64(1);
+        assert!(exact.split_with_rng(secret, &mut rng).is_ok());
+        assert!(exact.split_with_mandatory(secret, 1).is_ok());
+        assert_eq!(exact.split_bounded(secret).len(), 3);
+
+        // Fields attached after dealing count too
+        let hints = vec![b"hint".to_vec(); 3];
+        assert!(matches!(
+            exact.split_with_metadata(secret, &hints),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            exact.split_with_not_before(secret, 1),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        let config = Config::new().with_fixed_record_size(needed - 1);
+        let mut short = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        assert!(matches!(
+            short.split_with_rng(secret, &mut rng),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            short.split_with_parity(secret, 1),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(matches!(
+            short.split_with_mandatory(secret, 1),
+            Err(ShamirError::InvalidConfig(_))
+        ));
     }
 
     #[test]
-    fn test_dealer_lazy_evaluation() {
-        let secret = b"Lazy evaluation test";
-        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
-
-        // Create dealer but don't consume all shares
-        let mut dealer = shamir.dealer(secret);
-
-        // Take only first 3 shares
-        let first_three: Vec<Share> = dealer.by_ref().take(3).collect();
-        assert_eq!(first_three.len(), 3);
-        assert_eq!(first_three[0].index, 1);
-        assert_eq!(first_three[1].index, 2);
-        assert_eq!(first_three[2].index, 3);
-
-        // Take next 2 shares from the same dealer
-        let next_two: Vec<Share> = dealer.by_ref().take(2).collect();
-        assert_eq!(next_two.len(), 2);
-        assert_eq!(next_two[0].index, 4);
-        assert_eq!(next_two[1].index, 5);
+    fn test_split_with_mandatory_invalid() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        assert!(matches!(
+            scheme.split_with_mandatory(b"secret", 0),
+            Err(ShamirError::InvalidShareIndex(0))
+        ));
+        assert!(matches!(
+            scheme.split_with_mandatory(b"secret", 6),
+            Err(ShamirError::InvalidShareIndex(6))
+        ));
 
-        // Combine shares and reconstruct
-        let mut combined_shares = first_three;
-        combined_shares.extend(next_two);
+        let mut single = ShamirShare::builder(3, 1).build().unwrap();
+        assert!(matches!(
+            single.split_with_mandatory(b"secret", 1),
+            Err(ShamirError::InvalidThreshold(1))
+        ));
 
-        let reconstructed = ShamirShare::reconstruct(&combined_shares).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // Threshold 2 leaves a constant share set behind the mask
+        let mut pair = ShamirShare::builder(3, 2).build().unwrap();
+        let shares = pair.split_with_mandatory(b"secret", 3).unwrap();
+        assert_eq!(
+            ShamirShare::reconstruct_requiring(&shares[1..], 3).unwrap(),
+            b"secret"
+        );
     }
 
     #[test]
-    fn test_dealer_max_shares_limit() {
-        let secret = b"Max shares test";
-        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
+    fn test_parallel_threshold_paths_agree() {
+        use std::io::Cursor;
 
-        let dealer = shamir.dealer(secret);
+        let small = b"a 32 byte secret for the serial!".to_vec();
+        let large: Vec<u8> = (0..3 * DEFAULT_PARALLEL_THRESHOLD)
+            .map(|i| (i % 251) as u8)
+            .collect();
 
-        // Count all shares generated
-        let all_shares: Vec<Share> = dealer.collect();
-        assert_eq!(all_shares.len(), 255);
+        for threshold in [0, DEFAULT_PARALLEL_THRESHOLD, usize::MAX] {
+            let config = Config::new().with_parallel_threshold(threshold);
+            let mut scheme = ShamirShare::builder(5, 3)
+                .with_config(config.clone())
+                .build()
+                .unwrap();
+
+            for data in [&small, &large] {
+                let mut destinations = vec![Cursor::new(Vec::new()); 5];
+                scheme
+                    .split_stream(&mut Cursor::new(data), &mut destinations)
+                    .unwrap();
 
-        // Verify indices are correct (1 to 255)
-        for (i, share) in all_shares.iter().enumerate() {
-            assert_eq!(share.index, (i + 1) as u8);
+                let mut sources: Vec<_> = destinations[2..]
+                    .iter()
+                    .map(|d| Cursor::new(d.get_ref().clone()))
+                    .collect();
+                let mut reconstructed = Vec::new();
+                ShamirShare::reconstruct_stream(&mut sources, &mut reconstructed).unwrap();
+                assert_eq!(&reconstructed, data);
+
+                // In-memory reconstruction switches paths at the default threshold, or at
+                // the configured one with reconstruct_with_config
+                let shares = scheme.split(data).unwrap();
+                assert_eq!(&ShamirShare::reconstruct(&shares[1..4]).unwrap(), data);
+                assert_eq!(
+                    &ShamirShare::reconstruct_with_config(&shares[1..4], &config).unwrap(),
+                    data
+                );
+            }
         }
-
-        // Verify reconstruction works with threshold shares
-        let reconstructed = ShamirShare::reconstruct(&all_shares[0..128]).unwrap();
-        assert_eq!(&reconstructed, secret);
     }
 
     #[test]
-    fn test_dealer_stops_at_255() {
-        let secret = b"Stop at 255 test";
-        let mut shamir = ShamirShare::builder(255, 128).build().unwrap();
-
-        let mut dealer = shamir.dealer(secret);
-
-        // Consume all 255 shares
-        let shares: Vec<Share> = dealer.by_ref().collect();
-        assert_eq!(shares.len(), 255);
+    fn test_reconstruct_multi_detects_disagreement() {
+        let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+        let first = scheme.split(b"backup one").unwrap();
+        let second = scheme.split(b"backup one").unwrap();
+        let other = scheme.split(b"backup two").unwrap();
 
-        // Dealer should be exhausted
-        assert_eq!(dealer.next(), None);
-        assert_eq!(dealer.next(), None); // Should remain None
+        assert_eq!(
+            ShamirShare::reconstruct_multi(&[&first[..2], &second[1..]]).unwrap(),
+            b"backup one"
+        );
+        assert!(matches!(
+            ShamirShare::reconstruct_multi(&[&first[..2], &second[1..], &other[..2]]),
+            Err(ShamirError::SecretMismatch { set_index: 2 })
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_multi(&[&first[..2], &second[..1]]),
+            Err(ShamirError::InsufficientShares { .. })
+        ));
+        assert!(matches!(
+            ShamirShare::reconstruct_multi(&[]),
+            Err(ShamirError::InsufficientShares { needed: 1, got: 0 })
+        ));
     }
 
+    #[cfg(feature = "memmap")]
     #[test]
-    fn test_dealer_size_hint() {
-        let secret = b"Size hint test";
-        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
+    fn test_reconstruct_stream_to_mmap() {
+        let data: Vec<u8> = (0..10_000u32).map(|i| (i % 253) as u8).collect();
+        let streams = split_stream_for_test(&data, 1024);
+        let dir = tempfile::tempdir().unwrap();
+
+        // Exact, too large, and too small length hints all yield the same file
+        for (name, expected_len) in [("exact", 10_000), ("larger", 50_000), ("smaller", 100)] {
+            let path = dir.path().join(name);
+            let mut sources: Vec<_> = streams[1..].iter().map(std::io::Cursor::new).collect();
+            let len =
+                ShamirShare::reconstruct_stream_to_mmap(&mut sources, &path, expected_len).unwrap();
+            assert_eq!(len, data.len() as u64);
+            assert_eq!(std::fs::read(&path).unwrap(), data);
+        }
 
-        let mut dealer = shamir.dealer(secret);
+        // Empty streams produce an empty file
+        let empty = split_stream_for_test(&[], 1024);
+        let path = dir.path().join("empty");
+        let mut sources: Vec<_> = empty[..2].iter().map(std::io::Cursor::new).collect();
+        assert_eq!(
+            ShamirShare::reconstruct_stream_to_mmap(&mut sources, &path, 0).unwrap(),
+            0
+        );
+        assert!(std::fs::read(&path).unwrap().is_empty());
+    }
 
-        // Initial size hint should be 255 (max possible shares)
-        assert_eq!(dealer.size_hint(), (255, Some(255)));
-        assert_eq!(dealer.len(), 255);
+    #[cfg(feature = "memmap")]
+    #[test]
+    fn test_reconstruct_stream_to_mmap_failure_leaves_no_data() {
+        let data = vec![b's'; 4096];
+        let mut streams = split_stream_for_test(&data, 1024);
 
-        // Take one share
-        let _share = dealer.next().unwrap();
-        assert_eq!(dealer.size_hint(), (254, Some(254)));
-        assert_eq!(dealer.len(), 254);
+        // Corrupt the last chunk so earlier chunks were already written
+        let last = streams[0].len() - 1;
+        streams[0][last] ^= 0xFF;
 
-        // Take several more
-        let _shares: Vec<_> = dealer.by_ref().take(10).collect();
-        assert_eq!(dealer.size_hint(), (244, Some(244)));
-        assert_eq!(dealer.len(), 244);
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("secret");
+        let mut sources: Vec<_> = streams[..2].iter().map(std::io::Cursor::new).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_stream_to_mmap(&mut sources, &path, 4096),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+        assert!(std::fs::read(&path).unwrap().is_empty());
     }
 
+    #[cfg(feature = "memmap")]
     #[test]
-    fn test_dealer_with_integrity_check_disabled() {
-        let config = Config::new().with_integrity_check(false);
-        let mut shamir = ShamirShare::builder(5, 3)
+    fn test_reconstruct_from_mmaps_matches_in_memory() {
+        let map = |path: &PathBuf| {
+            let file = File::open(path).unwrap();
+            // Test files are not modified while mapped
+            unsafe { memmap2::Mmap::map(&file) }.unwrap()
+        };
+        let secret: Vec<u8> = (0..100_000u32).map(|i| (i % 251) as u8).collect();
+        let config = Config {
+            compression: cfg!(feature = "compress"),
+            ..Config::new().with_length_check(true)
+        };
+        let mut scheme = ShamirShare::builder(5, 3)
             .with_config(config)
             .build()
             .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let paths = scheme.backup_to_dir(&secret, dir.path()).unwrap();
 
-        let secret = b"No integrity check";
+        let mmaps: Vec<_> = paths[1..4].iter().map(map).collect();
+        let loaded: Vec<Share> = paths[1..4]
+            .iter()
+            .map(|path| Share::from_bytes(&std::fs::read(path).unwrap()).unwrap())
+            .collect();
+        let from_mmaps = ShamirShare::reconstruct_from_mmaps(&mmaps).unwrap();
+        assert_eq!(from_mmaps, ShamirShare::reconstruct(&loaded).unwrap());
+        assert_eq!(from_mmaps, secret);
 
-        // Generate shares using dealer
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_from_mmaps(&mmaps[..2]),
+            Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+        ));
 
-        // Verify integrity_check is false
-        for share in &dealer_shares {
-            assert!(!share.integrity_check);
+        // Shares of different lengths are reported as by `reconstruct`
+        let mut truncated = loaded[2].clone();
+        truncated.data.pop();
+        let in_memory = [loaded[0].clone(), loaded[1].clone(), truncated];
+        let mixed: Vec<Vec<u8>> = in_memory.iter().map(Share::to_bytes).collect();
+        for result in [
+            ShamirShare::reconstruct_from_mmaps(&mixed),
+            ShamirShare::reconstruct(&in_memory),
+        ] {
+            assert!(matches!(
+                result,
+                Err(ShamirError::InconsistentShareMetadata {
+                    field: "data length"
+                })
+            ));
         }
 
-        // Should still reconstruct correctly
-        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
-
-        // Compare with split method
-        let split_shares = shamir.split(secret).unwrap();
-        let reconstructed_split = ShamirShare::reconstruct(&split_shares[0..3]).unwrap();
-        assert_eq!(reconstructed, reconstructed_split);
-    }
-
-    #[test]
-    fn test_dealer_empty_secret() {
-        let secret = b"";
-        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
-
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(3).collect();
-        assert_eq!(dealer_shares.len(), 3);
-
-        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..2]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // A corrupted file is caught by its checksum before interpolation
+        let mut bytes = std::fs::read(&paths[0]).unwrap();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 1;
+        std::fs::write(&paths[0], bytes).unwrap();
+        let mmaps: Vec<_> = paths[..3].iter().map(map).collect();
+        assert!(matches!(
+            ShamirShare::reconstruct_from_mmaps(&mmaps),
+            Err(ShamirError::ShareFileCorrupt { index: 1 })
+        ));
     }
 
+    #[cfg(feature = "test-helpers")]
     #[test]
-    fn test_dealer_single_byte_secret() {
-        let secret = b"x";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
-
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(5).collect();
-        assert_eq!(dealer_shares.len(), 5);
-
-        let reconstructed = ShamirShare::reconstruct(&dealer_shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+    fn test_rng_fingerprint_distinguishes_seeding() {
+        let mut first = ShamirShare::builder(3, 2).build().unwrap();
+        let mut second = ShamirShare::builder(3, 2).build().unwrap();
+        assert_ne!(first.rng_fingerprint(), second.rng_fingerprint());
+
+        // Each call advances the RNG
+        let fingerprint = first.rng_fingerprint();
+        assert_ne!(first.rng_fingerprint(), fingerprint);
+
+        let seeded = || {
+            let rng = ChaCha20Rng::seed_from_u64(5);
+            ShamirShare::builder(3, 2)
+                .with_rng_source(RngSource::Custom(Box::new(rng)))
+                .build()
+                .unwrap()
+        };
+        assert_eq!(seeded().rng_fingerprint(), seeded().rng_fingerprint());
     }
 
     #[test]
-    fn test_dealer_different_share_combinations() {
-        let secret = b"Different dealer combinations test";
-        let mut shamir = ShamirShare::builder(7, 4).build().unwrap();
-
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(7).collect();
-
-        // Try different combinations of 4 shares
-        let combinations = vec![
-            vec![0, 1, 2, 3],
-            vec![1, 2, 3, 4],
-            vec![2, 3, 4, 5],
-            vec![0, 2, 4, 6],
-            vec![1, 3, 5, 6],
-        ];
+    fn test_builder_for_resilience() {
+        let threshold = |holders, tolerance| {
+            ShamirShare::builder_for_resilience(holders, tolerance)
+                .unwrap()
+                .build()
+                .unwrap()
+                .threshold()
+        };
+        assert_eq!(threshold(10, 0.3), 7);
+        assert_eq!(threshold(10, 0.0), 10);
+        assert_eq!(threshold(10, 0.5), 5);
+        assert_eq!(threshold(10, 0.99), 1);
+        assert_eq!(threshold(5, 0.3), 4);
+        assert_eq!(threshold(100, 0.29), 71);
+        assert_eq!(threshold(255, 0.2), 204);
+        assert_eq!(threshold(1, 0.5), 1);
+
+        let scheme = ShamirShare::builder_for_resilience(7, 0.4)
+            .unwrap()
+            .build()
+            .unwrap();
+        assert_eq!((scheme.total_shares(), scheme.threshold()), (7, 5));
 
-        for combo in combinations {
-            let selected_shares: Vec<Share> =
-                combo.iter().map(|&i| dealer_shares[i].clone()).collect();
-            let reconstructed = ShamirShare::reconstruct(&selected_shares).unwrap();
-            assert_eq!(&reconstructed, secret);
+        for impossible in [1.0, 1.5, -0.1, f64::NAN, f64::INFINITY] {
+            assert!(matches!(
+                ShamirShare::builder_for_resilience(10, impossible),
+                Err(ShamirError::InvalidConfig(_))
+            ));
         }
+        assert!(matches!(
+            ShamirShare::builder_for_resilience(0, 0.3),
+            Err(ShamirError::InvalidShareCount(0))
+        ));
     }
 
     #[test]
-    fn test_dealer_iterator_chain() {
-        let secret = b"Iterator chain test";
-        let mut shamir = ShamirShare::builder(10, 5).build().unwrap();
-
-        // Use iterator methods to filter and collect shares
-        let even_indexed_shares: Vec<Share> = shamir
-            .dealer(secret)
-            .filter(|share| share.index % 2 == 0)
-            .take(5)
-            .collect();
-
-        assert_eq!(even_indexed_shares.len(), 5);
-        for share in &even_indexed_shares {
-            assert_eq!(share.index % 2, 0);
+    fn test_from_share_metadata_matches_stored_share() {
+        let config = Config {
+            integrity_check: false,
+            ..Config::new().with_length_check(true)
+        };
+        let mut original = ShamirShare::builder(7, 4)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let secret = b"rebuilt from one share";
+        let shares = original.split(secret).unwrap();
+        let stored = Share::from_bytes(&shares[2].to_bytes()).unwrap();
+
+        let mut scheme = ShamirShare::from_share_metadata(&stored).unwrap();
+        assert_eq!((scheme.total_shares(), scheme.threshold()), (7, 4));
+
+        // New shares carry the same metadata, and the scheme can refresh the stored shares
+        let fresh = scheme.split(b"another secret").unwrap();
+        for share in &fresh {
+            assert_eq!(
+                (share.threshold, share.total_shares),
+                (stored.threshold, stored.total_shares)
+            );
+            assert_eq!(share.integrity_check, stored.integrity_check);
+            assert_eq!(share.compression, stored.compression);
+            assert_eq!(share.length_check, stored.length_check);
         }
+        let refreshed = scheme.refresh_shares(&shares[..4]).unwrap();
+        assert_eq!(ShamirShare::reconstruct(&refreshed).unwrap(), secret);
 
-        // Should still be able to reconstruct
-        let reconstructed = ShamirShare::reconstruct(&even_indexed_shares).unwrap();
-        assert_eq!(&reconstructed, secret);
+        let mut custom = stored.clone();
+        custom.integrity_check = true;
+        custom.integrity_algorithm = 0x80;
+        assert!(matches!(
+            ShamirShare::from_share_metadata(&custom),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        let mut invalid = stored;
+        invalid.threshold = 8;
+        assert!(ShamirShare::from_share_metadata(&invalid).is_err());
     }
 
     #[test]
-    #[cfg(feature = "zeroize")]
-    fn test_zeroize_feature_compilation() {
-        // This test ensures that the zeroize feature compiles correctly
-        // and that the derives are applied properly
-
-        let secret = b"test secret for zeroize";
-        let mut shamir = ShamirShare::builder(5, 3).build().unwrap();
+    fn test_custom_rng_source_produces_reconstructable_shares() {
+        use std::sync::Arc;
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        // Stands in for a compliance DRBG; counts the bytes the scheme draws
+        struct CountingDrbg {
+            inner: ChaCha20Rng,
+            drawn: Arc<AtomicUsize>,
+        }
+        impl RngCore for CountingDrbg {
+            fn next_u32(&mut self) -> u32 {
+                self.drawn.fetch_add(4, Ordering::Relaxed);
+                self.inner.next_u32()
+            }
+            fn next_u64(&mut self) -> u64 {
+                self.drawn.fetch_add(8, Ordering::Relaxed);
+                self.inner.next_u64()
+            }
+            fn fill_bytes(&mut self, dest: &mut [u8]) {
+                self.drawn.fetch_add(dest.len(), Ordering::Relaxed);
+                self.inner.fill_bytes(dest);
+            }
+        }
+        impl CryptoRng for CountingDrbg {}
 
-        // Test that Share struct has Zeroize and ZeroizeOnDrop derives
-        let shares = shamir.split(secret).unwrap();
-        assert_eq!(shares.len(), 5);
+        let build = |drawn: &Arc<AtomicUsize>| {
+            let drbg = CountingDrbg {
+                inner: ChaCha20Rng::seed_from_u64(9),
+                drawn: Arc::clone(drawn),
+            };
+            ShamirShare::builder(5, 3)
+                .with_rng_source(RngSource::Custom(Box::new(drbg)))
+                .build()
+                .unwrap()
+        };
+        let secret = b"drbg coefficients";
+        let drawn = Arc::new(AtomicUsize::new(0));
+        let mut scheme = build(&drawn);
+        let shares = scheme.split(secret).unwrap();
 
-        // Test that Dealer struct has Zeroize and ZeroizeOnDrop derives
-        let dealer_shares: Vec<Share> = shamir.dealer(secret).take(3).collect();
-        assert_eq!(dealer_shares.len(), 3);
+        assert_eq!(ShamirShare::reconstruct(&shares[2..]).unwrap(), secret);
+        // Two coefficients per byte of the integrity-tagged secret
+        assert_eq!(
+            drawn.load(Ordering::Relaxed),
+            2 * (HASH_SIZE + secret.len())
+        );
 
-        // Test reconstruction still works
-        let reconstructed = ShamirShare::reconstruct(&shares[0..3]).unwrap();
-        assert_eq!(&reconstructed, secret);
+        // The custom generator alone determines the shares
+        let mut replay = build(&Arc::new(AtomicUsize::new(0)));
+        assert_eq!(replay.split(secret).unwrap(), shares);
+        assert_ne!(scheme.split(secret).unwrap(), shares);
 
-        // Test that FiniteField has Zeroize derive
-        let mut field = crate::FiniteField::new(42);
-        field.zeroize();
-        assert_eq!(field.0, 0);
+        let default = ShamirShare::builder(5, 3)
+            .with_rng_source(RngSource::OsChaCha20)
+            .build()
+            .unwrap();
+        assert!(format!("{default:?}").contains("ChaCha20Rng { .. }"));
+        assert!(format!("{scheme:?}").contains("Custom(..)"));
     }
 
     #[test]
-    #[cfg(feature = "zeroize")]
-    fn test_share_zeroize_on_drop() {
-        use zeroize::Zeroize;
+    fn test_split_with_rng_is_reproducible() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let secret = b"seeded test fixture";
 
-        let secret = b"test secret for drop";
-        let mut shamir = ShamirShare::builder(3, 2).build().unwrap();
+        let first = scheme
+            .split_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(42))
+            .unwrap();
+        let second = scheme
+            .split_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(42))
+            .unwrap();
+        let other = scheme
+            .split_with_rng(secret, &mut ChaCha20Rng::seed_from_u64(43))
+            .unwrap();
 
-        // Create a share in a limited scope
-        let share_data = {
-            let shares = shamir.split(secret).unwrap();
-            shares[0].data.clone()
-        }; // Share is dropped here, should be zeroized automatically
+        assert_eq!(first, second);
+        assert_ne!(first, other);
+        assert_eq!(ShamirShare::reconstruct(&first[2..]).unwrap(), secret);
+        assert_eq!(ShamirShare::reconstruct(&other[..3]).unwrap(), secret);
 
-        // Verify we can still use the cloned data
-        assert!(!share_data.is_empty());
+        // The internal RNG is untouched and still produces fresh shares
+        assert_ne!(scheme.split(secret).unwrap(), first);
+    }
 
-        // Test manual zeroization
-        let mut shares = shamir.split(secret).unwrap();
-        let original_data = shares[0].data.clone();
-        shares[0].zeroize();
+    #[test]
+    fn test_reconstruct_tolerant() {
+        let mut scheme = ShamirShare::builder(6, 3).build().unwrap();
+        let secret = b"recover despite one bad share";
+        let shares = scheme.split(secret).unwrap();
+
+        // All good
+        let (recovered, excluded) = ShamirShare::reconstruct_tolerant(&shares[..4]).unwrap();
+        assert_eq!(recovered, secret);
+        assert_eq!(excluded, None);
+
+        // One of four shares corrupt
+        for bad in 0..4 {
+            let mut corrupted = shares[..4].to_vec();
+            corrupted[bad].data[7] ^= 0x21;
+            let (recovered, excluded) = ShamirShare::reconstruct_tolerant(&corrupted).unwrap();
+            assert_eq!(recovered, secret);
+            assert_eq!(excluded, Some(shares[bad].index));
+        }
 
-        // After zeroization, the share data should be zeroed
-        assert!(shares[0].data.iter().all(|&b| b == 0));
-        assert_ne!(original_data, shares[0].data);
+        // Two corrupt shares cannot be recovered from
+        let mut corrupted = shares[..5].to_vec();
+        corrupted[0].data[1] ^= 0x01;
+        corrupted[3].data[2] ^= 0x01;
+        assert!(matches!(
+            ShamirShare::reconstruct_tolerant(&corrupted),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
+
+        // Without spare shares the corrupt one cannot be identified
+        let mut corrupted = shares[..3].to_vec();
+        corrupted[1].data[0] ^= 0x80;
+        assert!(matches!(
+            ShamirShare::reconstruct_tolerant(&corrupted),
+            Err(ShamirError::IntegrityCheckFailed)
+        ));
     }
 }
@@ -1,12 +1,59 @@
+use std::borrow::Borrow;
+use std::collections::BTreeSet;
 use std::fs::{self, File};
 use std::io::{self, BufWriter, Read, Write};
 use std::path::{Path, PathBuf};
 
 use crate::error::{Result, ShamirError};
+use crate::integrity::{Crc32, SHA256_ALGORITHM_ID};
 use crate::shamir::Share;
 
+#[cfg(feature = "zeroize")]
+use zeroize::Zeroize;
+
 const MAGIC_NUMBER: &[u8] = b"SHS1"; // Changed magic number for new format
-const VERSION: u8 = 2; // Incremented version for new format
+const VERSION: u8 = 8; // Version 8 adds the not-before time
+/// Flag bit marking a share followed by a CRC-32 of everything before it
+const CHECKSUM_FLAG: u8 = 8;
+/// First version whose shares always carry the CRC-32 trailer
+const CHECKSUM_VERSION: u8 = 6;
+/// First version with a metadata block
+const METADATA_VERSION: u8 = 7;
+/// Bytes allocated up front when reading a length-prefixed field
+const READ_CHUNK_SIZE: usize = 64 * 1024;
+/// Flag bit marking a share with a not-before time
+const NOT_BEFORE_FLAG: u8 = 16;
+const DEFAULT_FILE_PREFIX: &str = "share";
+const MANIFEST_MAGIC: &[u8] = b"SHSM";
+const MANIFEST_VERSION: u8 = 1;
+
+/// Builds the file name used for a share: `<prefix>_<index>` with a zero-padded index
+fn share_file_name(prefix: &str, index: u8) -> String {
+    format!("{prefix}_{index:03}")
+}
+
+impl Share {
+    /// Returns the file name `FileShareStore` uses for this share
+    ///
+    /// The name has the form `<prefix>_<index>` with a three-digit index, e.g. `share_001`.
+    /// `prefix` defaults to `"share"`, matching a store without
+    /// [`with_prefix`](FileShareStore::with_prefix). Recovery tools can use this to map a loaded share
+    /// back to its canonical file name after files have been renamed or moved.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShamirShare;
+    ///
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// let shares = scheme.split(b"secret").unwrap();
+    ///
+    /// assert_eq!(shares[0].canonical_filename(None), "share_001");
+    /// assert_eq!(shares[4].canonical_filename(Some("vault")), "vault_005");
+    /// ```
+    pub fn canonical_filename(&self, prefix: Option<&str>) -> String {
+        share_file_name(prefix.unwrap_or(DEFAULT_FILE_PREFIX), self.index)
+    }
+}
 
 /// Trait defining storage operations for Shamir shares
 ///
@@ -37,18 +84,34 @@ pub trait ShareStore {
 
     /// Deletes a share from storage
     fn delete_share(&mut self, index: u8) -> Result<()>;
+
+    /// Iterates over the stored shares in index order, loading each one when it is reached
+    ///
+    /// The indices are listed up front; shares removed after the call yield the error from
+    /// `load_share`. Stopping early, e.g. once `threshold` shares are collected, skips
+    /// loading the rest.
+    fn iter_shares(&self) -> Result<Box<dyn Iterator<Item = Result<Share>> + '_>> {
+        let indices = self.list_shares()?;
+        Ok(Box::new(
+            indices.into_iter().map(move |index| self.load_share(index)),
+        ))
+    }
 }
 
 /// File system implementation of ShareStore
 ///
 /// Stores each share as a separate file with a secure binary format including
 /// magic numbers and version information to prevent format confusion attacks.
-/// Files are named in the format: `share_<index>` (e.g., share_001, share_002)
+/// Files are named in the format: `share_<index>` (e.g., share_001, share_002), or
+/// `<prefix>_<index>` with [`with_prefix`](Self::with_prefix), so several schemes can share
+/// a directory
 ///
 /// # Security
 /// - Files include magic number validation to prevent format attacks
 /// - Version checking ensures compatibility
 /// - Atomic write operations prevent partial file corruption
+/// - A CRC-32 checksum in every file written since version 6 detects bit rot when the
+///   share is loaded (`ShareFileCorrupt`). It guards against accidents, not tampering
 ///
 /// # Example
 /// ```
@@ -64,7 +127,12 @@ pub trait ShareStore {
 ///     threshold: 3,
 ///     total_shares: 5,
 ///     integrity_check: true,
+///     integrity_algorithm: shamir_share::SHA256_ALGORITHM_ID,
 ///     compression: false,
+///     length_check: false,
+///     refresh_generation: 0,
+///     metadata: Vec::new(),
+///     not_before: None,
 /// };
 ///
 /// store.store_share(&share).unwrap();
@@ -74,6 +142,8 @@ pub trait ShareStore {
 pub struct FileShareStore {
     /// Base directory for storing shares
     base_dir: PathBuf,
+    /// File name prefix identifying this store's scheme within the directory
+    prefix: String,
 }
 
 impl FileShareStore {
@@ -89,38 +159,761 @@ impl FileShareStore {
     pub fn new<P: AsRef<Path>>(base_dir: P) -> Result<Self> {
         let base_dir = base_dir.as_ref().to_path_buf();
         fs::create_dir_all(&base_dir)?;
-        Ok(Self { base_dir })
+        Ok(Self {
+            base_dir,
+            prefix: DEFAULT_FILE_PREFIX.to_string(),
+        })
+    }
+
+    /// Stores and looks up shares as `<prefix>_<index>` instead of `share_<index>`
+    ///
+    /// Giving each scheme its own prefix lets several schemes live in one directory;
+    /// [`list_schemes`](Self::list_schemes) finds them again.
+    ///
+    /// # Errors
+    /// Returns `ShamirError::InvalidConfig` if `prefix` is empty or contains a path
+    /// separator.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let mut store = FileShareStore::new(temp_dir.path())
+    ///     .unwrap()
+    ///     .with_prefix("vault")
+    ///     .unwrap();
+    ///
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// store.store_share(&scheme.split(b"secret").unwrap()[0]).unwrap();
+    /// assert!(temp_dir.path().join("vault_001").is_file());
+    /// ```
+    pub fn with_prefix(mut self, prefix: &str) -> Result<Self> {
+        if prefix.is_empty() || prefix.contains(['/', '\\']) {
+            return Err(ShamirError::InvalidConfig(format!(
+                "Invalid share file prefix {prefix:?}"
+            )));
+        }
+        self.prefix = prefix.to_string();
+        Ok(self)
+    }
+
+    /// Lists the distinct prefixes of the share files in the store's directory
+    ///
+    /// Every file named `<prefix>_<index>`, where `index` is a share index, counts as a
+    /// share of the scheme `<prefix>`. Recovery tooling can use this to discover which
+    /// schemes a directory holds, then open each with [`with_prefix`](Self::with_prefix).
+    /// The default prefix is reported as `"share"`.
+    ///
+    /// # Returns
+    /// The prefixes in ascending order
+    ///
+    /// # Errors
+    /// Returns `ShamirError::IoError` if the directory cannot be read.
+    pub fn list_schemes(&self) -> Result<Vec<String>> {
+        let mut prefixes = BTreeSet::new();
+
+        for entry in fs::read_dir(&self.base_dir)? {
+            let entry = entry?;
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            if let Some((prefix, index)) = file_name.rsplit_once('_')
+                && !prefix.is_empty()
+                && index.parse::<u8>().is_ok()
+            {
+                prefixes.insert(prefix.to_string());
+            }
+        }
+
+        Ok(prefixes.into_iter().collect())
+    }
+
+    /// Writes a `<prefix>.manifest` sidecar file recording the scheme and stored indices
+    ///
+    /// Every share records the threshold, but recovery tooling inspecting a directory
+    /// otherwise has to load a share to learn it, and cannot tell which shares went
+    /// missing. The manifest records the scheme parameters of the stored shares and the
+    /// indices present when it was written; [`read_manifest`](Self::read_manifest) loads it
+    /// back. An existing manifest is replaced atomically.
+    ///
+    /// Layout: `[magic "SHSM"][version][flags][threshold][total_shares]
+    /// [2-byte LE count][indices]`, with flags as in share files.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - The store holds no shares (`InsufficientShares`)
+    /// - The shares disagree on threshold, total share count, flags or refresh generation
+    ///   (`MixedShareSets`)
+    /// - A share cannot be loaded, or the manifest cannot be written (`IoError`)
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let mut store = FileShareStore::new(temp_dir.path()).unwrap();
+    /// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    /// for share in scheme.split(b"secret").unwrap() {
+    ///     store.store_share(&share).unwrap();
+    /// }
+    ///
+    /// store.write_manifest().unwrap();
+    /// let manifest = store.read_manifest().unwrap();
+    /// assert_eq!(manifest.threshold, 3);
+    /// assert_eq!(manifest.indices, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn write_manifest(&self) -> Result<()> {
+        let bytes = encode_manifest(self.iter_shares()?)?;
+
+        // Write beside the target and rename, so readers never see a partial manifest
+        let path = self.manifest_path();
+        let staging = self.base_dir.join(format!(".{}.manifest.tmp", self.prefix));
+        fs::write(&staging, &bytes)?;
+        fs::rename(&staging, &path)?;
+        Ok(())
+    }
+
+    /// Writes the manifest of `shares`, which must not exist yet, and syncs it
+    ///
+    /// # Returns
+    /// The path of the manifest
+    ///
+    /// # Errors
+    /// As for [`write_manifest`](Self::write_manifest); an existing manifest is left
+    /// untouched and reported as `IoError` with kind `AlreadyExists`.
+    pub(crate) fn create_manifest(&self, shares: &[Share]) -> Result<PathBuf> {
+        let bytes = encode_manifest(shares.iter().map(Ok))?;
+        let path = self.manifest_path();
+        write_new_file(&path, &bytes)?;
+        Ok(path)
+    }
+
+    /// Reads the manifest written by [`write_manifest`](Self::write_manifest)
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if:
+    /// - There is no manifest, or it cannot be read (`IoError`)
+    /// - The file is not a manifest or is truncated (`InvalidShareFormat`)
+    /// - The manifest was written by a newer version (`UnsupportedVersion`)
+    pub fn read_manifest(&self) -> Result<ShareManifest> {
+        let bytes = fs::read(self.manifest_path())?;
+        let Some((header, rest)) = bytes.split_first_chunk::<10>() else {
+            return Err(ShamirError::InvalidShareFormat);
+        };
+        if &header[..4] != MANIFEST_MAGIC {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+        if header[4] > MANIFEST_VERSION {
+            return Err(ShamirError::UnsupportedVersion {
+                found: header[4],
+                max_supported: MANIFEST_VERSION,
+            });
+        }
+        let count = u16::from_le_bytes([header[8], header[9]]) as usize;
+        if rest.len() != count {
+            return Err(ShamirError::InvalidShareFormat);
+        }
+
+        let flags = header[5];
+        Ok(ShareManifest {
+            threshold: header[6],
+            total_shares: header[7],
+            integrity_check: flags & 1 != 0,
+            compression: flags & 2 != 0,
+            length_check: flags & 4 != 0,
+            indices: rest.to_vec(),
+        })
+    }
+
+    /// Rewrites every share file written before the CRC-32 checksum (format version 6)
+    ///
+    /// Shares of every version load and reconstruct without migration; migrating adds the
+    /// checksum, so bit rot is caught on load. Each share is rewritten in the lowest
+    /// version that holds its fields, as [`store_share`](ShareStore::store_share) writes
+    /// it, so files of version 6 or later are already current and left untouched.
+    ///
+    /// A loaded share does not remember the version it was read from: fields an older
+    /// format lacks take their defaults (SHA-256 as the integrity algorithm, refresh
+    /// generation 0, no metadata and no not-before time), and the share is rewritten with
+    /// them. Share data and metadata are preserved. Each file is written and synced beside
+    /// its target, then renamed over it; on failure the temporary file is removed, so no
+    /// partial share file is left behind.
+    ///
+    /// # Returns
+    /// The indices of the migrated shares, in ascending order.
+    ///
+    /// # Errors
+    /// Returns `ShamirError` if a share cannot be loaded, as for
+    /// [`load_share`](ShareStore::load_share), or cannot be rewritten (`IoError`). Shares
+    /// migrated before the failure stay migrated.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::{FileShareStore, ShamirShare, ShareStore};
+    ///
+    /// let temp_dir = tempfile::tempdir().unwrap();
+    /// let mut store = FileShareStore::new(temp_dir.path()).unwrap();
+    /// let mut scheme = ShamirShare::builder(3, 2).build().unwrap();
+    /// for share in scheme.split(b"secret").unwrap() {
+    ///     store.store_share(&share).unwrap();
+    /// }
+    ///
+    /// // Shares written by this version need no migration
+    /// assert!(store.migrate_all().unwrap().is_empty());
+    /// ```
+    pub fn migrate_all(&mut self) -> Result<Vec<u8>> {
+        let mut migrated = Vec::new();
+        for index in self.list_shares()? {
+            let path = self.share_path(index);
+            let bytes = fs::read(&path)?;
+            let share = read_share(&mut bytes.as_slice())?;
+            if share.index != index {
+                return Err(ShamirError::InvalidShareFormat);
+            }
+            if bytes[MAGIC_NUMBER.len()] >= CHECKSUM_VERSION {
+                continue;
+            }
+
+            let staging = self.base_dir.join(format!(
+                ".{}.migrate.tmp",
+                share_file_name(&self.prefix, index)
+            ));
+            let mut bytes = Vec::new();
+            write_share(&mut bytes, &share)?;
+            let result = File::create(&staging)
+                .and_then(|mut file| {
+                    file.write_all(&bytes)?;
+                    file.sync_all()
+                })
+                .and_then(|()| fs::rename(&staging, &path));
+            if let Err(e) = result {
+                // Best-effort cleanup; the original error is the one worth reporting
+                let _ = fs::remove_file(&staging);
+                return Err(e.into());
+            }
+            migrated.push(index);
+        }
+        Ok(migrated)
     }
 
     /// Gets the path for a share file
     fn share_path(&self, index: u8) -> PathBuf {
-        self.base_dir.join(format!("share_{index:03}"))
+        self.base_dir.join(share_file_name(&self.prefix, index))
+    }
+
+    /// Gets the path of the manifest sidecar file
+    pub(crate) fn manifest_path(&self) -> PathBuf {
+        self.base_dir.join(format!("{}.manifest", self.prefix))
+    }
+}
+
+/// Scheme parameters and stored share indices of a [`FileShareStore`], as recorded by
+/// [`FileShareStore::write_manifest`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ShareManifest {
+    /// Shares needed to reconstruct
+    pub threshold: u8,
+    /// Shares produced by the split
+    pub total_shares: u8,
+    /// Whether the shares carry an integrity check
+    pub integrity_check: bool,
+    /// Whether the secret was compressed before splitting
+    pub compression: bool,
+    /// Whether the shares record the secret length
+    pub length_check: bool,
+    /// Indices stored when the manifest was written, in ascending order
+    pub indices: Vec<u8>,
+}
+
+impl ShareManifest {
+    /// Returns the recorded indices that are not in `present`, in ascending order
+    ///
+    /// Pass [`ShareStore::list_shares`] to find the shares lost since the manifest was
+    /// written.
+    ///
+    /// # Example
+    /// ```
+    /// use shamir_share::ShareManifest;
+    ///
+    /// let manifest = ShareManifest {
+    ///     threshold: 2,
+    ///     total_shares: 3,
+    ///     integrity_check: true,
+    ///     compression: false,
+    ///     length_check: false,
+    ///     indices: vec![1, 2, 3],
+    /// };
+    /// assert_eq!(manifest.missing_indices(&[1, 3]), [2]);
+    /// ```
+    pub fn missing_indices(&self, present: &[u8]) -> Vec<u8> {
+        self.indices
+            .iter()
+            .copied()
+            .filter(|index| !present.contains(index))
+            .collect()
+    }
+}
+
+/// Passes writes through to `inner`, keeping a CRC-32 of every byte written
+struct ChecksumWriter<W> {
+    inner: W,
+    crc: Crc32,
+}
+
+impl<W: Write> Write for ChecksumWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.crc.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+/// Passes reads through to `inner`, keeping a CRC-32 of every byte read
+struct ChecksumReader<R> {
+    inner: R,
+    crc: Crc32,
+}
+
+impl<R: Read> Read for ChecksumReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.crc.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+/// Writes a share using the binary format shared by `FileShareStore` and the text encodings
+///
+/// Layout: `[magic "SHS1"][version][flags][integrity algorithm][index][threshold][total_shares]
+/// [4-byte LE refresh generation][8-byte LE not-before time, if flagged][4-byte LE metadata
+/// length, from version 7][metadata][4-byte LE length][data][4-byte LE CRC-32]`
+///
+/// The CRC-32 covers every byte before it, so bit rot in the data or metadata is caught
+/// when the share is read, without reconstructing. Its presence is marked by a flag bit;
+/// shares written before version 6 have none.
+///
+/// The version written is the lowest that holds the share's fields (see
+/// [`format_version`]), so readers that predate metadata or not-before times keep
+/// reading shares that use neither; the metadata block is only written from version 7.
+///
+/// # Errors
+/// Returns `ShamirError::InvalidShareFormat` if the metadata or data is longer than a
+/// 4-byte length can record, and `ShamirError::IoError` if writing fails.
+pub(crate) fn write_share<W: Write>(writer: &mut W, share: &Share) -> Result<()> {
+    let mut writer = ChecksumWriter {
+        inner: writer,
+        crc: Crc32::new(),
+    };
+
+    // Write header
+    let version = format_version(share);
+    writer.write_all(MAGIC_NUMBER)?;
+    writer.write_all(&[version])?;
+
+    // Write flags and scheme parameters
+    let integrity_flag = if share.integrity_check { 1 } else { 0 };
+    let compression_flag = if share.compression { 2 } else { 0 };
+    let length_check_flag = if share.length_check { 4 } else { 0 };
+    let not_before_flag = if share.not_before.is_some() {
+        NOT_BEFORE_FLAG
+    } else {
+        0
+    };
+    let flags =
+        integrity_flag | compression_flag | length_check_flag | CHECKSUM_FLAG | not_before_flag;
+    writer.write_all(&[flags, share.integrity_algorithm])?;
+    writer.write_all(&[share.index, share.threshold, share.total_shares])?;
+    writer.write_all(&share.refresh_generation.to_le_bytes())?;
+    if let Some(not_before) = share.not_before {
+        writer.write_all(&not_before.to_le_bytes())?;
+    }
+    if version >= METADATA_VERSION {
+        let metadata_len =
+            u32::try_from(share.metadata.len()).map_err(|_| ShamirError::InvalidShareFormat)?;
+        writer.write_all(&metadata_len.to_le_bytes())?;
+        writer.write_all(&share.metadata)?;
+    }
+
+    // Write data
+    let len = u32::try_from(share.data.len()).map_err(|_| ShamirError::InvalidShareFormat)?;
+    writer.write_all(&len.to_le_bytes())?;
+    writer.write_all(&share.data)?;
+
+    let crc = writer.crc.finish();
+    writer.inner.write_all(&crc.to_le_bytes())?;
+    Ok(())
+}
+
+/// Returns the lowest share file version that can hold every field `share` uses
+///
+/// Every share is written with its checksum, so version 6 is the floor. Metadata needs
+/// version 7 and a not-before time version 8.
+fn format_version(share: &Share) -> u8 {
+    if share.not_before.is_some() {
+        VERSION
+    } else if !share.metadata.is_empty() {
+        METADATA_VERSION
+    } else {
+        CHECKSUM_VERSION
     }
 }
 
+/// Returns the length [`write_share`] writes for a share with these fields
+///
+/// The header, data length and checksum take 22 bytes. Metadata adds its own length
+/// field, which a not-before time also needs, and the time adds 8 bytes.
+pub(crate) fn encoded_len(data_len: usize, metadata_len: usize, not_before: bool) -> usize {
+    let mut len = 22 + data_len;
+    if not_before || metadata_len > 0 {
+        len += 4 + metadata_len;
+    }
+    if not_before {
+        len += 8;
+    }
+    len
+}
+
+/// Reads a share previously written by [`write_share`]
+///
+/// # Errors
+/// Returns `ShamirError::ShareFileCorrupt` if the share has a checksum that does not
+/// match the bytes read, ends before its checksum, or is of a version that always carries
+/// a checksum but lacks the checksum flag.
+pub(crate) fn read_share<R: Read>(reader: &mut R) -> Result<Share> {
+    let mut reader = ChecksumReader {
+        inner: reader,
+        crc: Crc32::new(),
+    };
+    let (mut share, len, checksummed) = read_share_header(&mut reader)?;
+
+    // With a checksum expected, running out of bytes means the length or data are corrupt
+    let index = share.index;
+    let corrupt = |e: ShamirError| match e {
+        ShamirError::IoError(e) if checksummed && e.kind() == io::ErrorKind::UnexpectedEof => {
+            ShamirError::ShareFileCorrupt { index }
+        }
+        other => other,
+    };
+    share.data = read_bytes(&mut reader, len)
+        .map_err(ShamirError::from)
+        .map_err(corrupt)?;
+
+    if checksummed {
+        verify_checksum(&mut reader.inner, reader.crc.finish(), index).map_err(corrupt)?;
+    }
+    Ok(share)
+}
+
+/// Reads `len` bytes, growing the buffer as they arrive rather than trusting `len` up front
+///
+/// A corrupted length therefore fails with `UnexpectedEof` once the input runs out,
+/// instead of allocating up to 4 GiB first.
+fn read_bytes<R: Read>(reader: &mut R, len: usize) -> io::Result<Vec<u8>> {
+    let mut bytes = vec![0u8; len.min(READ_CHUNK_SIZE)];
+    reader.read_exact(&mut bytes)?;
+    while bytes.len() < len {
+        let filled = bytes.len();
+        let mut grown = vec![0u8; filled.saturating_mul(2).min(len)];
+        grown[..filled].copy_from_slice(&bytes);
+        // The bytes may be share data, so do not leave a copy behind
+        #[cfg(feature = "zeroize")]
+        bytes.zeroize();
+        bytes = grown;
+        reader.read_exact(&mut bytes[filled..])?;
+    }
+    Ok(bytes)
+}
+
+/// Parses the share at the start of `bytes` without copying its data
+///
+/// Returns the share with empty `data`, and the data borrowed from `bytes`. Bytes after
+/// the share are ignored, as when loading a share file.
+///
+/// # Errors
+/// Returns `ShamirError::InvalidShareFormat` if `bytes` is truncated, and otherwise the
+/// errors of [`read_share`].
+#[cfg(feature = "memmap")]
+pub(crate) fn parse_share_slice(bytes: &[u8]) -> Result<(Share, &[u8])> {
+    let mut reader = ChecksumReader {
+        inner: bytes,
+        crc: Crc32::new(),
+    };
+    let truncated = |e| match e {
+        ShamirError::IoError(_) => ShamirError::InvalidShareFormat,
+        other => other,
+    };
+    let (share, len, checksummed) = read_share_header(&mut reader).map_err(truncated)?;
+
+    // With a checksum expected, a truncated share means the length or data are corrupt
+    let corrupt = |_| ShamirError::ShareFileCorrupt { index: share.index };
+    let Some((data, mut rest)) = reader.inner.split_at_checked(len) else {
+        return Err(if checksummed {
+            ShamirError::ShareFileCorrupt { index: share.index }
+        } else {
+            ShamirError::InvalidShareFormat
+        });
+    };
+    if checksummed {
+        reader.crc.update(data);
+        verify_checksum(&mut rest, reader.crc.finish(), share.index).map_err(corrupt)?;
+    }
+    Ok((share, data))
+}
+
+/// Reads everything before the share data
+///
+/// Returns the share with empty `data`, the data length, and whether a checksum follows
+/// the data.
+fn read_share_header<R: Read>(reader: &mut R) -> Result<(Share, usize, bool)> {
+    // Read and verify header
+    let mut magic = [0u8; 4];
+    reader.read_exact(&mut magic)?;
+    if magic != MAGIC_NUMBER {
+        return Err(ShamirError::InvalidShareFormat);
+    }
+
+    let mut version = [0u8; 1];
+    reader.read_exact(&mut version)?;
+    if version[0] > VERSION {
+        return Err(ShamirError::UnsupportedVersion {
+            found: version[0],
+            max_supported: VERSION,
+        });
+    }
+
+    // Read flags and scheme parameters
+    let mut flags = [0u8; 1];
+    reader.read_exact(&mut flags)?;
+    let integrity_check = (flags[0] & 1) != 0;
+    let compression = (flags[0] & 2) != 0;
+    let length_check = (flags[0] & 4) != 0;
+
+    // Version 2 files predate pluggable verifiers and always used SHA-256
+    let mut integrity_algorithm = [SHA256_ALGORITHM_ID];
+    if version[0] >= 3 {
+        reader.read_exact(&mut integrity_algorithm)?;
+    }
+
+    let mut header = [0u8; 3];
+    reader.read_exact(&mut header)?;
+    let (index, threshold, total_shares) = (header[0], header[1], header[2]);
+
+    // Every share since version 6 carries a checksum, so a cleared flag is itself corruption
+    let checksummed = flags[0] & CHECKSUM_FLAG != 0;
+    if version[0] >= CHECKSUM_VERSION && !checksummed {
+        return Err(ShamirError::ShareFileCorrupt { index });
+    }
+
+    // Shares written before version 5 were never refreshed with a tracked generation
+    let mut refresh_generation = [0u8; 4];
+    if version[0] >= 5 {
+        reader.read_exact(&mut refresh_generation)?;
+    }
+
+    let mut not_before = None;
+    if flags[0] & NOT_BEFORE_FLAG != 0 {
+        let mut time = [0u8; 8];
+        reader.read_exact(&mut time)?;
+        not_before = Some(u64::from_le_bytes(time));
+    }
+
+    // Shares written before version 7 carry no metadata. The length is untrusted, so
+    // metadata running past the end of the input is a format error, not an allocation
+    let mut metadata = Vec::new();
+    if version[0] >= METADATA_VERSION {
+        let mut len_bytes = [0u8; 4];
+        reader.read_exact(&mut len_bytes)?;
+        metadata =
+            read_bytes(reader, u32::from_le_bytes(len_bytes) as usize).map_err(|e| {
+                match e.kind() {
+                    io::ErrorKind::UnexpectedEof => ShamirError::InvalidShareFormat,
+                    _ => e.into(),
+                }
+            })?;
+    }
+
+    // Read data length
+    let mut len_bytes = [0u8; 4];
+    reader.read_exact(&mut len_bytes)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    let share = Share {
+        index,
+        data: Vec::new(),
+        threshold,
+        total_shares,
+        integrity_check,
+        integrity_algorithm: integrity_algorithm[0],
+        compression,
+        length_check,
+        refresh_generation: u32::from_le_bytes(refresh_generation),
+        metadata,
+        not_before,
+    };
+    Ok((share, len, checksummed))
+}
+
+/// Reads the CRC-32 trailer and compares it with the checksum of the bytes before it
+fn verify_checksum<R: Read>(reader: &mut R, expected: u32, index: u8) -> Result<()> {
+    let mut checksum = [0u8; 4];
+    reader.read_exact(&mut checksum)?;
+    if u32::from_le_bytes(checksum) != expected {
+        return Err(ShamirError::ShareFileCorrupt { index });
+    }
+    Ok(())
+}
+
+/// Encodes the manifest of a share set, checking that the shares belong together
+fn encode_manifest<S: Borrow<Share>>(
+    shares: impl IntoIterator<Item = Result<S>>,
+) -> Result<Vec<u8>> {
+    let mut shares = shares.into_iter();
+    let first = shares
+        .next()
+        .transpose()?
+        .ok_or(ShamirError::InsufficientShares { needed: 1, got: 0 })?;
+    let first = first.borrow();
+    let generation = first.refresh_generation;
+    let manifest = ShareManifest {
+        threshold: first.threshold,
+        total_shares: first.total_shares,
+        integrity_check: first.integrity_check,
+        compression: first.compression,
+        length_check: first.length_check,
+        indices: vec![first.index],
+    };
+    let manifest = shares.try_fold(manifest, |mut manifest, share| {
+        let share = share?;
+        let share = share.borrow();
+        if share.threshold != manifest.threshold
+            || share.total_shares != manifest.total_shares
+            || share.integrity_check != manifest.integrity_check
+            || share.compression != manifest.compression
+            || share.length_check != manifest.length_check
+            || share.refresh_generation != generation
+        {
+            return Err(ShamirError::MixedShareSets);
+        }
+        manifest.indices.push(share.index);
+        Ok(manifest)
+    })?;
+
+    let flags = u8::from(manifest.integrity_check)
+        | u8::from(manifest.compression) << 1
+        | u8::from(manifest.length_check) << 2;
+    let mut bytes = MANIFEST_MAGIC.to_vec();
+    bytes.extend_from_slice(&[
+        MANIFEST_VERSION,
+        flags,
+        manifest.threshold,
+        manifest.total_shares,
+    ]);
+    bytes.extend_from_slice(&(manifest.indices.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(&manifest.indices);
+    Ok(bytes)
+}
+
+/// Creates (or truncates) the file at `path` and writes `share` to it
+fn write_share_file(path: &Path, share: &Share) -> Result<()> {
+    let file = File::create(path)?;
+    let mut writer = BufWriter::new(file);
+    write_share(&mut writer, share)?;
+    writer.flush()?;
+    Ok(())
+}
+
+/// Creates the file at `path`, which must not exist yet, writes `share` to it and syncs it
+fn write_new_share_file(path: &Path, share: &Share) -> Result<()> {
+    let mut bytes = Vec::new();
+    write_share(&mut bytes, share)?;
+    write_new_file(path, &bytes)?;
+    Ok(())
+}
+
+/// Writes `shares` into `dir` as `share_<index>` files, either all of them or none
+///
+/// Each file is created with [`File::create_new`] and synced to the device, so existing
+/// files are never overwritten. If any step fails, the files this call created are
+/// removed before the error is returned.
+///
+/// # Errors
+/// Returns `ShamirError::IoError` if a target file already exists (`AlreadyExists`) or
+/// on any I/O failure.
+pub(crate) fn write_share_files(dir: &Path, shares: &[Share]) -> Result<Vec<PathBuf>> {
+    write_share_files_with(dir, shares, write_new_share_file)
+}
+
+/// [`write_share_files`] with the function that creates each file
+fn write_share_files_with(
+    dir: &Path,
+    shares: &[Share],
+    mut write_file: impl FnMut(&Path, &Share) -> Result<()>,
+) -> Result<Vec<PathBuf>> {
+    fs::create_dir_all(dir)?;
+
+    let mut written = Vec::with_capacity(shares.len());
+    for share in shares {
+        let path = dir.join(share_file_name(DEFAULT_FILE_PREFIX, share.index));
+        if let Err(e) = write_file(&path, share) {
+            // Best-effort cleanup; the original error is the one worth reporting
+            for path in &written {
+                let _ = fs::remove_file(path);
+            }
+            return Err(e);
+        }
+        written.push(path);
+    }
+    Ok(written)
+}
+
+/// Writes each share into its own directory, share `i` into `dirs[i]`, all or nothing
+///
+/// Each directory receives a single `share_<index>` file. The directories must exist,
+/// so an unmounted drive fails instead of silently writing to the mount point's parent
+/// file system. Existing files are never overwritten. If any write fails, the files
+/// already written are removed.
+///
+/// # Errors
+/// Returns `ShamirError::ShareWriteFailed` naming the file that could not be written.
+pub(crate) fn write_shares_to_dirs(dirs: &[PathBuf], shares: &[Share]) -> Result<()> {
+    let mut written = Vec::with_capacity(shares.len());
+    for (dir, share) in dirs.iter().zip(shares) {
+        let path = dir.join(share_file_name(DEFAULT_FILE_PREFIX, share.index));
+        let mut bytes = Vec::new();
+        write_share(&mut bytes, share)?;
+
+        if let Err(source) = write_new_file(&path, &bytes) {
+            // Best-effort cleanup; the failed path is the one worth reporting
+            for path in &written {
+                let _ = fs::remove_file(path);
+            }
+            return Err(ShamirError::ShareWriteFailed { path, source });
+        }
+        written.push(path);
+    }
+    Ok(())
+}
+
+/// Writes `bytes` to a file that must not exist yet, and syncs it to the device
+///
+/// A partially written file is removed; an existing file is left untouched.
+pub(crate) fn write_new_file(path: &Path, bytes: &[u8]) -> io::Result<()> {
+    let mut file = File::create_new(path)?;
+    let result = file.write_all(bytes).and_then(|()| file.sync_all());
+    if result.is_err() {
+        let _ = fs::remove_file(path);
+    }
+    result
+}
+
 impl ShareStore for FileShareStore {
     fn store_share(&mut self, share: &Share) -> Result<()> {
-        let path = self.share_path(share.index);
-        let file = File::create(path)?;
-        let mut writer = BufWriter::new(file);
-
-        // Write header
-        writer.write_all(MAGIC_NUMBER)?;
-        writer.write_all(&[VERSION])?;
-
-        // Write metadata
-        let integrity_flag = if share.integrity_check { 1 } else { 0 };
-        let compression_flag = if share.compression { 2 } else { 0 };
-        let flags = integrity_flag | compression_flag;
-        writer.write_all(&[flags])?;
-        writer.write_all(&[share.index, share.threshold, share.total_shares])?;
-
-        // Write data
-        let len = share.data.len() as u32;
-        writer.write_all(&len.to_le_bytes())?;
-        writer.write_all(&share.data)?;
-
-        Ok(())
+        write_share_file(&self.share_path(share.index), share)
     }
 
     fn load_share(&self, index: u8) -> Result<Share> {
@@ -133,50 +926,14 @@ impl ShareStore for FileShareStore {
             }
         })?;
 
-        // Read and verify header
-        let mut magic = [0u8; 4];
-        file.read_exact(&mut magic)?;
-        if magic != MAGIC_NUMBER {
-            return Err(ShamirError::InvalidShareFormat);
-        }
-
-        let mut version = [0u8; 1];
-        file.read_exact(&mut version)?;
-        if version[0] > VERSION {
-            return Err(ShamirError::InvalidShareFormat);
-        }
-
-        // Read metadata
-        let mut flags = [0u8; 1];
-        file.read_exact(&mut flags)?;
-        let integrity_check = (flags[0] & 1) != 0;
-        let compression = (flags[0] & 2) != 0;
-
-        let mut header = [0u8; 3];
-        file.read_exact(&mut header)?;
-        let (stored_index, threshold, total_shares) = (header[0], header[1], header[2]);
+        let share = read_share(&mut file)?;
 
         // Verify stored index matches requested index
-        if stored_index != index {
+        if share.index != index {
             return Err(ShamirError::InvalidShareFormat);
         }
 
-        // Read data
-        let mut len_bytes = [0u8; 4];
-        file.read_exact(&mut len_bytes)?;
-        let len = u32::from_le_bytes(len_bytes) as usize;
-
-        let mut data = vec![0u8; len];
-        file.read_exact(&mut data)?;
-
-        Ok(Share {
-            index,
-            data,
-            threshold,
-            total_shares,
-            integrity_check,
-            compression,
-        })
+        Ok(share)
     }
 
     fn list_shares(&self) -> Result<Vec<u8>> {
@@ -187,10 +944,12 @@ impl ShareStore for FileShareStore {
             let file_name = entry.file_name();
             let file_name = file_name.to_string_lossy();
 
-            if let Some(stripped) = file_name.strip_prefix("share_") {
-                if let Ok(index) = stripped.parse::<u8>() {
-                    indices.push(index);
-                }
+            if let Some(stripped) = file_name
+                .strip_prefix(self.prefix.as_str())
+                .and_then(|rest| rest.strip_prefix('_'))
+                && let Ok(index) = stripped.parse::<u8>()
+            {
+                indices.push(index);
             }
         }
 
@@ -228,7 +987,12 @@ mod tests {
             threshold: 3,    // Added threshold
             total_shares: 5, // Added total_shares
             integrity_check: true,
+            integrity_algorithm: 0,
             compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
         };
 
         // Store share
@@ -282,7 +1046,12 @@ mod tests {
                 threshold: 3,    // Added threshold
                 total_shares: 5, // Added total_shares
                 integrity_check: true,
+                integrity_algorithm: 0,
                 compression: false,
+                length_check: false,
+                refresh_generation: 0,
+                metadata: Vec::new(),
+                not_before: None,
             };
             store.store_share(&share)?;
         }
@@ -301,6 +1070,526 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn test_iter_shares_reconstructs_from_first_threshold() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut store = FileShareStore::new(temp_dir.path())?;
+        let mut scheme = crate::ShamirShare::builder(5, 3).build()?;
+        for share in scheme.split(b"iterated secret")? {
+            store.store_share(&share)?;
+        }
+
+        let shares = store.iter_shares()?.take(3).collect::<Result<Vec<_>>>()?;
+        assert_eq!(
+            shares.iter().map(|s| s.index).collect::<Vec<_>>(),
+            vec![1, 2, 3]
+        );
+        assert_eq!(
+            crate::ShamirShare::reconstruct(&shares)?,
+            b"iterated secret"
+        );
+
+        // Files are read lazily: a share deleted mid-iteration is reported when reached
+        let mut shares = store.iter_shares()?;
+        assert_eq!(shares.next().unwrap()?.index, 1);
+        fs::remove_file(temp_dir.path().join("share_002"))?;
+        assert!(matches!(
+            shares.next(),
+            Some(Err(ShamirError::InvalidShareIndex(2)))
+        ));
+        assert_eq!(shares.count(), 3);
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_share_files_rolls_back_on_failure() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut scheme = crate::ShamirShare::builder(5, 3).build()?;
+        let shares = scheme.split(b"all or nothing")?;
+
+        // Fail while writing the fourth share, after three have been written
+        let result = write_share_files_with(temp_dir.path(), &shares, |path, share| {
+            if share.index == 4 {
+                return Err(io::Error::other("disk full").into());
+            }
+            write_new_share_file(path, share)
+        });
+        assert!(matches!(result, Err(ShamirError::IoError(_))));
+        assert_eq!(fs::read_dir(temp_dir.path())?.count(), 0);
+
+        let paths = write_share_files(temp_dir.path(), &shares)?;
+        assert_eq!(paths.len(), 5);
+        assert_eq!(paths[0], temp_dir.path().join("share_001"));
+        assert_eq!(fs::read_dir(temp_dir.path())?.count(), 5);
+
+        // Existing shares are never overwritten
+        let again = write_share_files(temp_dir.path(), &shares);
+        assert!(
+            matches!(again, Err(ShamirError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists)
+        );
+        assert_eq!(fs::read_dir(temp_dir.path())?.count(), 5);
+
+        // A clash halfway through removes only the files this call created
+        let other = tempdir()?;
+        fs::write(other.path().join("share_003"), b"not ours")?;
+        let clash = write_share_files(other.path(), &shares);
+        assert!(
+            matches!(clash, Err(ShamirError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists)
+        );
+        assert_eq!(fs::read_dir(other.path())?.count(), 1);
+        assert_eq!(fs::read(other.path().join("share_003"))?, b"not ours");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_distribute_to_dirs_one_share_per_holder() -> Result<()> {
+        let holders = (0..5).map(|_| tempdir()).collect::<io::Result<Vec<_>>>()?;
+        let mut dirs: Vec<PathBuf> = holders.iter().map(|d| d.path().to_path_buf()).collect();
+        let mut scheme = crate::ShamirShare::builder(5, 3).build()?;
+
+        assert!(matches!(
+            scheme.distribute_to_dirs(b"per holder", &dirs[..4]),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+
+        // A holder whose medium is missing fails the whole distribution
+        let unmounted = holders[3].path().join("unmounted");
+        dirs[3] = unmounted.clone();
+        match scheme.distribute_to_dirs(b"per holder", &dirs) {
+            Err(ShamirError::ShareWriteFailed { path, source }) => {
+                assert_eq!(path, unmounted.join("share_004"));
+                assert_eq!(source.kind(), io::ErrorKind::NotFound);
+            }
+            other => panic!("expected ShareWriteFailed, got {other:?}"),
+        }
+        for holder in &holders {
+            assert_eq!(fs::read_dir(holder.path())?.count(), 0);
+        }
+
+        dirs[3] = holders[3].path().to_path_buf();
+        scheme.distribute_to_dirs(b"per holder", &dirs)?;
+
+        // Each holder reads back the single file on their medium
+        let mut shares = Vec::new();
+        for (i, dir) in dirs.iter().enumerate() {
+            let files: Vec<_> = fs::read_dir(dir)?.collect::<io::Result<_>>()?;
+            assert_eq!(files.len(), 1);
+            let share = read_share(&mut File::open(files[0].path())?)?;
+            assert_eq!(share.index as usize, i + 1);
+            shares.push(share);
+        }
+        assert_eq!(
+            crate::ShamirShare::reconstruct(&shares[2..])?,
+            b"per holder"
+        );
+
+        // A second distribution never overwrites the holders' shares
+        assert!(matches!(
+            scheme.distribute_to_dirs(b"replacement", &dirs),
+            Err(ShamirError::ShareWriteFailed { source, .. })
+                if source.kind() == io::ErrorKind::AlreadyExists
+        ));
+        assert_eq!(
+            read_share(&mut File::open(dirs[0].join("share_001"))?)?,
+            shares[0]
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_manifest_detects_missing_share() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut store = FileShareStore::new(temp_dir.path())?;
+        assert!(matches!(
+            store.write_manifest(),
+            Err(ShamirError::InsufficientShares { .. })
+        ));
+
+        let mut scheme = crate::ShamirShare::builder(5, 3).build()?;
+        for share in scheme.split(b"manifested")? {
+            store.store_share(&share)?;
+        }
+        store.write_manifest()?;
+        assert!(temp_dir.path().join("share.manifest").is_file());
+        assert_eq!(store.list_shares()?, [1, 2, 3, 4, 5]);
+
+        store.delete_share(4)?;
+        let manifest = store.read_manifest()?;
+        assert_eq!((manifest.threshold, manifest.total_shares), (3, 5));
+        assert!(manifest.integrity_check);
+        assert_eq!(manifest.missing_indices(&store.list_shares()?), [4]);
+
+        // Rewriting the manifest records the current holding
+        store.write_manifest()?;
+        assert_eq!(store.read_manifest()?.indices, [1, 2, 3, 5]);
+
+        // Shares of another scheme under the same prefix are refused
+        let mut other = crate::ShamirShare::builder(5, 2).build()?;
+        store.store_share(&other.split(b"intruder")?[3])?;
+        assert!(matches!(
+            store.write_manifest(),
+            Err(ShamirError::MixedShareSets)
+        ));
+
+        fs::write(temp_dir.path().join("share.manifest"), b"SHSM")?;
+        assert!(matches!(
+            store.read_manifest(),
+            Err(ShamirError::InvalidShareFormat)
+        ));
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_recovery_kit_contents() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let kit = temp_dir.path().join("kit");
+        let mut scheme = crate::ShamirShare::builder(5, 3).build()?;
+        scheme.export_recovery_kit(b"kit secret", &kit)?;
+
+        let mut files: Vec<String> = fs::read_dir(&kit)?
+            .map(|entry| Ok(entry?.file_name().to_string_lossy().into_owned()))
+            .collect::<Result<_>>()?;
+        files.sort();
+        assert_eq!(
+            files,
+            [
+                "README.txt",
+                "share.manifest",
+                "share_001",
+                "share_002",
+                "share_003",
+                "share_004",
+                "share_005"
+            ]
+        );
+
+        let store = FileShareStore::new(&kit)?;
+        let manifest = store.read_manifest()?;
+        assert_eq!((manifest.threshold, manifest.total_shares), (3, 5));
+        assert_eq!(manifest.indices, [1, 2, 3, 4, 5]);
+
+        // The README is generated from the scheme and names every share
+        let readme = fs::read_to_string(kit.join("README.txt"))?;
+        assert!(readme.contains("any 3 of the 5 shares"));
+        let shares: Vec<Share> = store.iter_shares()?.collect::<Result<_>>()?;
+        for share in &shares {
+            let line = format!(
+                "{}  share {} of 5, fingerprint {}",
+                share.canonical_filename(None),
+                share.index,
+                share.short_fingerprint()
+            );
+            assert!(readme.contains(&line));
+        }
+        assert_eq!(
+            crate::ShamirShare::reconstruct(&shares[2..])?,
+            b"kit secret"
+        );
+
+        // An existing kit is never overwritten
+        let mut other = crate::ShamirShare::builder(3, 2).build()?;
+        assert!(matches!(
+            other.export_recovery_kit(b"other", &kit),
+            Err(ShamirError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists
+        ));
+        fs::remove_file(kit.join("README.txt"))?;
+        assert!(matches!(
+            other.export_recovery_kit(b"other", &kit),
+            Err(ShamirError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists
+        ));
+        assert_eq!(store.list_shares()?, [1, 2, 3, 4, 5]);
+
+        // A clash with an existing manifest keeps it and removes only the new share files
+        let clash = temp_dir.path().join("clash");
+        fs::create_dir(&clash)?;
+        fs::write(clash.join("share.manifest"), b"not ours")?;
+        assert!(matches!(
+            other.export_recovery_kit(b"other", &clash),
+            Err(ShamirError::IoError(e)) if e.kind() == io::ErrorKind::AlreadyExists
+        ));
+        assert_eq!(fs::read_dir(&clash)?.count(), 1);
+        assert_eq!(fs::read(clash.join("share.manifest"))?, b"not ours");
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_canonical_filename_matches_store() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut store = FileShareStore::new(temp_dir.path())?;
+
+        for index in [1, 42, 255] {
+            let share = Share {
+                index,
+                data: vec![index; 4],
+                threshold: 2,
+                total_shares: 255,
+                integrity_check: true,
+                integrity_algorithm: 0,
+                compression: false,
+                length_check: false,
+                refresh_generation: 0,
+                metadata: Vec::new(),
+                not_before: None,
+            };
+            store.store_share(&share)?;
+
+            let expected = temp_dir.path().join(share.canonical_filename(None));
+            assert!(expected.is_file(), "missing {}", expected.display());
+        }
+
+        let share = store.load_share(42)?;
+        assert_eq!(share.canonical_filename(None), "share_042");
+        assert_eq!(share.canonical_filename(Some("backup")), "backup_042");
+        Ok(())
+    }
+
+    #[test]
+    fn test_list_schemes_with_two_prefixes() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut alpha = FileShareStore::new(temp_dir.path())?.with_prefix("alpha")?;
+        let mut beta = FileShareStore::new(temp_dir.path())?.with_prefix("beta_backup")?;
+
+        for index in 1..=3 {
+            let share = Share {
+                index,
+                data: vec![index; 4],
+                threshold: 2,
+                total_shares: 3,
+                integrity_check: true,
+                integrity_algorithm: 0,
+                compression: false,
+                length_check: false,
+                refresh_generation: 0,
+                metadata: Vec::new(),
+                not_before: None,
+            };
+            alpha.store_share(&share)?;
+            if index < 3 {
+                beta.store_share(&share)?;
+            }
+        }
+        fs::write(temp_dir.path().join("notes.txt"), b"not a share")?;
+        fs::write(temp_dir.path().join("alpha_final"), b"not a share either")?;
+
+        assert_eq!(alpha.list_schemes()?, vec!["alpha", "beta_backup"]);
+        assert_eq!(alpha.list_shares()?, vec![1, 2, 3]);
+        assert_eq!(beta.list_shares()?, vec![1, 2]);
+        assert_eq!(beta.load_share(2)?.data, vec![2; 4]);
+
+        let invalid = FileShareStore::new(temp_dir.path())?.with_prefix("");
+        assert!(invalid.is_err());
+        let invalid = FileShareStore::new(temp_dir.path())?.with_prefix("../up");
+        assert!(invalid.is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_version_2_share() -> Result<()> {
+        // Version 2 files have no algorithm byte and always used SHA-256
+        let mut bytes = b"SHS1".to_vec();
+        bytes.extend_from_slice(&[2, 0b01, 4, 3, 5]);
+        bytes.extend_from_slice(&3u32.to_le_bytes());
+        bytes.extend_from_slice(&[7, 8, 9]);
+
+        let share = read_share(&mut bytes.as_slice())?;
+        assert_eq!(share.index, 4);
+        assert_eq!(share.data, vec![7, 8, 9]);
+        assert!(share.integrity_check);
+        assert_eq!(share.integrity_algorithm, SHA256_ALGORITHM_ID);
+
+        let mut share = share;
+        share.integrity_algorithm = 0x80;
+        let mut written = Vec::new();
+        write_share(&mut written, &share)?;
+        assert_eq!(written[4], CHECKSUM_VERSION);
+        assert_eq!(
+            read_share(&mut written.as_slice())?.integrity_algorithm,
+            0x80
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn test_write_lowest_version_for_fields() -> Result<()> {
+        let mut scheme = crate::ShamirShare::builder(3, 2).build()?;
+        let mut share = scheme.split(b"versioned")?.remove(0);
+
+        // Only shares using metadata or a not-before time need the newer versions
+        let mut versions = Vec::new();
+        for (metadata, not_before) in [(vec![], None), (b"hint".to_vec(), None), (vec![], Some(7))]
+        {
+            share.metadata = metadata;
+            share.not_before = not_before;
+            let mut written = Vec::new();
+            write_share(&mut written, &share)?;
+            versions.push(written[4]);
+            assert_eq!(
+                written.len(),
+                encoded_len(share.data.len(), share.metadata.len(), not_before.is_some())
+            );
+            assert_eq!(read_share(&mut written.as_slice())?, share);
+        }
+        assert_eq!(versions, [CHECKSUM_VERSION, METADATA_VERSION, VERSION]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_migrate_version_1_shares() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut scheme = crate::ShamirShare::builder(3, 2).build()?;
+        let shares = scheme.split(b"legacy secret")?;
+
+        // Version 1 files share the version 2 layout: flags, index, threshold, total, data
+        for share in &shares {
+            let mut bytes = b"SHS1".to_vec();
+            let flags = u8::from(share.integrity_check) | u8::from(share.compression) << 1;
+            bytes.extend_from_slice(&[1, flags, share.index, 2, 3]);
+            bytes.extend_from_slice(&(share.data.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(&share.data);
+            fs::write(temp_dir.path().join(share.canonical_filename(None)), bytes)?;
+        }
+
+        let mut store = FileShareStore::new(temp_dir.path())?;
+        let legacy = vec![store.load_share(1)?, store.load_share(3)?];
+        assert_eq!(legacy[0], shares[0]);
+        assert_eq!(crate::ShamirShare::reconstruct(&legacy)?, b"legacy secret");
+
+        assert_eq!(store.migrate_all()?, vec![1, 2, 3]);
+        for share in &shares {
+            let bytes = fs::read(temp_dir.path().join(share.canonical_filename(None)))?;
+            assert_eq!(bytes[4], CHECKSUM_VERSION);
+            assert_eq!(&store.load_share(share.index)?, share);
+        }
+        let migrated = vec![store.load_share(2)?, store.load_share(3)?];
+        assert_eq!(
+            crate::ShamirShare::reconstruct(&migrated)?,
+            b"legacy secret"
+        );
+
+        // Migration is idempotent and leaves no staging files behind
+        assert!(store.migrate_all()?.is_empty());
+        assert_eq!(fs::read_dir(temp_dir.path())?.count(), 3);
+        Ok(())
+    }
+
+    #[test]
+    fn test_refresh_generation_persisted() -> Result<()> {
+        // Version 4 files predate refresh generations
+        let mut bytes = b"SHS1".to_vec();
+        bytes.extend_from_slice(&[4, 0b100, SHA256_ALGORITHM_ID, 2, 2, 3]);
+        bytes.extend_from_slice(&2u32.to_le_bytes());
+        bytes.extend_from_slice(&[1, 2]);
+        let share = read_share(&mut bytes.as_slice())?;
+        assert!(share.length_check);
+        assert_eq!(share.refresh_generation, 0);
+
+        let temp_dir = tempdir()?;
+        let mut store = FileShareStore::new(temp_dir.path())?;
+        let mut share = share;
+        share.refresh_generation = 0x0102_0304;
+        store.store_share(&share)?;
+        assert_eq!(store.load_share(2)?, share);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_detects_corrupted_share_file() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let mut store = FileShareStore::new(temp_dir.path())?;
+        let mut scheme = crate::ShamirShare::builder(3, 2).build()?;
+        for share in scheme.split(b"bit rot")? {
+            store.store_share(&share)?;
+        }
+
+        // Flip one bit in the data of share 2, and one in the threshold of share 3
+        let path = temp_dir.path().join("share_002");
+        let mut bytes = fs::read(&path)?;
+        assert_eq!(bytes[5] & CHECKSUM_FLAG, CHECKSUM_FLAG);
+        bytes[24] ^= 0x10;
+        fs::write(&path, &bytes)?;
+        let path = temp_dir.path().join("share_003");
+        let mut bytes = fs::read(&path)?;
+        bytes[9] ^= 0x01;
+        fs::write(&path, &bytes)?;
+
+        assert!(store.load_share(1).is_ok());
+        assert!(matches!(
+            store.load_share(2),
+            Err(ShamirError::ShareFileCorrupt { index: 2 })
+        ));
+        assert!(matches!(
+            store.load_share(3),
+            Err(ShamirError::ShareFileCorrupt { index: 3 })
+        ));
+
+        // A cleared checksum flag or a length running past the end is corruption too
+        let path = temp_dir.path().join("share_001");
+        let mut bytes = fs::read(&path)?;
+        bytes[5] &= !CHECKSUM_FLAG;
+        fs::write(&path, &bytes)?;
+        assert!(matches!(
+            store.load_share(1),
+            Err(ShamirError::ShareFileCorrupt { index: 1 })
+        ));
+        bytes[5] |= CHECKSUM_FLAG;
+        // The data length follows the 14-byte header of a share without metadata
+        bytes[17] = 0xff;
+        fs::write(&path, &bytes)?;
+        assert!(matches!(
+            store.load_share(1),
+            Err(ShamirError::ShareFileCorrupt { index: 1 })
+        ));
+
+        // Shares without the checksum flag, as written before version 6, still load
+        let mut legacy = b"SHS1".to_vec();
+        legacy.extend_from_slice(&[5, 0, SHA256_ALGORITHM_ID, 1, 1, 1]);
+        legacy.extend_from_slice(&0u32.to_le_bytes());
+        legacy.extend_from_slice(&1u32.to_le_bytes());
+        legacy.push(42);
+        fs::write(temp_dir.path().join("share_001"), &legacy)?;
+        assert_eq!(store.load_share(1)?.data, [42]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_read_bytes_grows_with_input() -> io::Result<()> {
+        let input: Vec<u8> = (0..3 * READ_CHUNK_SIZE + 5).map(|i| i as u8).collect();
+        assert_eq!(read_bytes(&mut input.as_slice(), input.len())?, input);
+
+        // A length far past the input fails without allocating it
+        let err = read_bytes(&mut input.as_slice(), u32::MAX as usize).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::UnexpectedEof);
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_share_from_newer_version() -> Result<()> {
+        let temp_dir = tempdir()?;
+        let store = FileShareStore::new(temp_dir.path())?;
+
+        let mut bytes = b"SHS1".to_vec();
+        bytes.extend_from_slice(&[VERSION + 1, 0, 0, 1, 2, 3]);
+        bytes.extend_from_slice(&0u32.to_le_bytes());
+        fs::write(store.share_path(1), bytes)?;
+
+        match store.load_share(1) {
+            Err(ShamirError::UnsupportedVersion {
+                found,
+                max_supported,
+            }) => {
+                assert_eq!(found, VERSION + 1);
+                assert_eq!(max_supported, VERSION);
+            }
+            other => panic!("unexpected result: {other:?}"),
+        }
+        Ok(())
+    }
+
     #[test]
     fn test_special_characters_path() -> Result<()> {
         let temp_dir = tempdir()?;
@@ -313,7 +1602,12 @@ mod tests {
             threshold: 3,
             total_shares: 5,
             integrity_check: true,
+            integrity_algorithm: 0,
             compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
         };
 
         store.store_share(&share)?;
@@ -336,7 +1630,12 @@ mod tests {
             threshold: 3,
             total_shares: 5,
             integrity_check: true,
+            integrity_algorithm: 0,
             compression: false,
+            length_check: false,
+            refresh_generation: 0,
+            metadata: Vec::new(),
+            not_before: None,
         };
 
         assert!(matches!(
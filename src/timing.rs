@@ -0,0 +1,151 @@
+//! Timing hooks for side-channel testing of split and reconstruction
+//!
+//! With the `timing-harness` feature, [`ShamirShare::split`](crate::ShamirShare::split)
+//! and every reconstruction record how long they took. [`record`] collects these samples
+//! for the operations run inside a closure, so an external test can compare the timing
+//! distributions of different secrets. The algorithms themselves are unchanged; when no
+//! recording is active the hooks only read the clock.
+
+use std::cell::RefCell;
+use std::time::{Duration, Instant};
+
+/// An instrumented operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Operation {
+    /// Splitting a secret into shares
+    Split,
+    /// Reconstructing a secret from shares
+    Reconstruct,
+}
+
+/// Timing of one instrumented operation
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimingSample {
+    /// The operation that was timed
+    pub operation: Operation,
+    /// Wall-clock time taken, measured with [`Instant`]
+    pub elapsed: Duration,
+    /// Time stamp counter cycles taken, on x86_64 only
+    ///
+    /// The counter ticks at a constant rate on modern CPUs, not at the current core
+    /// frequency, and is not serialized against surrounding instructions, so treat it as a
+    /// finer-grained clock rather than an exact instruction cost.
+    pub cycles: Option<u64>,
+}
+
+thread_local! {
+    static SAMPLES: RefCell<Option<Vec<TimingSample>>> = const { RefCell::new(None) };
+}
+
+/// Runs `f` and returns its result with the timings of the instrumented operations it ran
+///
+/// Only operations run on the calling thread are recorded, in the order they finished.
+/// Recordings nest: an inner `record` call collects its own samples, which the outer call
+/// does not see.
+///
+/// # Example
+/// ```
+/// use shamir_share::ShamirShare;
+/// use shamir_share::timing::{self, Operation};
+///
+/// let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+/// let (shares, samples) = timing::record(|| scheme.split(b"secret").unwrap());
+///
+/// assert_eq!(shares.len(), 5);
+/// assert_eq!(samples.len(), 1);
+/// assert_eq!(samples[0].operation, Operation::Split);
+/// ```
+pub fn record<R>(f: impl FnOnce() -> R) -> (R, Vec<TimingSample>) {
+    /// Restores the enclosing recording, even if `f` panics
+    struct Restore(Option<Vec<TimingSample>>);
+
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            SAMPLES.with(|samples| *samples.borrow_mut() = self.0.take());
+        }
+    }
+
+    let restore = Restore(SAMPLES.with(|samples| samples.replace(Some(Vec::new()))));
+    let result = f();
+    let samples = SAMPLES.with(|samples| samples.take()).unwrap_or_default();
+    drop(restore);
+    (result, samples)
+}
+
+/// Times an operation from its creation until it is dropped
+pub(crate) struct OperationTimer {
+    operation: Operation,
+    start: Instant,
+    start_cycles: Option<u64>,
+}
+
+impl OperationTimer {
+    pub(crate) fn start(operation: Operation) -> Self {
+        Self {
+            operation,
+            start_cycles: cycle_count(),
+            start: Instant::now(),
+        }
+    }
+}
+
+impl Drop for OperationTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        let cycles = cycle_count()
+            .zip(self.start_cycles)
+            .map(|(end, start)| end.wrapping_sub(start));
+        SAMPLES.with(|samples| {
+            if let Some(samples) = samples.borrow_mut().as_mut() {
+                samples.push(TimingSample {
+                    operation: self.operation,
+                    elapsed,
+                    cycles,
+                });
+            }
+        });
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+fn cycle_count() -> Option<u64> {
+    // SAFETY: RDTSC is part of the x86_64 baseline and only reads the time stamp counter
+    Some(unsafe { core::arch::x86_64::_rdtsc() })
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+fn cycle_count() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ShamirShare;
+
+    #[test]
+    fn test_record_collects_split_and_reconstruct() {
+        let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+        let (secret, samples) = record(|| {
+            let shares = scheme.split(b"timed").unwrap();
+            ShamirShare::reconstruct(&shares[..3]).unwrap()
+        });
+        assert_eq!(secret, b"timed");
+        let operations: Vec<Operation> = samples.iter().map(|s| s.operation).collect();
+        assert_eq!(operations, [Operation::Split, Operation::Reconstruct]);
+        assert_eq!(
+            samples.iter().all(|s| s.cycles.is_some()),
+            cfg!(target_arch = "x86_64")
+        );
+
+        // Nested recordings keep their samples apart, and nothing is kept afterwards
+        let ((_, inner), outer) = record(|| {
+            let inner = record(|| scheme.split(b"inner").unwrap());
+            scheme.split(b"outer").unwrap();
+            inner
+        });
+        assert_eq!((inner.len(), outer.len()), (1, 1));
+        scheme.split(b"unrecorded").unwrap();
+        assert!(SAMPLES.with(|samples| samples.borrow().is_none()));
+    }
+}
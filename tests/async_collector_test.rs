@@ -0,0 +1,83 @@
+#![cfg(feature = "tokio")]
+
+use futures_util::SinkExt;
+use shamir_share::{AsyncCollector, ShamirError, ShamirShare};
+
+#[tokio::test]
+async fn test_collector_reconstructs_from_streamed_shares() {
+    let mut scheme = ShamirShare::builder(7, 4).build().unwrap();
+    let secret = b"secret distributed over a gossip network";
+    let shares = scheme.split(secret).unwrap();
+
+    let (mut collector, result) = AsyncCollector::new();
+
+    // Peers deliver shares out of order and with repeats
+    let producer = tokio::spawn(async move {
+        for i in [5, 2, 5, 6, 0, 3, 1] {
+            collector.send(shares[i].clone()).await.unwrap();
+            tokio::task::yield_now().await;
+        }
+        collector
+    });
+
+    assert_eq!(result.await.unwrap().unwrap(), secret);
+
+    let collector = producer.await.unwrap();
+    assert!(collector.is_complete());
+}
+
+#[tokio::test]
+async fn test_collector_surfaces_first_inconsistency() {
+    let mut scheme_a = ShamirShare::builder(5, 3).build().unwrap();
+    let mut scheme_b = ShamirShare::builder(5, 2).build().unwrap();
+    let shares_a = scheme_a.split(b"same length!").unwrap();
+    let shares_b = scheme_b.split(b"same length!").unwrap();
+
+    let (mut collector, result) = AsyncCollector::new();
+    collector.send(shares_a[0].clone()).await.unwrap();
+
+    // A share from a different split with a different threshold
+    assert!(matches!(
+        collector.send(shares_b[1].clone()).await,
+        Err(ShamirError::InconsistentShareMetadata { field: "threshold" })
+    ));
+    assert!(matches!(
+        result.await.unwrap(),
+        Err(ShamirError::InconsistentShareMetadata { field: "threshold" })
+    ));
+
+    // Late shares after completion are accepted and ignored
+    collector.send(shares_a[1].clone()).await.unwrap();
+}
+
+#[tokio::test]
+async fn test_collector_rejects_conflicting_duplicate_index() {
+    let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    let first = scheme.split(b"payload").unwrap();
+    let second = scheme.split(b"payload").unwrap();
+
+    let (mut collector, result) = AsyncCollector::new();
+    collector.send(first[1].clone()).await.unwrap();
+    assert!(collector.send(second[1].clone()).await.is_err());
+    assert!(matches!(
+        result.await.unwrap(),
+        Err(ShamirError::InvalidShareIndex(2))
+    ));
+}
+
+#[tokio::test]
+async fn test_collector_close_before_threshold() {
+    let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    let shares = scheme.split(b"not enough").unwrap();
+
+    let (mut collector, result) = AsyncCollector::new();
+    collector.send(shares[0].clone()).await.unwrap();
+    collector.send(shares[4].clone()).await.unwrap();
+    assert_eq!(collector.collected(), 2);
+    collector.close().await.unwrap();
+
+    assert!(matches!(
+        result.await.unwrap(),
+        Err(ShamirError::InsufficientShares { needed: 3, got: 2 })
+    ));
+}
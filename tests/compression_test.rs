@@ -92,3 +92,101 @@ fn test_streaming_with_compression() {
 
     assert_eq!(&destination, data);
 }
+
+#[test]
+fn test_incompressible_data_stored_uncompressed() {
+    let config = Config::new().with_compression(true);
+    let mut shamir_compressed = ShamirShare::builder(5, 3)
+        .with_config(config)
+        .build()
+        .unwrap();
+    let mut shamir_uncompressed = ShamirShare::builder(5, 3).build().unwrap();
+
+    let random: Vec<u8> = (0..1024).map(|_| rand::random::<u8>()).collect();
+    for secret in [&random[..], b"tiny"] {
+        let compressed_shares = shamir_compressed.split(secret).unwrap();
+        let uncompressed_shares = shamir_uncompressed.split(secret).unwrap();
+
+        // zstd would only add framing, so the secret is stored as-is
+        assert!(!compressed_shares[0].compression);
+        assert_eq!(
+            compressed_shares[0].data.len(),
+            uncompressed_shares[0].data.len()
+        );
+
+        let reconstructed = ShamirShare::reconstruct(&compressed_shares[1..4]).unwrap();
+        assert_eq!(reconstructed, secret);
+    }
+
+    // Compressible secrets still get compressed
+    let shares = shamir_compressed.split(&[0u8; 1024]).unwrap();
+    assert!(shares[0].compression);
+}
+
+#[test]
+fn test_streaming_compression_per_chunk() {
+    use std::io::Cursor;
+
+    let split = |config: Config, data: &[u8]| -> Vec<Vec<u8>> {
+        let mut shamir = ShamirShare::builder(3, 2)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let mut destinations = vec![Cursor::new(Vec::new()); 3];
+        shamir
+            .split_stream(&mut Cursor::new(data), &mut destinations)
+            .unwrap();
+        destinations.into_iter().map(Cursor::into_inner).collect()
+    };
+
+    // One incompressible chunk followed by one highly compressible chunk
+    let mut data: Vec<u8> = (0..1024).map(|_| rand::random::<u8>()).collect();
+    data.extend_from_slice(&[b'a'; 1024]);
+
+    let base = Config::new().with_chunk_size(1024).unwrap();
+    let compressed = split(base.clone().with_compression(true), &data);
+    let uncompressed = split(base, &data);
+
+    // The random chunk costs a single marker byte instead of zstd framing
+    let random_chunk_len = |stream: &[u8]| u32::from_le_bytes(stream[7..11].try_into().unwrap());
+    assert_eq!(
+        random_chunk_len(&compressed[0]),
+        random_chunk_len(&uncompressed[0]) + 1
+    );
+    assert!(compressed[0].len() < uncompressed[0].len());
+
+    let mut sources: Vec<_> = compressed[1..].iter().map(Cursor::new).collect();
+    let mut reconstructed = Vec::new();
+    ShamirShare::reconstruct_stream(&mut sources, &mut reconstructed).unwrap();
+    assert_eq!(reconstructed, data);
+}
+
+#[test]
+fn test_reconstruct_multi_across_compression_settings() {
+    let secret = vec![b'k'; 512];
+
+    let config = Config::new().with_compression(true);
+    let mut compressed = ShamirShare::builder(5, 3)
+        .with_config(config)
+        .build()
+        .unwrap();
+    let mut uncompressed = ShamirShare::builder(5, 3).build().unwrap();
+
+    let compressed_shares = compressed.split(&secret).unwrap();
+    let uncompressed_shares = uncompressed.split(&secret).unwrap();
+    assert!(compressed_shares[0].compression);
+    assert!(!uncompressed_shares[0].compression);
+
+    // The sets cannot be mixed, but each reconstructs to the same secret
+    let mixed = [
+        compressed_shares[0].clone(),
+        compressed_shares[1].clone(),
+        uncompressed_shares[2].clone(),
+    ];
+    assert!(ShamirShare::reconstruct(&mixed).is_err());
+
+    let reconstructed =
+        ShamirShare::reconstruct_multi(&[&compressed_shares[..3], &uncompressed_shares[2..]])
+            .unwrap();
+    assert_eq!(reconstructed, secret);
+}
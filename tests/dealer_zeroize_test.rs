@@ -0,0 +1,125 @@
+#![cfg(feature = "zeroize")]
+
+use shamir_share::{Config, ShamirShare};
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// Allocator hook that inspects buffers of interest as they are freed
+///
+/// While armed, every freed block whose size matches `WATCHED_SIZE_A` or `WATCHED_SIZE_B`
+/// is checked for leftover non-zero bytes before it is returned to the system.
+struct DropTrackingAllocator;
+
+static ARMED: AtomicBool = AtomicBool::new(false);
+static WATCHED_SIZE_A: AtomicUsize = AtomicUsize::new(0);
+static WATCHED_SIZE_B: AtomicUsize = AtomicUsize::new(0);
+static FREED_WATCHED: AtomicUsize = AtomicUsize::new(0);
+static FREED_DIRTY: AtomicUsize = AtomicUsize::new(0);
+
+/// Held by each test while it uses the allocator hook, so tests never count each other's frees
+static HOOK: Mutex<()> = Mutex::new(());
+
+/// Starts watching freed blocks of the given sizes, resetting the counters
+fn watch(size_a: usize, size_b: usize) -> std::sync::MutexGuard<'static, ()> {
+    let guard = HOOK.lock().unwrap_or_else(|e| e.into_inner());
+    WATCHED_SIZE_A.store(size_a, Ordering::SeqCst);
+    WATCHED_SIZE_B.store(size_b, Ordering::SeqCst);
+    FREED_WATCHED.store(0, Ordering::SeqCst);
+    FREED_DIRTY.store(0, Ordering::SeqCst);
+    guard
+}
+
+unsafe impl GlobalAlloc for DropTrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        unsafe { System.alloc(layout) }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        let size = layout.size();
+        if ARMED.load(Ordering::SeqCst)
+            && (size == WATCHED_SIZE_A.load(Ordering::SeqCst)
+                || size == WATCHED_SIZE_B.load(Ordering::SeqCst))
+        {
+            // The block is still allocated here, so reading it is sound
+            let contents = unsafe { std::slice::from_raw_parts(ptr, size) };
+            FREED_WATCHED.fetch_add(1, Ordering::SeqCst);
+            if contents.iter().any(|&b| b != 0) {
+                FREED_DIRTY.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static GLOBAL: DropTrackingAllocator = DropTrackingAllocator;
+
+#[test]
+fn test_dealer_dropped_mid_iteration_zeroizes_buffers() {
+    const SECRET_LEN: usize = 1009;
+    const THRESHOLD: u8 = 3;
+
+    // Without integrity hashing the dealer's data buffer is exactly the secret length,
+    // and the coefficient buffer holds (threshold - 1) bytes per secret byte
+    let _hook = watch(SECRET_LEN, SECRET_LEN * (THRESHOLD as usize - 1));
+
+    let secret: Vec<u8> = (0..SECRET_LEN).map(|i| (i % 255) as u8 + 1).collect();
+    let config = Config::new().with_integrity_check(false);
+    let mut shamir = ShamirShare::builder(5, THRESHOLD)
+        .with_config(config)
+        .build()
+        .unwrap();
+
+    let mut dealer = shamir.dealer(&secret);
+    let first = dealer.next().unwrap();
+    let second = dealer.next().unwrap();
+    assert_eq!(first.data.len(), SECRET_LEN);
+
+    ARMED.store(true, Ordering::SeqCst);
+    // Drop the dealer with shares still left to generate
+    drop(dealer);
+    // Yielded shares are zeroized independently when the caller drops them
+    drop(first);
+    drop(second);
+    ARMED.store(false, Ordering::SeqCst);
+
+    // Dealer data + coefficients + two shares
+    assert_eq!(FREED_WATCHED.load(Ordering::SeqCst), 4);
+    assert_eq!(
+        FREED_DIRTY.load(Ordering::SeqCst),
+        0,
+        "secret-bearing buffers were freed without being zeroized"
+    );
+}
+
+#[test]
+fn test_take_data_empties_share_and_zeroizes_on_drop() {
+    const SECRET_LEN: usize = 1013;
+
+    let _hook = watch(SECRET_LEN, SECRET_LEN);
+
+    let secret: Vec<u8> = (0..SECRET_LEN).map(|i| (i % 255) as u8 + 1).collect();
+    let config = Config::new().with_integrity_check(false);
+    let mut shamir = ShamirShare::builder(3, 2)
+        .with_config(config)
+        .build()
+        .unwrap();
+    let mut shares = shamir.split(&secret).unwrap();
+
+    let data = shares[0].take_data();
+    assert!(shares[0].data.is_empty());
+    assert_eq!(data.len(), SECRET_LEN);
+    assert!(data.iter().any(|&b| b != 0));
+
+    ARMED.store(true, Ordering::SeqCst);
+    drop(data);
+    ARMED.store(false, Ordering::SeqCst);
+
+    assert_eq!(FREED_WATCHED.load(Ordering::SeqCst), 1);
+    assert_eq!(
+        FREED_DIRTY.load(Ordering::SeqCst),
+        0,
+        "taken share data was freed without being zeroized"
+    );
+}
@@ -0,0 +1,50 @@
+use shamir_share::{Config, Profile, features};
+
+#[test]
+fn test_features_match_build_configuration() {
+    let features = features();
+    let flags = [
+        ("compress", features.compress, cfg!(feature = "compress")),
+        ("zeroize", features.zeroize, cfg!(feature = "zeroize")),
+        ("interop", features.interop, cfg!(feature = "interop")),
+        (
+            "diagnostics",
+            features.diagnostics,
+            cfg!(feature = "diagnostics"),
+        ),
+        ("protobuf", features.protobuf, cfg!(feature = "protobuf")),
+        ("tokio", features.tokio, cfg!(feature = "tokio")),
+        ("memmap", features.memmap, cfg!(feature = "memmap")),
+        ("encrypt", features.encrypt, cfg!(feature = "encrypt")),
+        (
+            "timing-harness",
+            features.timing_harness,
+            cfg!(feature = "timing-harness"),
+        ),
+        (
+            "test-helpers",
+            features.test_helpers,
+            cfg!(feature = "test-helpers"),
+        ),
+    ];
+    for (name, reported, enabled) in flags {
+        assert_eq!(reported, enabled, "feature `{name}` is misreported");
+    }
+}
+
+#[test]
+fn test_compress_feature_matches_behaviour() {
+    // Presets only enable compression when the build supports it
+    assert_eq!(
+        Config::preset(Profile::Balanced).compression,
+        features().compress
+    );
+}
+
+#[cfg(all(feature = "compress", feature = "zeroize"))]
+#[test]
+fn test_default_features_reported() {
+    let features = features();
+    assert!(features.compress);
+    assert!(features.zeroize);
+}
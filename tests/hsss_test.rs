@@ -1,4 +1,4 @@
-use shamir_share::hsss::Hsss;
+use shamir_share::hsss::{AccessLevel, Hsss};
 use shamir_share::{ShamirError, ShamirShare};
 
 #[test]
@@ -395,3 +395,96 @@ fn test_share_properties_consistency() {
         assert_eq!(index, (i + 1) as u8);
     }
 }
+
+#[test]
+fn test_hierarchical_share_serialization_roundtrip() {
+    let mut hsss = Hsss::builder(5)
+        .add_level("President", 5)
+        .add_level("VP", 3)
+        .add_level("Executive", 2)
+        .build()
+        .unwrap();
+
+    let secret = b"organizational master key";
+    let all_h_shares = hsss.split_secret(secret).unwrap();
+
+    let packet = Hsss::serialize_hierarchical(&all_h_shares);
+    let restored = Hsss::deserialize_hierarchical(&packet).unwrap();
+    assert_eq!(restored, all_h_shares);
+    assert_eq!(restored[1].level_name, "VP");
+
+    // A single participant's packet reconstructs together with another's
+    let vp_packet = Hsss::serialize_hierarchical(&all_h_shares[1..2]);
+    let executive_packet = Hsss::serialize_hierarchical(&all_h_shares[2..3]);
+    let mut collected = Hsss::deserialize_hierarchical(&vp_packet).unwrap();
+    collected.extend(Hsss::deserialize_hierarchical(&executive_packet).unwrap());
+    assert_eq!(hsss.reconstruct(&collected).unwrap(), secret);
+
+    // Truncated or padded packets are rejected
+    assert!(matches!(
+        Hsss::deserialize_hierarchical(&packet[..packet.len() - 1]),
+        Err(ShamirError::InvalidShareFormat)
+    ));
+    let mut padded = packet.clone();
+    padded.push(0);
+    assert!(matches!(
+        Hsss::deserialize_hierarchical(&padded),
+        Err(ShamirError::InvalidShareFormat)
+    ));
+}
+
+#[test]
+fn test_from_shares_partitions_flat_split() {
+    let mut scheme = ShamirShare::builder(10, 5).build().unwrap();
+    let secret = b"split first, organize later";
+    let shares = scheme.split(secret).unwrap();
+
+    let levels = [
+        AccessLevel {
+            name: "President".to_string(),
+            shares_count: 5,
+        },
+        AccessLevel {
+            name: "VP".to_string(),
+            shares_count: 3,
+        },
+        AccessLevel {
+            name: "Executive".to_string(),
+            shares_count: 2,
+        },
+    ];
+    let all_h_shares = Hsss::from_shares(shares.clone(), &levels).unwrap();
+
+    assert_eq!(all_h_shares.len(), 3);
+    for (h_share, level) in all_h_shares.iter().zip(&levels) {
+        assert_eq!(h_share.level_name, level.name);
+        assert_eq!(h_share.shares.len(), level.shares_count as usize);
+    }
+    let indices: Vec<u8> = all_h_shares[1].shares.iter().map(|s| s.index).collect();
+    assert_eq!(indices, vec![6, 7, 8]);
+
+    // The President reconstructs alone; VP and Executive need each other
+    assert_eq!(
+        ShamirShare::reconstruct(&all_h_shares[0].shares).unwrap(),
+        secret
+    );
+    assert!(ShamirShare::reconstruct(&all_h_shares[1].shares).is_err());
+    let mut combined = all_h_shares[1].shares.clone();
+    combined.extend_from_slice(&all_h_shares[2].shares);
+    assert_eq!(ShamirShare::reconstruct(&combined).unwrap(), secret);
+
+    // Share counts must match the number of shares
+    assert!(matches!(
+        Hsss::from_shares(shares[..9].to_vec(), &levels),
+        Err(ShamirError::InvalidConfig(_))
+    ));
+
+    // Shares from splits with different thresholds are rejected
+    let mut other = ShamirShare::builder(10, 4).build().unwrap();
+    let mut mixed = shares;
+    mixed[9] = other.split(secret).unwrap().remove(9);
+    assert!(matches!(
+        Hsss::from_shares(mixed, &levels),
+        Err(ShamirError::InvalidShareFormat)
+    ));
+}
@@ -0,0 +1,159 @@
+use sha2::{Digest, Sha256};
+use shamir_share::{
+    Config, FileShareStore, IntegrityVerifier, SHA256_ALGORITHM_ID, ShamirError, ShamirShare,
+    ShareStore,
+};
+
+const KEYED_ALGORITHM_ID: u8 = 0x80;
+const TAG_LEN: usize = 16;
+
+/// Keyed SHA-256 truncated to 16 bytes, standing in for a truncated MAC
+struct TruncatedKeyedHash {
+    key: [u8; 32],
+}
+
+impl IntegrityVerifier for TruncatedKeyedHash {
+    fn algorithm_id(&self) -> u8 {
+        KEYED_ALGORITHM_ID
+    }
+
+    fn prefix_len(&self) -> usize {
+        TAG_LEN
+    }
+
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.key);
+        hasher.update(data);
+        hasher.finalize()[..TAG_LEN].to_vec()
+    }
+}
+
+fn keyed_scheme(key: [u8; 32]) -> ShamirShare {
+    let config = Config::new().with_verifier(Box::new(TruncatedKeyedHash { key }));
+    ShamirShare::builder(5, 3)
+        .with_config(config)
+        .build()
+        .unwrap()
+}
+
+#[test]
+fn test_custom_verifier_roundtrip() {
+    let secret = b"custom integrity scheme";
+    let mut scheme = keyed_scheme([7; 32]);
+    let shares = scheme.split(secret).unwrap();
+
+    for share in &shares {
+        assert!(share.integrity_check);
+        assert_eq!(share.integrity_algorithm, KEYED_ALGORITHM_ID);
+        assert_eq!(share.data.len(), TAG_LEN + secret.len());
+    }
+
+    let verifier = TruncatedKeyedHash { key: [7; 32] };
+    let reconstructed = ShamirShare::reconstruct_with_verifier(&shares[1..4], &verifier).unwrap();
+    assert_eq!(reconstructed, secret);
+
+    // Without the verifier the algorithm id cannot be dispatched
+    assert!(matches!(
+        ShamirShare::reconstruct(&shares[1..4]),
+        Err(ShamirError::UnsupportedIntegrityAlgorithm(
+            KEYED_ALGORITHM_ID
+        ))
+    ));
+
+    // A verifier with the wrong key rejects the tag
+    let wrong_key = TruncatedKeyedHash { key: [8; 32] };
+    assert!(matches!(
+        ShamirShare::reconstruct_with_verifier(&shares[1..4], &wrong_key),
+        Err(ShamirError::IntegrityCheckFailed)
+    ));
+}
+
+#[test]
+fn test_custom_verifier_detects_tampering() {
+    let mut scheme = keyed_scheme([1; 32]);
+    let mut shares = scheme.split(b"tamper evident").unwrap();
+    shares[0].data[TAG_LEN + 2] ^= 0x01;
+
+    let verifier = TruncatedKeyedHash { key: [1; 32] };
+    assert!(matches!(
+        ShamirShare::reconstruct_with_verifier(&shares[..3], &verifier),
+        Err(ShamirError::IntegrityCheckFailed)
+    ));
+}
+
+#[test]
+fn test_default_shares_ignore_custom_verifier() {
+    let mut scheme = ShamirShare::builder(5, 3).build().unwrap();
+    let shares = scheme.split(b"built-in").unwrap();
+    assert_eq!(shares[0].integrity_algorithm, SHA256_ALGORITHM_ID);
+
+    // Built-in algorithm ids keep using the built-in implementation
+    let verifier = TruncatedKeyedHash { key: [0; 32] };
+    let reconstructed = ShamirShare::reconstruct_with_verifier(&shares[..3], &verifier).unwrap();
+    assert_eq!(reconstructed, b"built-in");
+}
+
+#[test]
+fn test_algorithm_id_survives_storage() {
+    let temp_dir = tempfile::tempdir().unwrap();
+    let mut store = FileShareStore::new(temp_dir.path()).unwrap();
+
+    let mut scheme = keyed_scheme([9; 32]);
+    for share in scheme.split(b"stored with custom tag").unwrap() {
+        store.store_share(&share).unwrap();
+    }
+
+    let loaded: Vec<_> = (2..=4).map(|i| store.load_share(i).unwrap()).collect();
+    assert!(
+        loaded
+            .iter()
+            .all(|s| s.integrity_algorithm == KEYED_ALGORITHM_ID)
+    );
+
+    let verifier = TruncatedKeyedHash { key: [9; 32] };
+    let reconstructed = ShamirShare::reconstruct_with_verifier(&loaded, &verifier).unwrap();
+    assert_eq!(reconstructed, b"stored with custom tag");
+}
+
+#[test]
+fn test_stream_split_rejects_custom_verifier() {
+    let mut scheme = keyed_scheme([3; 32]);
+    let mut source = &b"stream data"[..];
+    let mut destinations = vec![Vec::new(); 5];
+    assert!(matches!(
+        scheme.split_stream(&mut source, &mut destinations),
+        Err(ShamirError::InvalidConfig(_))
+    ));
+}
+
+/// A verifier whose tags do not match its declared length
+struct MislabeledVerifier {
+    prefix_len: usize,
+}
+
+impl IntegrityVerifier for MislabeledVerifier {
+    fn algorithm_id(&self) -> u8 {
+        0x81
+    }
+
+    fn prefix_len(&self) -> usize {
+        self.prefix_len
+    }
+
+    fn compute(&self, data: &[u8]) -> Vec<u8> {
+        Sha256::digest(data)[..8].to_vec()
+    }
+}
+
+#[test]
+fn test_verifier_validated_only_with_integrity_check() {
+    for prefix_len in [0, 16] {
+        let config = Config::new().with_verifier(Box::new(MislabeledVerifier { prefix_len }));
+        assert!(matches!(
+            config.validate(),
+            Err(ShamirError::InvalidConfig(_))
+        ));
+        assert!(config.with_integrity_check(false).validate().is_ok());
+    }
+}
@@ -0,0 +1,90 @@
+#![cfg(feature = "interop")]
+
+use shamir_share::interop::import_from_json;
+use shamir_share::{ShamirError, ShamirShare};
+
+/// Shares of `b"hello interop"` (threshold 2) as emitted by an external implementation
+const SAMPLE_PAYLOAD: &str = r#"[
+    {"x": 1, "y": "Y1U5FvDkgGBHPQ/Ntw=="},
+    {"x": 2, "y": "fgXGmEqzoHIS1Ygw5Q=="},
+    {"x": 3, "y": "dTWT4tV3SXwhjfWSIg=="}
+]"#;
+
+#[test]
+fn test_import_sample_payload() {
+    let shares = import_from_json(SAMPLE_PAYLOAD, 2).unwrap();
+    assert_eq!(shares.len(), 3);
+
+    for (i, share) in shares.iter().enumerate() {
+        assert_eq!(share.index, i as u8 + 1);
+        assert_eq!(share.threshold, 2);
+        assert_eq!(share.total_shares, 3);
+        assert!(!share.integrity_check);
+        assert!(!share.compression);
+    }
+
+    // Any two shares reconstruct the known secret
+    for pair in [[0, 1], [0, 2], [1, 2]] {
+        let subset = vec![shares[pair[0]].clone(), shares[pair[1]].clone()];
+        let secret = ShamirShare::reconstruct(&subset).unwrap();
+        assert_eq!(secret, b"hello interop");
+    }
+}
+
+#[test]
+fn test_import_roundtrip_with_native_shares() {
+    use base64::Engine;
+    use base64::engine::general_purpose::STANDARD;
+    use shamir_share::Config;
+
+    let config = Config::new().with_integrity_check(false);
+    let mut shamir = ShamirShare::builder(5, 3)
+        .with_config(config)
+        .build()
+        .unwrap();
+    let secret = b"native shares exported as JSON";
+    let shares = shamir.split(secret).unwrap();
+
+    let entries: Vec<String> = shares[1..4]
+        .iter()
+        .map(|s| {
+            format!(
+                r#"{{"x": {}, "y": "{}"}}"#,
+                s.index,
+                STANDARD.encode(&s.data)
+            )
+        })
+        .collect();
+    let json = format!("[{}]", entries.join(","));
+
+    let imported = import_from_json(&json, 3).unwrap();
+    assert_eq!(ShamirShare::reconstruct(&imported).unwrap(), secret);
+}
+
+#[test]
+fn test_import_rejects_invalid_payloads() {
+    assert!(matches!(
+        import_from_json(SAMPLE_PAYLOAD, 0),
+        Err(ShamirError::InvalidThreshold(0))
+    ));
+    assert!(matches!(
+        import_from_json("not json", 2),
+        Err(ShamirError::InvalidShareFormat)
+    ));
+    assert!(matches!(
+        import_from_json(r#"[{"x": 1, "y": "***"}]"#, 2),
+        Err(ShamirError::InvalidShareFormat)
+    ));
+    assert!(matches!(
+        import_from_json(r#"[{"x": 0, "y": "AA=="}]"#, 2),
+        Err(ShamirError::InvalidShareIndex(0))
+    ));
+    assert!(matches!(
+        import_from_json(r#"[{"x": 1, "y": "AA=="}, {"x": 1, "y": "AA=="}]"#, 2),
+        Err(ShamirError::InvalidShareIndex(1))
+    ));
+    assert!(matches!(
+        import_from_json(r#"[{"x": 1, "y": "AA=="}, {"x": 2, "y": "AAA="}]"#, 2),
+        Err(ShamirError::InconsistentShareLength)
+    ));
+}
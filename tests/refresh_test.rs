@@ -1,4 +1,4 @@
-use shamir_share::{Config, ShamirShare, ShamirError};
+use shamir_share::{Config, ShamirShare, ShamirError, Share};
 
 #[test]
 fn test_refreshed_shares_reconstruct_correctly() {
@@ -274,4 +274,36 @@ fn test_refresh_with_inconsistent_share_lengths() {
         }
         other => panic!("Expected InconsistentShareLength error, got: {:?}", other),
     }
-}
\ No newline at end of file
+}
+
+#[test]
+fn test_mixing_generations_reports_mixed_generations() {
+    for integrity_check in [true, false] {
+        let config = Config::new().with_integrity_check(integrity_check);
+        let mut shamir = ShamirShare::builder(5, 3)
+            .with_config(config)
+            .build()
+            .unwrap();
+        let gen0 = shamir.split(b"generation tracked").unwrap();
+        let gen1 = shamir.refresh_shares(&gen0[0..3]).unwrap();
+        let gen2 = shamir.refresh_shares(&gen1).unwrap();
+
+        assert!(gen0.iter().all(|share| share.refresh_generation == 0));
+        assert!(gen1.iter().all(|share| share.refresh_generation == 1));
+        assert!(gen2.iter().all(|share| share.refresh_generation == 2));
+
+        // The generation survives serialization
+        let stored = Share::from_bytes(&gen1[2].to_bytes()).unwrap();
+        assert_eq!(stored.refresh_generation, 1);
+
+        let mixed = vec![gen0[0].clone(), gen0[1].clone(), stored];
+        assert!(matches!(
+            ShamirShare::reconstruct(&mixed),
+            Err(ShamirError::MixedGenerations)
+        ));
+        assert!(matches!(
+            shamir.refresh_shares(&mixed),
+            Err(ShamirError::MixedGenerations)
+        ));
+    }
+}
@@ -0,0 +1,81 @@
+use shamir_share::ShamirShare;
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// Allocator hook that tracks the live and peak number of heap bytes
+struct TrackingAllocator;
+
+static CURRENT: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = unsafe { System.alloc(layout) };
+        if !ptr.is_null() {
+            let current = CURRENT.fetch_add(layout.size(), Ordering::SeqCst) + layout.size();
+            PEAK.fetch_max(current, Ordering::SeqCst);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        unsafe { System.dealloc(ptr, layout) };
+        CURRENT.fetch_sub(layout.size(), Ordering::SeqCst);
+    }
+}
+
+#[global_allocator]
+static GLOBAL: TrackingAllocator = TrackingAllocator;
+
+/// Runs `f` and returns the heap high-water mark it reached above the starting level
+fn peak_during<F: FnOnce()>(f: F) -> usize {
+    let baseline = CURRENT.load(Ordering::SeqCst);
+    PEAK.store(baseline, Ordering::SeqCst);
+    f();
+    PEAK.load(Ordering::SeqCst) - baseline
+}
+
+// Kept as a single test so no other test allocates concurrently in this binary
+#[test]
+fn test_split_bounded_reduces_peak_memory() {
+    const SECRET_LEN: usize = 64 * 1024;
+    const TOTAL_SHARES: u8 = 50;
+
+    let secret: Vec<u8> = (0..SECRET_LEN).map(|i| (i % 251) as u8).collect();
+    let mut scheme = ShamirShare::builder(TOTAL_SHARES, 2).build().unwrap();
+
+    // Shares produced by both methods must be interchangeable
+    let bounded: Vec<_> = scheme.split_bounded(&secret).collect();
+    assert_eq!(bounded.len(), TOTAL_SHARES as usize);
+    assert!(
+        bounded
+            .iter()
+            .enumerate()
+            .all(|(i, s)| s.index == i as u8 + 1)
+    );
+    assert_eq!(ShamirShare::reconstruct(&bounded[10..12]).unwrap(), secret);
+    drop(bounded);
+
+    let split_peak = peak_during(|| {
+        let shares = scheme.split(&secret).unwrap();
+        assert_eq!(shares.len(), TOTAL_SHARES as usize);
+    });
+
+    let bounded_peak = peak_during(|| {
+        let mut count = 0;
+        for share in scheme.split_bounded(&secret) {
+            assert_eq!(share.data.len(), SECRET_LEN + 32);
+            count += 1;
+        }
+        assert_eq!(count, TOTAL_SHARES as usize);
+    });
+
+    // `split` holds every share at once; the bounded path holds the prepared secret,
+    // one coefficient per byte, and a single share
+    assert!(split_peak >= TOTAL_SHARES as usize * SECRET_LEN);
+    assert!(
+        bounded_peak < 5 * SECRET_LEN,
+        "bounded peak {bounded_peak} should stay within a few multiples of the secret size"
+    );
+    assert!(bounded_peak * 10 < split_peak);
+}
@@ -124,9 +124,9 @@ fn test_streaming_integrity_check_failure() {
     // Get the inner Vec<u8> from one of the resulting share cursors and corrupt it
     let mut share_data: Vec<Vec<u8>> = share_writers.into_iter().map(|c| c.into_inner()).collect();
 
-    // Corrupt a byte in the first share (skip header bytes and corrupt data)
-    if share_data[0].len() > 10 {
-        share_data[0][10] ^= 0xFF;
+    // Corrupt a byte in the first share (skip the 7-byte header and 4-byte length)
+    if share_data[0].len() > 15 {
+        share_data[0][15] ^= 0xFF;
     }
 
     // Convert corrupted data back to readers
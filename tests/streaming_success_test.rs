@@ -1,4 +1,4 @@
-use shamir_share::ShamirShare;
+use shamir_share::{Config, ShamirShare};
 use std::io::Cursor;
 
 #[test]
@@ -43,3 +43,37 @@ fn test_streaming_end_to_end() {
     // Compare the original source_data with the data in the reconstructed_writer
     assert_eq!(source_data, reconstructed_writer.into_inner());
 }
+
+#[test]
+fn test_streaming_sources_in_any_order() {
+    // Small chunks with compression off give many chunk records to interpolate
+    let config = Config {
+        compression: false,
+        ..Config::new().with_chunk_size(64).unwrap()
+    };
+    let mut scheme = ShamirShare::builder(5, 3)
+        .with_config(config)
+        .build()
+        .unwrap();
+    let source_data: Vec<u8> = (0..1000u32).map(|i| (i * 7 % 256) as u8).collect();
+
+    let mut share_writers: Vec<_> = (0..5).map(|_| Cursor::new(Vec::new())).collect();
+    scheme
+        .split_stream(&mut Cursor::new(&source_data), &mut share_writers)
+        .unwrap();
+    let streams: Vec<Vec<u8>> = share_writers.into_iter().map(Cursor::into_inner).collect();
+
+    // The index in each source's header decides its x-coordinate, not its position
+    for order in [[2, 0, 1], [4, 2, 0], [3, 4, 1], [1, 4, 3]] {
+        let mut share_readers: Vec<_> = order.iter().map(|&i| Cursor::new(&streams[i])).collect();
+        let mut reconstructed = Vec::new();
+        ShamirShare::reconstruct_stream(&mut share_readers, &mut reconstructed).unwrap();
+        assert_eq!(reconstructed, source_data, "sources in order {order:?}");
+    }
+
+    // All five sources, reversed
+    let mut share_readers: Vec<_> = streams.iter().rev().map(Cursor::new).collect();
+    let mut reconstructed = Vec::new();
+    ShamirShare::reconstruct_stream(&mut share_readers, &mut reconstructed).unwrap();
+    assert_eq!(reconstructed, source_data);
+}